@@ -1,6 +1,7 @@
 pub mod model;
 pub mod controllers;
 pub mod services;
+pub mod repositories;
 pub mod configuration;
 
 pub mod util;
@@ -10,4 +11,6 @@ pub mod strategy;
 
 pub mod telemetry;
 pub mod middlewares;
-pub mod handlers;
\ No newline at end of file
+pub mod handlers;
+pub mod jwt;
+pub mod external_dependency_systems;
\ No newline at end of file
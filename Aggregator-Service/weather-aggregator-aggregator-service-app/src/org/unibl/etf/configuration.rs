@@ -32,5 +32,8 @@ pub fn get_configuration(config_path : &str) -> Result<Settings, config::ConfigE
 
     let s1 = settings.try_deserialize::<Settings>();
     println!("{:?}", s1); //remove later
-    s1
+
+    let settings = s1?;
+    settings.validate_provider_weights()?;
+    Ok(settings)
 }
\ No newline at end of file
@@ -1,20 +1,81 @@
 use std::{fs, io};
 use std::io::BufReader;
+use std::net::IpAddr;
 use std::sync::Arc;
+use base64::Engine;
+use base64::engine::general_purpose;
+use jsonwebtoken::DecodingKey;
 use opentelemetry_otlp::tonic_types::transport::{Certificate, ClientTlsConfig, Identity};
 use rustls::{RootCertStore, ServerConfig};
 use rustls::server::WebPkiClientVerifier;
 use secrecy::{ExposeSecret, SecretBox};
 use serde_aux::prelude::deserialize_bool_from_anything;
 use serde::Deserialize;
-use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_aux::field_attributes::{deserialize_number_from_string, deserialize_option_number_from_string};
+use crate::org::unibl::etf::jwt::jwks::Jwks;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+use crate::org::unibl::etf::strategy::strategy_kind::StrategyKind;
 
 #[derive(Deserialize, Debug)]
 pub struct Settings {
     pub application: ApplicationSettings,
     pub providers: Vec<ProviderSettings>,
+    #[serde(default)]
+    pub strategy: StrategySettings,
     pub cache_service: CacheServiceSettings,
     pub tracing_agent: TracingSettings,
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerSettings,
+    #[serde(default)]
+    pub fan_out: FanOutSettings,
+    #[serde(default)]
+    pub local_cache: LocalCacheSettings,
+    // Only required when client rate limiting is enabled.
+    #[serde(default)]
+    pub redis_store: Option<RedisStoreSettings>,
+    // Without it every caller is limited by IP address.
+    #[serde(default)]
+    pub jwt: Option<JwtSettings>,
+    #[serde(default)]
+    pub client_rate_limit: ClientRateLimitSettings,
+    // Used to name coordinate queries. Without it locations are left as the providers returned them.
+    #[serde(default)]
+    pub geocoding_service: Option<GeocodingServiceSettings>,
+}
+
+impl Settings {
+    // A weight scales a provider's share of every aggregated value, so a negative one would pull
+    // the result away from it and all of them being zero leaves nothing to average.
+    pub fn validate_provider_weights(&self) -> Result<(), config::ConfigError> {
+        if let Some(provider) = self.providers.iter().find(|p| p.weight.is_nan() || p.weight < 0.0) {
+            return Err(config::ConfigError::Message(
+                format!("Weight of the provider {} must not be negative, got {}.", provider.name, provider.weight)
+            ));
+        }
+
+        if !self.providers.is_empty() && self.providers.iter().all(|p| p.weight == 0.0) {
+            return Err(config::ConfigError::Message(
+                "At least one provider must have a weight greater than zero.".to_string()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GeocodingServiceSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+    pub host: String,
+    pub scheme: String,
+    // The lookup holds up the response, so it is given up on quickly.
+    #[serde(default = "default_reverse_geocoding_timeout_in_ms", deserialize_with = "deserialize_number_from_string")]
+    pub timeout_in_ms: u64,
+}
+
+fn default_reverse_geocoding_timeout_in_ms() -> u64 {
+    1000
 }
 
 #[derive(Deserialize, Debug)]
@@ -31,9 +92,19 @@ pub struct ApplicationSettings {
     pub pkcs_file_path: String,
     pub pkcs_export_password: SecretBox<String>,
     pub ca_cert_file_path: String,
+    // Plain HTTP listener for operational endpoints such as the local cache stats, kept off the public port.
+    // Left out, those endpoints are not served at all.
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub internal_port: Option<u16>,
+    #[serde(default = "default_internal_host")]
+    pub internal_host: String,
 }
 
-#[derive(Deserialize, Debug)]
+fn default_internal_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct ProviderSettings {
     pub name: String,
     #[serde(deserialize_with = "deserialize_number_from_string")]
@@ -41,7 +112,310 @@ pub struct ProviderSettings {
     pub host: String,
     pub scheme: String,
     #[serde(deserialize_with = "deserialize_bool_from_anything")]
-    pub ip_support: bool
+    pub ip_support: bool,
+    #[serde(default = "default_provider_weight", deserialize_with = "deserialize_number_from_string")]
+    pub weight: f64,
+    // The generic provider adapter service hosts each provider under api/v1/<provider name>.
+    #[serde(default = "default_provider_api_path")]
+    pub api_path: String,
+    #[serde(default = "default_provider_timeout_in_ms", deserialize_with = "deserialize_number_from_string")]
+    pub timeout_in_ms: u64,
+    // When set, a duplicate request is sent if the first one has not answered after this delay.
+    #[serde(default)]
+    pub hedge_after_in_ms: Option<u64>,
+    // Endpoints the provider's adapter serves. Left out, the adapter is expected to serve all of them.
+    #[serde(default)]
+    pub endpoints: Option<Vec<ProviderEndpoint>>,
+}
+
+fn default_provider_weight() -> f64 {
+    1.0
+}
+
+fn default_provider_api_path() -> String {
+    "api/v1".to_string()
+}
+
+fn default_provider_timeout_in_ms() -> u64 {
+    3000
+}
+
+impl ProviderSettings {
+    pub fn endpoint_url(&self, endpoint: ProviderEndpoint) -> String {
+        format!("{}://{}:{}/{}/{}", self.scheme, self.host, self.port, self.api_path.trim_matches('/'), endpoint.as_str())
+    }
+
+    pub fn supports(&self, endpoint: ProviderEndpoint) -> bool {
+        self.endpoints.as_ref().is_none_or(|endpoints| endpoints.contains(&endpoint))
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CircuitBreakerSettings {
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub enabled: bool,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub failure_threshold: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub open_duration_in_seconds: u64,
+}
+
+impl Default for CircuitBreakerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            failure_threshold: 5,
+            open_duration_in_seconds: 30,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FanOutSettings {
+    // Total time a request waits for providers before resolving with the results that have arrived.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub request_budget_in_ms: u64,
+}
+
+impl Default for FanOutSettings {
+    fn default() -> Self {
+        Self {
+            request_budget_in_ms: 4000,
+        }
+    }
+}
+
+// In-process cache in front of the Cache-Service. Entries never outlive the Cache-Service entry they were read from.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LocalCacheSettings {
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub enabled: bool,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_entries: usize,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ttl_in_seconds: u64,
+}
+
+impl Default for LocalCacheSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries: 1000,
+            ttl_in_seconds: 30,
+        }
+    }
+}
+
+// Limits on inbound requests, counted per user for authenticated callers and per IP address otherwise.
+// Tiers left out of the configuration keep their defaults.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ClientRateLimitSettings {
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub enabled: bool,
+    pub anonymous: ClientRateLimitTierSettings,
+    pub guest: ClientRateLimitTierSettings,
+    pub standard: ClientRateLimitTierSettings,
+    // Only these peers are believed about the client address they put in X-Forwarded-For.
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl Default for ClientRateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            anonymous: ClientRateLimitTierSettings {
+                requests_per_minute: Some(30),
+                requests_per_day: Some(1000),
+            },
+            guest: ClientRateLimitTierSettings {
+                requests_per_minute: Some(60),
+                requests_per_day: Some(2000),
+            },
+            standard: ClientRateLimitTierSettings {
+                requests_per_minute: Some(120),
+                requests_per_day: Some(10000),
+            },
+            trusted_proxies: Vec::new(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClientRateLimitTierSettings {
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_minute: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_day: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClientRateLimitWindow {
+    pub name: &'static str,
+    pub limit: u64,
+    pub window_in_seconds: u64,
+}
+
+impl ClientRateLimitTierSettings {
+    pub fn windows(&self) -> Vec<ClientRateLimitWindow> {
+        [
+            ("minute", self.requests_per_minute, 60),
+            ("day", self.requests_per_day, 86400),
+        ]
+            .into_iter()
+            .filter_map(|(name, limit, window_in_seconds)| {
+                limit.map(|limit| ClientRateLimitWindow { name, limit, window_in_seconds })
+            })
+            .collect()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RedisStoreSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+    pub host: String,
+    pub scheme: String,
+    pub username: String,
+    pub user_password: SecretBox<String>
+}
+
+impl RedisStoreSettings {
+    pub fn get_redis_config(&self) -> Result<String, io::Error> {
+        let connection_uri = format!("{}://{}:{}@{}:{}",
+                                     self.scheme, &self.username,
+                                     &self.user_password.expose_secret(),
+                                     &self.host, &self.port);
+        Ok(connection_uri)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct JwtSettings {
+    pub signer_public_key_file_path: String,
+    pub signer_public_key_url: String,
+    pub issuer: String,
+    pub kid: String,
+}
+
+impl JwtSettings {
+    pub async fn get_signer_jwt_public_key(&self, client: reqwest::Client) -> Result<DecodingKey, io::Error> {
+        match self.fetch_signer_jwt_public_key_from_online_source(client).await {
+            Ok(jwks_string) => {
+                match serde_json::from_str::<Jwks>(&jwks_string) {
+                    Ok(jwks) => {
+                        match jwks.keys.first() {
+                            Some(key) => {
+                                match general_purpose::URL_SAFE_NO_PAD
+                                    .decode(&key.x) {
+                                    Err(e) => {
+                                        tracing::error!("Failed to decode base64: {}", e);
+                                    },
+                                    Ok(x_bytes) => {
+                                        return Ok(DecodingKey::from_ed_der(&x_bytes));
+                                    }
+                                }
+                            },
+                            None => {
+                                tracing::warn!("JWKS contained no keys")
+                            }
+                        }
+                    },
+                    Err(e) => {
+                        tracing::warn!("Online JWKS was not valid JSON. {:?}", e.to_string())
+                    }
+                }
+            },
+            Err(e) => {
+                tracing::warn!("Online fetch failed: {}. Using offline key.", e);
+            }
+        }
+
+        let offline_bytes = self.get_signer_jwt_public_key_from_offline_source()?;
+        DecodingKey::from_ed_pem(&offline_bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn get_signer_jwt_public_key_from_offline_source(&self) -> Result<Vec<u8>, io::Error> {
+        fs::read(self.signer_public_key_file_path.clone())
+    }
+
+    pub async fn fetch_signer_jwt_public_key_from_online_source(&self, client: reqwest::Client) -> Result<String, reqwest::Error> {
+        tracing::info!("Fetching public key from {}", self.signer_public_key_url);
+        let response = client.get(self.signer_public_key_url.clone()).send().await?.text().await?;
+        Ok(response)
+    }
+}
+
+// Defaults to the composite strategy the Aggregator always used before it became configurable.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct StrategySettings {
+    pub default: StrategyKind,
+    pub priority: PriorityStrategySettings,
+    pub composite: CompositeStrategySettings,
+    pub trimmed_mean: TrimmedMeanStrategySettings,
+}
+
+impl Default for StrategySettings {
+    fn default() -> Self {
+        Self {
+            default: StrategyKind::Composite,
+            priority: PriorityStrategySettings::default(),
+            composite: CompositeStrategySettings::default(),
+            trimmed_mean: TrimmedMeanStrategySettings::default(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PriorityStrategySettings {
+    pub order: Vec<String>,
+}
+
+impl Default for PriorityStrategySettings {
+    fn default() -> Self {
+        Self {
+            order: vec!["openweathermap.org".to_string(), "weatherapi.com".to_string()],
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CompositeStrategySettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub average_min: usize,
+}
+
+impl Default for CompositeStrategySettings {
+    fn default() -> Self {
+        Self {
+            average_min: 2,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TrimmedMeanStrategySettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_temperature_deviation: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_pressure_deviation: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_humidity_deviation: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_wind_speed_deviation: f64,
+}
+
+impl Default for TrimmedMeanStrategySettings {
+    fn default() -> Self {
+        Self {
+            max_temperature_deviation: 3.0,
+            max_pressure_deviation: 5.0,
+            max_humidity_deviation: 15.0,
+            max_wind_speed_deviation: 3.0,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -50,6 +424,20 @@ pub struct CacheServiceSettings {
     pub port: u16,
     pub host: String,
     pub scheme: String,
+    // Cache hits expiring within this window are served and refreshed in the background.
+    #[serde(default = "default_refresh_ahead_in_seconds", deserialize_with = "deserialize_number_from_string")]
+    pub refresh_ahead_in_seconds: i64,
+    // Coordinate queries that miss the cache are answered from a reading within this distance. Zero disables it.
+    #[serde(default = "default_nearby_radius_in_meters", deserialize_with = "deserialize_number_from_string")]
+    pub nearby_radius_in_meters: f64,
+}
+
+fn default_nearby_radius_in_meters() -> f64 {
+    500.0
+}
+
+fn default_refresh_ahead_in_seconds() -> i64 {
+    120
 }
 
 #[derive(serde::Deserialize, Debug, Clone)]
@@ -0,0 +1,73 @@
+use actix_web::{web, HttpResponse, Responder};
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
+use crate::org::unibl::etf::controllers::errors::GenericServiceError;
+use crate::org::unibl::etf::model::requests::air_quality_coordinates_query::AirQualityCoordinatesQuery;
+use crate::org::unibl::etf::model::requests::air_quality_location_query::AirQualityLocationQuery;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_coordinates::UpstreamCurrentWeatherRequestByCoordinates;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_location::UpstreamCurrentWeatherRequestByLocation;
+use crate::org::unibl::etf::services::air_quality_cache_service::AirQualityCacheService;
+use crate::org::unibl::etf::services::air_quality_service::AirQualityService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/air_quality_by_coordinates", web::get().to(get_air_quality_by_coordinates))
+        .route("/air_quality_by_location", web::get().to(get_air_quality_by_location));
+}
+
+#[tracing::instrument(
+    name = "Get Air Quality Controller",
+    skip(http_client, air_quality_service, cache_service_settings, providers_configuration)
+)]
+async fn get_air_quality_by_coordinates(
+    query: Query<UpstreamCurrentWeatherRequestByCoordinates>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    air_quality_service: web::Data<AirQualityService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = AirQualityCoordinatesQuery {
+        request: query.into_inner(),
+        cache_service: AirQualityCacheService::default(),
+    };
+
+    let air_quality = air_quality_service
+        .get_air_quality(
+            query,
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(air_quality))
+}
+
+#[tracing::instrument(
+    name = "Get Air Quality Controller",
+    skip(http_client, air_quality_service, cache_service_settings, providers_configuration)
+)]
+async fn get_air_quality_by_location(
+    query: Query<UpstreamCurrentWeatherRequestByLocation>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    air_quality_service: web::Data<AirQualityService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = AirQualityLocationQuery {
+        request: query.into_inner(),
+        cache_service: AirQualityCacheService::default(),
+    };
+
+    let air_quality = air_quality_service
+        .get_air_quality(
+            query,
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(air_quality))
+}
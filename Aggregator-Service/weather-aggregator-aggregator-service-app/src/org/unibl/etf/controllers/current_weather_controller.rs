@@ -1,11 +1,11 @@
 use std::net::{IpAddr};
 use actix_web::{web, HttpRequest, HttpResponse, Responder};
-use actix_web::dev::ConnectionInfo;
 use actix_web_validator::Query;
 use reqwest_middleware::ClientWithMiddleware;
-use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings, StrategySettings};
 use crate::org::unibl::etf::controllers::errors::{GenericServiceError, GenericServiceErrorDetails};
 use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::requests::aggregation_options::AggregationOptions;
 use crate::org::unibl::etf::model::requests::current_weather_coordinates_query::CurrentWeatherCoordinatesQuery;
 use crate::org::unibl::etf::model::requests::current_weather_ip_address_query::CurrentWeatherIpAddressQuery;
 use crate::org::unibl::etf::model::requests::current_weather_location_query::CurrentWeatherLocationQuery;
@@ -28,14 +28,17 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         http_client,
         current_weather_service,
         cache_service_settings,
-        providers_configuration
+        providers_configuration,
+        strategy_settings
     )
 )]
 async fn get_current_weather_data_by_coordinates(
     query: Query<UpstreamCurrentWeatherRequestByCoordinates>,
+    options: Query<AggregationOptions>,
     http_client: web::Data<ClientWithMiddleware>,
     providers_configuration: web::Data<Vec<ProviderSettings>>,
     cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
     current_weather_service: web::Data<CurrentWeatherService>
 ) -> Result<impl Responder, GenericServiceError> {
     let data = CurrentWeatherCoordinatesQuery {
@@ -46,9 +49,11 @@ async fn get_current_weather_data_by_coordinates(
     let res = current_weather_service
         .get_current_weather(
             data,
+            options.into_inner(),
             http_client,
             providers_configuration,
             cache_service_settings,
+            strategy_settings,
         )
         .await
         .and_then(|current_weather_data|
@@ -66,14 +71,17 @@ async fn get_current_weather_data_by_coordinates(
         http_client,
         current_weather_service,
         cache_service_settings,
-        providers_configuration
+        providers_configuration,
+        strategy_settings
     )
 )]
 async fn get_current_weather_data_by_location(
     query: Query<UpstreamCurrentWeatherRequestByLocation>,
+    options: Query<AggregationOptions>,
     http_client: web::Data<ClientWithMiddleware>,
     providers_configuration: web::Data<Vec<ProviderSettings>>,
     cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
     current_weather_service: web::Data<CurrentWeatherService>
 ) -> Result<impl Responder, GenericServiceError> {
     let query = CurrentWeatherLocationQuery {
@@ -84,9 +92,11 @@ async fn get_current_weather_data_by_location(
     let res = current_weather_service
         .get_current_weather(
             query,
+            options.into_inner(),
             http_client,
             providers_configuration,
             cache_service_settings,
+            strategy_settings,
         )
         .await
         .and_then(|current_weather_data|
@@ -104,16 +114,19 @@ async fn get_current_weather_data_by_location(
         http_client,
         current_weather_service,
         cache_service_settings,
-        providers_configuration)
+        providers_configuration,
+        strategy_settings)
 )]
 async fn get_current_weather_data_by_ip_address(
+    options: Query<AggregationOptions>,
     http_client: web::Data<ClientWithMiddleware>,
     providers_configuration: web::Data<Vec<ProviderSettings>>,
     cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
     current_weather_service: web::Data<CurrentWeatherService>,
-    conn: ConnectionInfo,
     req: HttpRequest
 ) -> Result<impl Responder, GenericServiceError> {
+    let conn = req.connection_info().clone();
     let ip_str = conn.realip_remote_addr().unwrap_or("");
 
     let ip: IpAddr = ip_str.parse::<IpAddr>().map_err(|e| {
@@ -147,9 +160,11 @@ async fn get_current_weather_data_by_ip_address(
     let res = current_weather_service
         .get_current_weather(
             query,
+            options.into_inner(),
             http_client,
             providers_configuration,
             cache_service_settings,
+            strategy_settings,
         ).await
         .and_then(|current_weather_data|
             Ok(HttpResponse::Ok()
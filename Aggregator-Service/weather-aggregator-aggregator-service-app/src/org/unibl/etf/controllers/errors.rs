@@ -1,6 +1,6 @@
 use std::fmt;
 use actix_web::{error, HttpResponse};
-use actix_web::http::StatusCode;
+use actix_web::http::{header, StatusCode};
 use chrono::{DateTime, Utc};
 use serde::{Serialize};
 
@@ -52,6 +52,9 @@ impl error::ResponseError for GenericServiceError {
             AggregatorError::AmbiguousLocationNameError(_) => {
                 StatusCode::CONFLICT
             },
+            AggregatorError::RateLimitExceeded(_) => {
+                StatusCode::TOO_MANY_REQUESTS
+            },
             _ => {
                 StatusCode::INTERNAL_SERVER_ERROR
             },
@@ -64,7 +67,15 @@ impl error::ResponseError for GenericServiceError {
 
         sanitized_details.code = sanitized_details.code.get_sanitized_error();
 
-        HttpResponse::build(self.status_code())
+        let mut response = HttpResponse::build(self.status_code());
+        if let AggregatorError::RateLimitExceeded(status) = self.error.code {
+            for rate_limit_header in status.headers() {
+                response.insert_header(rate_limit_header);
+            }
+            response.insert_header((header::RETRY_AFTER, status.reset_in_seconds));
+        }
+
+        response
             .json(GenericServiceError {
                 error: sanitized_details,
             })
@@ -0,0 +1,149 @@
+use actix_web::{web, HttpResponse, Responder};
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings, StrategySettings};
+use crate::org::unibl::etf::controllers::errors::GenericServiceError;
+use crate::org::unibl::etf::model::requests::aggregation_options::AggregationOptions;
+use crate::org::unibl::etf::model::requests::forecast_coordinates_query::ForecastCoordinatesQuery;
+use crate::org::unibl::etf::model::requests::forecast_location_query::ForecastLocationQuery;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_coordinates::UpstreamCurrentWeatherRequestByCoordinates;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_location::UpstreamCurrentWeatherRequestByLocation;
+use crate::org::unibl::etf::model::responses::forecast_response::{DailyForecastResponse, HourlyForecastResponse};
+use crate::org::unibl::etf::services::forecast_cache_service::ForecastCacheService;
+use crate::org::unibl::etf::services::forecast_service::ForecastService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/hourly_forecast_by_coordinates", web::get().to(get_hourly_forecast_by_coordinates))
+        .route("/hourly_forecast_by_location", web::get().to(get_hourly_forecast_by_location))
+        .route("/daily_forecast_by_coordinates", web::get().to(get_daily_forecast_by_coordinates))
+        .route("/daily_forecast_by_location", web::get().to(get_daily_forecast_by_location));
+}
+
+#[tracing::instrument(
+    name = "Get Hourly Forecast Controller",
+    skip(http_client, forecast_service, cache_service_settings, providers_configuration, strategy_settings)
+)]
+async fn get_hourly_forecast_by_coordinates(
+    query: Query<UpstreamCurrentWeatherRequestByCoordinates>,
+    options: Query<AggregationOptions>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
+    forecast_service: web::Data<ForecastService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = ForecastCoordinatesQuery {
+        request: query.into_inner(),
+        cache_service: ForecastCacheService::default(),
+    };
+
+    let forecast = forecast_service
+        .get_forecast(
+            query,
+            options.into_inner(),
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+            strategy_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(HourlyForecastResponse::from(forecast)))
+}
+
+#[tracing::instrument(
+    name = "Get Hourly Forecast Controller",
+    skip(http_client, forecast_service, cache_service_settings, providers_configuration, strategy_settings)
+)]
+async fn get_hourly_forecast_by_location(
+    query: Query<UpstreamCurrentWeatherRequestByLocation>,
+    options: Query<AggregationOptions>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
+    forecast_service: web::Data<ForecastService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = ForecastLocationQuery {
+        request: query.into_inner(),
+        cache_service: ForecastCacheService::default(),
+    };
+
+    let forecast = forecast_service
+        .get_forecast(
+            query,
+            options.into_inner(),
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+            strategy_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(HourlyForecastResponse::from(forecast)))
+}
+
+#[tracing::instrument(
+    name = "Get Daily Forecast Controller",
+    skip(http_client, forecast_service, cache_service_settings, providers_configuration, strategy_settings)
+)]
+async fn get_daily_forecast_by_coordinates(
+    query: Query<UpstreamCurrentWeatherRequestByCoordinates>,
+    options: Query<AggregationOptions>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
+    forecast_service: web::Data<ForecastService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = ForecastCoordinatesQuery {
+        request: query.into_inner(),
+        cache_service: ForecastCacheService::default(),
+    };
+
+    let forecast = forecast_service
+        .get_forecast(
+            query,
+            options.into_inner(),
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+            strategy_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(DailyForecastResponse::from(forecast)))
+}
+
+#[tracing::instrument(
+    name = "Get Daily Forecast Controller",
+    skip(http_client, forecast_service, cache_service_settings, providers_configuration, strategy_settings)
+)]
+async fn get_daily_forecast_by_location(
+    query: Query<UpstreamCurrentWeatherRequestByLocation>,
+    options: Query<AggregationOptions>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
+    forecast_service: web::Data<ForecastService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = ForecastLocationQuery {
+        request: query.into_inner(),
+        cache_service: ForecastCacheService::default(),
+    };
+
+    let forecast = forecast_service
+        .get_forecast(
+            query,
+            options.into_inner(),
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+            strategy_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(DailyForecastResponse::from(forecast)))
+}
@@ -0,0 +1,82 @@
+use actix_web::{web, HttpResponse, Responder};
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings, StrategySettings};
+use crate::org::unibl::etf::controllers::errors::GenericServiceError;
+use crate::org::unibl::etf::model::requests::aggregation_options::AggregationOptions;
+use crate::org::unibl::etf::model::requests::historical_weather_coordinates_query::HistoricalWeatherCoordinatesQuery;
+use crate::org::unibl::etf::model::requests::historical_weather_location_query::HistoricalWeatherLocationQuery;
+use crate::org::unibl::etf::model::requests::upstream_historical_weather_request_by_coordinates::UpstreamHistoricalWeatherRequestByCoordinates;
+use crate::org::unibl::etf::model::requests::upstream_historical_weather_request_by_location::UpstreamHistoricalWeatherRequestByLocation;
+use crate::org::unibl::etf::services::historical_weather_cache_service::HistoricalWeatherCacheService;
+use crate::org::unibl::etf::services::historical_weather_service::HistoricalWeatherService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/historical_weather_by_coordinates", web::get().to(get_historical_weather_by_coordinates))
+        .route("/historical_weather_by_location", web::get().to(get_historical_weather_by_location));
+}
+
+#[tracing::instrument(
+    name = "Get Historical Weather Controller",
+    skip(http_client, historical_weather_service, cache_service_settings, providers_configuration, strategy_settings)
+)]
+async fn get_historical_weather_by_coordinates(
+    query: Query<UpstreamHistoricalWeatherRequestByCoordinates>,
+    options: Query<AggregationOptions>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
+    historical_weather_service: web::Data<HistoricalWeatherService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = HistoricalWeatherCoordinatesQuery {
+        request: query.into_inner(),
+        cache_service: HistoricalWeatherCacheService::default(),
+    };
+
+    let observations = historical_weather_service
+        .get_historical_weather(
+            query,
+            options.into_inner(),
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+            strategy_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(observations))
+}
+
+#[tracing::instrument(
+    name = "Get Historical Weather Controller",
+    skip(http_client, historical_weather_service, cache_service_settings, providers_configuration, strategy_settings)
+)]
+async fn get_historical_weather_by_location(
+    query: Query<UpstreamHistoricalWeatherRequestByLocation>,
+    options: Query<AggregationOptions>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    cache_service_settings: web::Data<CacheServiceSettings>,
+    strategy_settings: web::Data<StrategySettings>,
+    historical_weather_service: web::Data<HistoricalWeatherService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = HistoricalWeatherLocationQuery {
+        request: query.into_inner(),
+        cache_service: HistoricalWeatherCacheService::default(),
+    };
+
+    let observations = historical_weather_service
+        .get_historical_weather(
+            query,
+            options.into_inner(),
+            http_client,
+            providers_configuration,
+            cache_service_settings,
+            strategy_settings,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(observations))
+}
@@ -0,0 +1,58 @@
+use actix_web::{web, HttpResponse, Responder};
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::ProviderSettings;
+use crate::org::unibl::etf::controllers::errors::GenericServiceError;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_coordinates::UpstreamCurrentWeatherRequestByCoordinates;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_location::UpstreamCurrentWeatherRequestByLocation;
+use crate::org::unibl::etf::model::requests::weather_alerts_coordinates_query::WeatherAlertsCoordinatesQuery;
+use crate::org::unibl::etf::model::requests::weather_alerts_location_query::WeatherAlertsLocationQuery;
+use crate::org::unibl::etf::services::weather_alerts_service::WeatherAlertsService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .route("/weather_alerts_by_coordinates", web::get().to(get_weather_alerts_by_coordinates))
+        .route("/weather_alerts_by_location", web::get().to(get_weather_alerts_by_location));
+}
+
+#[tracing::instrument(
+    name = "Get Weather Alerts Controller",
+    skip(http_client, weather_alerts_service, providers_configuration)
+)]
+async fn get_weather_alerts_by_coordinates(
+    query: Query<UpstreamCurrentWeatherRequestByCoordinates>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    weather_alerts_service: web::Data<WeatherAlertsService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = WeatherAlertsCoordinatesQuery {
+        request: query.into_inner(),
+    };
+
+    let alerts = weather_alerts_service
+        .get_weather_alerts(query, http_client, providers_configuration)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(alerts))
+}
+
+#[tracing::instrument(
+    name = "Get Weather Alerts Controller",
+    skip(http_client, weather_alerts_service, providers_configuration)
+)]
+async fn get_weather_alerts_by_location(
+    query: Query<UpstreamCurrentWeatherRequestByLocation>,
+    http_client: web::Data<ClientWithMiddleware>,
+    providers_configuration: web::Data<Vec<ProviderSettings>>,
+    weather_alerts_service: web::Data<WeatherAlertsService>
+) -> Result<impl Responder, GenericServiceError> {
+    let query = WeatherAlertsLocationQuery {
+        request: query.into_inner(),
+    };
+
+    let alerts = weather_alerts_service
+        .get_weather_alerts(query, http_client, providers_configuration)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(alerts))
+}
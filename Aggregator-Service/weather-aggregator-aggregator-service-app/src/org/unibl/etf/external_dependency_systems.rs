@@ -0,0 +1 @@
+pub mod redis_store;
@@ -0,0 +1,9 @@
+use std::io;
+use deadpool_redis::{Config, Runtime};
+
+pub fn create_redis_pool(connection_uri: String) -> Result<deadpool_redis::Pool, io::Error> {
+    let cfg = Config::from_url(connection_uri);
+
+    cfg.create_pool(Some(Runtime::Tokio1))
+        .map_err(|e| io::Error::other(format!("Failed to create Redis pool. {}", e)))
+}
@@ -0,0 +1,4 @@
+pub mod claims;
+pub mod jwks;
+pub mod jwkey;
+pub mod token_type;
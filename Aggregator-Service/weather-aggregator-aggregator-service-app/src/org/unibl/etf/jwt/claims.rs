@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::jwt::token_type::TokenType;
+use crate::org::unibl::etf::model::user_type::UserType;
+
+// Claims of the tokens issued by the User Identity Service.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub user_type: UserType,
+    pub exp: usize,
+    pub iat: usize,
+    pub iss: String,
+    pub typ: TokenType,
+}
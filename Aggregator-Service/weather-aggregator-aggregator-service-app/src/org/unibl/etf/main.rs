@@ -1,7 +1,9 @@
 
+use std::io;
 use std::net::TcpListener;
 use rustls::crypto::CryptoProvider;
 use weather_aggregator_aggregator_service_app::org::unibl::etf::configuration::get_configuration;
+use weather_aggregator_aggregator_service_app::org::unibl::etf::external_dependency_systems::redis_store::create_redis_pool;
 use weather_aggregator_aggregator_service_app::org::unibl::etf::startup::run;
 use weather_aggregator_aggregator_service_app::org::unibl::etf::telemetry::{get_subscriber, init_subscriber};
 
@@ -27,6 +29,27 @@ async fn main() -> std::io::Result<()> {
     let subscriber = get_subscriber("Aggregator Service".into(), "info".into(), std::io::stdout, configuration.tracing_agent.clone());
     init_subscriber(subscriber);
 
+    // The counters live in Redis, so the store is only required once client rate limiting is turned on.
+    let redis_pool = if configuration.client_rate_limit.enabled {
+        let redis_connection_uri = configuration
+            .redis_store
+            .as_ref()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Client rate limiting requires redis store settings."))?
+            .get_redis_config()?;
+        Some(create_redis_pool(redis_connection_uri)?)
+    } else {
+        None
+    };
+
+    let signer_jwt_public_key = match &configuration.jwt {
+        Some(jwt_settings) => Some(
+            jwt_settings
+                .get_signer_jwt_public_key(reqwest::Client::new())
+                .await?
+        ),
+        None => None,
+    };
+
     let address = format!(
         "{}:{}",
         configuration.application.host,
@@ -36,11 +59,20 @@ async fn main() -> std::io::Result<()> {
     let listener = TcpListener::bind(address)
         .expect("Failed to bind to specified address.");
 
+    let internal_listener = configuration
+        .application
+        .internal_port
+        .map(|port| TcpListener::bind(format!("{}:{}", configuration.application.internal_host, port)))
+        .transpose()?;
+
     let res = run(
         listener,
+        internal_listener,
         configuration,
         http_server_config,
-        http_client_config
+        http_client_config,
+        redis_pool,
+        signer_jwt_public_key,
     )?.await;
 
     res
@@ -1 +1,2 @@
-pub mod json_500_middleware;
\ No newline at end of file
+pub mod json_500_middleware;
+pub mod client_rate_limit_middleware;
\ No newline at end of file
@@ -0,0 +1,121 @@
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::X_FORWARDED_FOR,
+    Error, HttpResponse, ResponseError,
+};
+use futures_util::future::{ok, LocalBoxFuture, Ready};
+use std::rc::Rc;
+use std::sync::Arc;
+use crate::org::unibl::etf::controllers::errors::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::services::client_rate_limit_service::ClientRateLimitService;
+
+pub const ACCESS_TOKEN_COOKIE_NAME: &str = "access_token";
+
+// Counts every request against its client's limits and answers 429 once a limit is reached.
+pub struct ClientRateLimitMiddleware {
+    pub client_rate_limit_service: Arc<ClientRateLimitService>,
+    // None when client rate limiting is disabled.
+    pub redis_pool: Option<deadpool_redis::Pool>,
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ClientRateLimitMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = ClientRateLimitMiddlewareInner<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(ClientRateLimitMiddlewareInner {
+            service: Rc::new(service),
+            client_rate_limit_service: self.client_rate_limit_service.clone(),
+            redis_pool: self.redis_pool.clone(),
+        })
+    }
+}
+
+pub struct ClientRateLimitMiddlewareInner<S> {
+    service: Rc<S>,
+    client_rate_limit_service: Arc<ClientRateLimitService>,
+    redis_pool: Option<deadpool_redis::Pool>,
+}
+
+impl<S, B> Service<ServiceRequest> for ClientRateLimitMiddlewareInner<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(
+        &self,
+        ctx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let client_rate_limit_service = self.client_rate_limit_service.clone();
+        let redis_pool = self.redis_pool.clone();
+
+        Box::pin(async move {
+            let Some(redis_pool) = redis_pool else {
+                return Ok(svc.call(req).await?.map_into_left_body());
+            };
+
+            let forwarded_for = req
+                .headers()
+                .get_all(X_FORWARDED_FOR)
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .map(str::trim)
+                .collect::<Vec<_>>();
+            let ip = client_rate_limit_service
+                .resolve_client_ip(req.peer_addr().map(|addr| addr.ip()), &forwarded_for);
+            let access_token = req.cookie(ACCESS_TOKEN_COOKIE_NAME);
+            let client = client_rate_limit_service
+                .identify_client(access_token.as_ref().map(|cookie| cookie.value()), ip);
+
+            // Lumping unidentifiable callers together would let one of them exhaust the limit for all the others.
+            let Some(client) = client else {
+                tracing::warn!("Client address could not be determined, request is not rate limited.");
+                return Ok(svc.call(req).await?.map_into_left_body());
+            };
+
+            let status = match client_rate_limit_service.acquire_client_request(&client, &redis_pool).await {
+                Ok(status) => status,
+                Err(e @ AggregatorError::RateLimitExceeded(_)) => {
+                    let error = GenericServiceError {
+                        error: GenericServiceErrorDetails::new_aggregator_error(e),
+                    };
+                    let (req, _) = req.into_parts();
+                    let response: HttpResponse = error.error_response();
+                    return Ok(ServiceResponse::new(req, response.map_into_right_body()));
+                },
+                // Losing the counters must not take the API down with them.
+                Err(e) => {
+                    tracing::error!("Client rate limit could not be checked, letting the request through. {:?}", e);
+                    None
+                }
+            };
+
+            let mut res = svc.call(req).await?;
+            if let Some(status) = status {
+                for (name, value) in status.headers() {
+                    res.headers_mut().insert(name, value);
+                }
+            }
+
+            Ok(res.map_into_left_body())
+        })
+    }
+}
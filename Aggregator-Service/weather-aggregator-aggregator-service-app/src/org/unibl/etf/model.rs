@@ -1,3 +1,6 @@
 pub mod responses;
 pub mod errors;
 pub mod requests;
+pub mod user_type;
+pub mod client_rate_limit_status;
+pub mod provider_endpoint;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use actix_web::http::header::{HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+const RATE_LIMIT_LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const RATE_LIMIT_REMAINING_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const RATE_LIMIT_RESET_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+// State of the tightest window a client request was counted against.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ClientRateLimitStatus {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset_in_seconds: u64,
+}
+
+impl ClientRateLimitStatus {
+    pub fn headers(&self) -> [(HeaderName, HeaderValue); 3] {
+        [
+            (RATE_LIMIT_LIMIT_HEADER, HeaderValue::from(self.limit)),
+            (RATE_LIMIT_REMAINING_HEADER, HeaderValue::from(self.remaining)),
+            (RATE_LIMIT_RESET_HEADER, HeaderValue::from(self.reset_in_seconds)),
+        ]
+    }
+}
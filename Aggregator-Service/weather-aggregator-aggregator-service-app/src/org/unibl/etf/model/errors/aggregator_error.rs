@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::client_rate_limit_status::ClientRateLimitStatus;
 use crate::org::unibl::etf::model::errors::cache_service_error::CacheError;
 use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::{AdapterError, LocationCandidate};
 use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
@@ -19,7 +20,10 @@ pub enum AggregatorError {
     IpLookupNotSupported,
     OnlyPotentialMatchesFoundError(Vec<CurrentWeatherResponse>),
     CacheNotSupported(Option<String>),
-
+    CircuitOpenError,
+    ProviderTimeoutError,
+    RateLimitExceeded(ClientRateLimitStatus),
+    ProviderRateLimitedError(Option<u64>),
 }
 
 impl AggregatorError {
@@ -30,6 +34,7 @@ impl AggregatorError {
             Self::RequestParametersValidationError(s) => Self::RequestParametersValidationError(s.clone()),
             Self::AmbiguousLocationNameError(s) => Self::AmbiguousLocationNameError(s.clone()),
             Self::LocalIpError => Self::LocalIpError,
+            Self::RateLimitExceeded(status) => Self::RateLimitExceeded(*status),
             _ => Self::ServerError(None),
         }
     }
@@ -39,11 +44,40 @@ impl AggregatorError {
             Self::RequestParametersValidationError(s) => s.clone().unwrap_or(String::default()),
             Self::AmbiguousLocationNameError(_s) => self.get_message(),
             Self::LocalIpError => self.get_message(),
+            Self::RateLimitExceeded(_) => self.get_message(),
             _ => String::default(),
         }
 
     }
 
+    // Keeps the error kind but drops free-form messages, which can carry raw upstream bodies.
+    pub fn without_details(&self) -> Self {
+        match self {
+            Self::RequestParametersValidationError(_) => Self::RequestParametersValidationError(None),
+            Self::ServerError(_) => Self::ServerError(None),
+            Self::LocationNotFoundError(_) => Self::LocationNotFoundError(None),
+            Self::ConnectionError(_) => Self::ConnectionError(None),
+            Self::ResponseParsingError(_) => Self::ResponseParsingError(None),
+            Self::StoringCacheError(_) => Self::StoringCacheError(None),
+            Self::CacheNotSupported(_) => Self::CacheNotSupported(None),
+            Self::OnlyPotentialMatchesFoundError(_) => Self::OnlyPotentialMatchesFoundError(Vec::new()),
+            e => e.clone(),
+        }
+    }
+
+    // Only errors that say something about the provider's health count against its circuit breaker.
+    pub fn is_provider_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::ServerError(_) | Self::ConnectionError(_) | Self::ResponseParsingError(_) | Self::ProviderTimeoutError
+        )
+    }
+
+    // Providers that were never asked have no say in whether the location exists.
+    pub fn is_provider_skipped(&self) -> bool {
+        matches!(self, Self::CircuitOpenError | Self::ProviderRateLimitedError(_))
+    }
+
     pub fn get_message(&self) -> String {
         match self {
             AggregatorError::RequestParametersValidationError(msg) => {
@@ -59,6 +93,12 @@ impl AggregatorError {
             //AggregatorError::ServerError(s) => format!("ServerError: {}", s.clone().unwrap_or(String::from(""))),
             AggregatorError::ResponseParsingError(s) => format!("ResponseParsingError: {}", s.clone().unwrap_or(String::from(""))),
             AggregatorError::LocalIpError => String::from("Request made with local ip address. Can not determine location by IP address."),
+            AggregatorError::CircuitOpenError => String::from("Provider skipped because its circuit breaker is open"),
+            AggregatorError::ProviderTimeoutError => String::from("Provider did not respond in time"),
+            AggregatorError::ProviderRateLimitedError(_) => String::from("Provider skipped because its request quota is used up"),
+            AggregatorError::RateLimitExceeded(status) => {
+                format!("Rate limit exceeded. Try again in {} seconds.", status.reset_in_seconds)
+            },
             _ => { String::default() }
         }
     }
@@ -79,6 +119,10 @@ impl AggregatorError {
                 404
             },
             AggregatorError::LocalIpError => 400,
+            AggregatorError::CircuitOpenError => 503,
+            AggregatorError::ProviderTimeoutError => 504,
+            AggregatorError::RateLimitExceeded(_) => 429,
+            AggregatorError::ProviderRateLimitedError(_) => 503,
             _ => {
                 500
             },
@@ -96,6 +140,9 @@ impl From<AdapterError> for AggregatorError {
             AdapterError::LocationNotFoundError(s) => {
                 AggregatorError::LocationNotFoundError(s)
             }
+            AdapterError::ProviderRateLimitedError(retry_after_in_seconds) => {
+                AggregatorError::ProviderRateLimitedError(retry_after_in_seconds)
+            }
             AdapterError::ServerError => {
                 AggregatorError::ServerError(None)
             }
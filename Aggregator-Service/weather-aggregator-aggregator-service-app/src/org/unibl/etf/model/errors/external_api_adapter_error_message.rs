@@ -24,6 +24,8 @@ pub enum AdapterError {
     // This matches "AMBIGUOUS_LOCATION": [...]
     AmbiguousLocationNameError(Vec<LocationCandidate>),
     LocationNotFoundError(Option<String>),
+    // Seconds until the adapter's own provider quota lets another call through.
+    ProviderRateLimitedError(Option<u64>),
     ServerError,
 }
 
@@ -32,6 +34,8 @@ pub enum AdapterError {
 pub enum RawAdapterError {
     LocationNotFoundError(Option<String>),
     AmbiguousLocationNameError(Vec<LocationCandidate>),
+    // Carries the seconds until the provider accepts another call.
+    RateLimitExceeded(Option<u64>),
     ServerError,
     #[serde(other)]
     Unknown,
@@ -40,6 +44,7 @@ pub enum RawAdapterError {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LocationCandidate {
     pub location_name: String,
+    #[serde(default)]
     pub state: String,
     pub country: String,
     pub lat: f64,
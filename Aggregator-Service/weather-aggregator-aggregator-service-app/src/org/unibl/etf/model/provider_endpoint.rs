@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+// Endpoints an adapter can serve, named as they appear in the adapter's routes.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderEndpoint {
+    CurrentWeather,
+    Forecast,
+    HistoricalWeather,
+    WeatherAlerts,
+    AirQuality,
+}
+
+impl ProviderEndpoint {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProviderEndpoint::CurrentWeather => "current_weather",
+            ProviderEndpoint::Forecast => "forecast",
+            ProviderEndpoint::HistoricalWeather => "historical_weather",
+            ProviderEndpoint::WeatherAlerts => "weather_alerts",
+            ProviderEndpoint::AirQuality => "air_quality",
+        }
+    }
+}
@@ -8,4 +8,24 @@ pub mod current_weather_coordinates_query;
 pub mod current_weather_location_query;
 pub mod current_weather_ip_address_query;
 pub mod retrieve_current_weather_cache_request;
-
+pub mod aggregation_options;
+pub mod forecast_query;
+pub mod forecast_coordinates_query;
+pub mod forecast_location_query;
+pub mod store_forecast_cache_request;
+pub mod date_range;
+pub mod upstream_historical_weather_request_by_coordinates;
+pub mod upstream_historical_weather_request_by_location;
+pub mod store_historical_weather_cache_request;
+pub mod retrieve_historical_weather_cache_request;
+pub mod downstream_historical_weather_request;
+pub mod historical_weather_query;
+pub mod historical_weather_coordinates_query;
+pub mod historical_weather_location_query;
+pub mod weather_alerts_query;
+pub mod weather_alerts_coordinates_query;
+pub mod weather_alerts_location_query;
+pub mod air_quality_query;
+pub mod air_quality_coordinates_query;
+pub mod air_quality_location_query;
+pub mod store_air_quality_cache_request;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::org::unibl::etf::strategy::strategy_kind::StrategyKind;
+
+
+#[derive(Serialize, Debug, Deserialize, Validate, Clone, Default)]
+pub struct AggregationOptions {
+    pub strategy: Option<StrategyKind>,
+    #[serde(default)]
+    pub include_aggregation: bool,
+}
@@ -0,0 +1,87 @@
+use reqwest_middleware::ClientWithMiddleware;
+
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::requests::downstream_current_weather_request::DownstreamCurrentWeatherRequest;
+use crate::org::unibl::etf::model::requests::air_quality_query::AirQualityQuery;
+use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::RetrieveCurrentWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::store_air_quality_cache_request::StoreAirQualityCacheRequest;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_coordinates::UpstreamCurrentWeatherRequestByCoordinates;
+use crate::org::unibl::etf::model::responses::air_quality_response::AirQualityResponse;
+use crate::org::unibl::etf::services::air_quality_cache_service::AirQualityCacheService;
+use crate::org::unibl::etf::util::parse_provider_response;
+
+#[derive(Debug, Clone)]
+pub struct AirQualityCoordinatesQuery {
+    pub request: UpstreamCurrentWeatherRequestByCoordinates,
+    pub cache_service: AirQualityCacheService,
+}
+
+#[async_trait::async_trait]
+impl AirQualityQuery for AirQualityCoordinatesQuery {
+    type NewDataRequest = DownstreamCurrentWeatherRequest;
+    type RetrieveCacheRequest = RetrieveCurrentWeatherCacheRequest;
+    type StoreCacheRequest = StoreAirQualityCacheRequest;
+
+    fn build_downstream_request(&self) -> Result<Self::NewDataRequest, AggregatorError> {
+        DownstreamCurrentWeatherRequest::try_from(&self.request)
+            .map_err(|e| AggregatorError::ServerError(Some(e.to_string())))
+    }
+
+    fn build_retrieve_cache_request(&self) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
+        Ok(RetrieveCurrentWeatherCacheRequest {
+            location_name: None,
+            lat: Some(self.request.lat),
+            lon: Some(self.request.lon),
+            country: None,
+            state: None,
+            allow_stale: false,
+        })
+    }
+
+    fn build_store_cache_request(&self, air_quality: &AirQualityResponse, location_names: Vec<String>) -> Result<Self::StoreCacheRequest, AggregatorError> {
+        Ok(StoreAirQualityCacheRequest {
+            lat: self.request.lat,
+            lon: self.request.lon,
+            air_quality_data: air_quality.clone(),
+            location_names,
+        })
+    }
+
+    async fn cache_get(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        client: &ClientWithMiddleware,
+        cache_settings: &CacheServiceSettings,
+    ) -> Result<AirQualityResponse, AggregatorError> {
+        self.cache_service.get_cached_air_quality_data_by_coordinates(req, client, cache_settings).await
+    }
+
+    async fn call_provider(
+        &self,
+        provider: &ProviderSettings,
+        req: &DownstreamCurrentWeatherRequest,
+        client: &ClientWithMiddleware,
+    ) -> Result<AirQualityResponse, AggregatorError> {
+        let url = provider.endpoint_url(ProviderEndpoint::AirQuality);
+
+        let response = client
+            .get(url)
+            .query(&[("lat", req.lat), ("lon", req.lon)])
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        parse_provider_response(provider, response).await
+    }
+
+    async fn cache_set(
+        &self,
+        req: &StoreAirQualityCacheRequest,
+        client: &ClientWithMiddleware,
+        cache_settings: &CacheServiceSettings,
+    ) -> Result<(), AggregatorError> {
+        self.cache_service.save_air_quality_data_to_cache(client, cache_settings, req).await
+    }
+}
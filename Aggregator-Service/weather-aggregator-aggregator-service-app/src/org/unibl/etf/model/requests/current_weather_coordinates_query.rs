@@ -1,5 +1,6 @@
 use reqwest_middleware::ClientWithMiddleware;
 
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
 use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
 use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
 use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::AdapterServiceError;
@@ -29,16 +30,26 @@ impl CurrentWeatherQuery for CurrentWeatherCoordinatesQuery {
             .map_err(|e| AggregatorError::ServerError(Some(e.to_string())))
     }
 
-    fn build_retrieve_cache_request(&self) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
+    fn coalescing_key(&self) -> String {
+        // Four decimal places is roughly 11 m, well below what providers resolve.
+        format!("coordinates:{:.4}:{:.4}", self.request.lat, self.request.lon)
+    }
+
+    fn build_retrieve_cache_request(&self, allow_stale: bool) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
         Ok(RetrieveCurrentWeatherCacheRequest {
             location_name: None,
             lat: Some(self.request.lat),
             lon: Some(self.request.lon),
             country: None,
             state: None,
+            allow_stale,
         })
     }
 
+    fn local_cache_key(&self) -> Option<String> {
+        self.build_retrieve_cache_request(false).ok().map(|req| req.local_cache_key())
+    }
+
     fn build_store_cache_request(&self, current_weather_response: &CurrentWeatherResponse, location_names: Vec<String>) -> Result<Self::StoreCacheRequest, AggregatorError> {
         Ok(StoreCurrentWeatherCacheRequest {
             lat: self.request.lat,
@@ -55,7 +66,33 @@ impl CurrentWeatherQuery for CurrentWeatherCoordinatesQuery {
         client: &ClientWithMiddleware,
         cache_settings: &CacheServiceSettings,
     ) -> Result<CurrentWeatherResponse, AggregatorError> {
-        self.cache_service.get_cached_current_weather_data_by_coordinates(req, client, cache_settings).await
+        let cached = self.cache_service.get_cached_current_weather_data_by_coordinates(req, client, cache_settings).await;
+        if cached.is_ok() || req.allow_stale || cache_settings.nearby_radius_in_meters <= 0.0 {
+            return cached;
+        }
+
+        let nearby = self.cache_service
+            .get_nearby_cached_current_weather_data(
+                self.request.lat,
+                self.request.lon,
+                cache_settings.nearby_radius_in_meters / 1000.0,
+                client,
+                cache_settings,
+            )
+            .await;
+
+        // Observations come back closest first.
+        match nearby.map(|nearby| nearby.observations.into_iter().next()) {
+            Ok(Some(observation)) => {
+                tracing::info!("Answering from a cached observation {:.2} km away.", observation.distance_km);
+                Ok(observation.data)
+            },
+            Ok(None) => cached,
+            Err(e) => {
+                tracing::error!("Was not able to get nearby cached current weather data: {:?}", e);
+                cached
+            },
+        }
     }
 
     async fn call_provider(
@@ -64,7 +101,7 @@ impl CurrentWeatherQuery for CurrentWeatherCoordinatesQuery {
         req: &DownstreamCurrentWeatherRequest,
         client: &ClientWithMiddleware,
     ) -> Result<CurrentWeatherResponse, AggregatorError> {
-        let url = format!("{}://{}:{}/api/v1/current_weather", provider.scheme, provider.host, provider.port);
+        let url = provider.endpoint_url(ProviderEndpoint::CurrentWeather);
 
         let response = client
             .get(url)
@@ -1,5 +1,6 @@
 use std::net::IpAddr;
 use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
 use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
 use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
 use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::AdapterServiceError;
@@ -26,10 +27,18 @@ impl CurrentWeatherQuery for CurrentWeatherIpAddressQuery {
             .map_err(|e| AggregatorError::ServerError(Some(e.to_string())))
     }
 
-    fn build_retrieve_cache_request(&self) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
+    fn coalescing_key(&self) -> String {
+        format!("ip:{}", self.request)
+    }
+
+    fn build_retrieve_cache_request(&self, _allow_stale: bool) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
         Err(AggregatorError::CacheNotSupported(None))
     }
 
+    fn local_cache_key(&self) -> Option<String> {
+        None
+    }
+
     fn build_store_cache_request(&self, _current_weather_response: &CurrentWeatherResponse, _location_names: Vec<String>) -> Result<Self::StoreCacheRequest, AggregatorError> {
         Err(AggregatorError::CacheNotSupported(None))
     }
@@ -52,7 +61,7 @@ impl CurrentWeatherQuery for CurrentWeatherIpAddressQuery {
         if provider.ip_support == false {
             return Err(AggregatorError::IpLookupNotSupported);
         }
-        let url = format!("{}://{}:{}/api/v1/current_weather", provider.scheme, provider.host, provider.port);
+        let url = provider.endpoint_url(ProviderEndpoint::CurrentWeather);
 
         let response = client
             .get(url)
@@ -1,4 +1,5 @@
 use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
 use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
 use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
 use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::AdapterServiceError;
@@ -28,16 +29,31 @@ impl CurrentWeatherQuery for CurrentWeatherLocationQuery {
             .map_err(|e| AggregatorError::ServerError(Some(e.to_string())))
     }
 
-    fn build_retrieve_cache_request(&self) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
+    fn coalescing_key(&self) -> String {
+        let normalize = |value: Option<&String>| value.map(|v| v.trim().to_lowercase()).unwrap_or_default();
+        format!(
+            "location:{}:{}:{}",
+            normalize(Some(&self.request.location_name)),
+            normalize(self.request.country.as_ref()),
+            normalize(self.request.state.as_ref()),
+        )
+    }
+
+    fn build_retrieve_cache_request(&self, allow_stale: bool) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
         Ok(RetrieveCurrentWeatherCacheRequest {
             location_name: Some(self.request.location_name.clone()),
             lat: None,
             lon: None,
             country: self.request.country.clone(),
             state: self.request.state.clone(),
+            allow_stale,
         })
     }
 
+    fn local_cache_key(&self) -> Option<String> {
+        self.build_retrieve_cache_request(false).ok().map(|req| req.local_cache_key())
+    }
+
     fn build_store_cache_request(&self, current_weather_response: &CurrentWeatherResponse, location_names: Vec<String>) -> Result<Self::StoreCacheRequest, AggregatorError> {
         Ok(StoreCurrentWeatherCacheRequest {
             lat: current_weather_response.location.clone().lat,
@@ -62,7 +78,7 @@ impl CurrentWeatherQuery for CurrentWeatherLocationQuery {
         req: &DownstreamCurrentWeatherRequest,
         client: &ClientWithMiddleware,
     ) -> Result<CurrentWeatherResponse, AggregatorError> {
-        let url = format!("{}://{}:{}/api/v1/current_weather", provider.scheme, provider.host, provider.port);
+        let url = provider.endpoint_url(ProviderEndpoint::CurrentWeather);
 
         let response = client
             .get(url)
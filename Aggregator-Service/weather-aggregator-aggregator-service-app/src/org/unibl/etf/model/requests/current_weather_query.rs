@@ -10,7 +10,12 @@ pub trait CurrentWeatherQuery {
     type StoreCacheRequest:  Clone + Send + Sync;
 
     fn build_downstream_request(&self) -> Result<Self::NewDataRequest, AggregatorError>;
-    fn build_retrieve_cache_request(&self) -> Result<Self::RetrieveCacheRequest, AggregatorError>;
+    // Identical concurrent queries share one provider fan-out, so equivalent inputs must produce the same key.
+    fn coalescing_key(&self) -> String;
+    // Stale requests may be answered with an expired copy the Cache Service keeps for longer.
+    fn build_retrieve_cache_request(&self, allow_stale: bool) -> Result<Self::RetrieveCacheRequest, AggregatorError>;
+    // None when results for this query are not cached.
+    fn local_cache_key(&self) -> Option<String>;
     fn build_store_cache_request(&self, request: &CurrentWeatherResponse, location_names: Vec<String>) -> Result<Self::StoreCacheRequest, AggregatorError>;
 
     async fn cache_get(
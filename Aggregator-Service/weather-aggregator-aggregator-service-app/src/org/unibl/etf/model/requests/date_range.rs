@@ -0,0 +1,27 @@
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use validator::ValidationError;
+
+pub const MAX_DATE_RANGE_IN_DAYS: i64 = 30;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct DateRange {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+pub fn validate_date_range(start_date: NaiveDate, end_date: NaiveDate) -> Result<(), ValidationError> {
+    if start_date > end_date {
+        return Err(ValidationError::new("date_range")
+            .with_message("Start date must not be after end date".into()));
+    }
+    if end_date >= Utc::now().date_naive() {
+        return Err(ValidationError::new("date_range")
+            .with_message("Historical weather is only available for past dates".into()));
+    }
+    if (end_date - start_date).num_days() >= MAX_DATE_RANGE_IN_DAYS {
+        return Err(ValidationError::new("date_range")
+            .with_message(format!("Date range must not exceed {} days", MAX_DATE_RANGE_IN_DAYS).into()));
+    }
+    Ok(())
+}
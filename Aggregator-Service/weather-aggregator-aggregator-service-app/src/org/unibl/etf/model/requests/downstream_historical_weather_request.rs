@@ -0,0 +1,8 @@
+use crate::org::unibl::etf::model::requests::date_range::DateRange;
+use crate::org::unibl::etf::model::requests::downstream_current_weather_request::DownstreamCurrentWeatherRequest;
+
+#[derive(Debug, Clone)]
+pub struct DownstreamHistoricalWeatherRequest {
+    pub location: DownstreamCurrentWeatherRequest,
+    pub date_range: DateRange,
+}
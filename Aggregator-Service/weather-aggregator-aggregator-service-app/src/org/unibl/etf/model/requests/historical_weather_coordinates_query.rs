@@ -0,0 +1,101 @@
+use reqwest_middleware::ClientWithMiddleware;
+
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::requests::downstream_current_weather_request::DownstreamCurrentWeatherRequest;
+use crate::org::unibl::etf::model::requests::downstream_historical_weather_request::DownstreamHistoricalWeatherRequest;
+use crate::org::unibl::etf::model::requests::historical_weather_query::HistoricalWeatherQuery;
+use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::RetrieveCurrentWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::retrieve_historical_weather_cache_request::RetrieveHistoricalWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::store_historical_weather_cache_request::StoreHistoricalWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::upstream_historical_weather_request_by_coordinates::UpstreamHistoricalWeatherRequestByCoordinates;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+use crate::org::unibl::etf::services::historical_weather_cache_service::HistoricalWeatherCacheService;
+use crate::org::unibl::etf::util::parse_provider_response;
+
+#[derive(Debug, Clone)]
+pub struct HistoricalWeatherCoordinatesQuery {
+    pub request: UpstreamHistoricalWeatherRequestByCoordinates,
+    pub cache_service: HistoricalWeatherCacheService,
+}
+
+#[async_trait::async_trait]
+impl HistoricalWeatherQuery for HistoricalWeatherCoordinatesQuery {
+    type NewDataRequest = DownstreamHistoricalWeatherRequest;
+    type RetrieveCacheRequest = RetrieveHistoricalWeatherCacheRequest;
+    type StoreCacheRequest = StoreHistoricalWeatherCacheRequest;
+
+    fn build_downstream_request(&self) -> Result<Self::NewDataRequest, AggregatorError> {
+        Ok(DownstreamHistoricalWeatherRequest {
+            location: DownstreamCurrentWeatherRequest {
+                lat: Some(self.request.lat),
+                lon: Some(self.request.lon),
+                location_name: None,
+                ip_address: None,
+            },
+            date_range: self.request.date_range(),
+        })
+    }
+
+    fn build_retrieve_cache_request(&self) -> Result<Self::RetrieveCacheRequest, AggregatorError> {
+        Ok(RetrieveHistoricalWeatherCacheRequest {
+            location: RetrieveCurrentWeatherCacheRequest {
+                location_name: None,
+                lat: Some(self.request.lat),
+                lon: Some(self.request.lon),
+                country: None,
+                state: None,
+                allow_stale: false,
+            },
+            date_range: self.request.date_range(),
+        })
+    }
+
+    fn build_store_cache_request(&self, observations: &[CurrentWeatherResponse], location_names: Vec<String>) -> Result<Self::StoreCacheRequest, AggregatorError> {
+        Ok(StoreHistoricalWeatherCacheRequest {
+            lat: self.request.lat,
+            lon: self.request.lon,
+            date_range: self.request.date_range(),
+            observations: observations.to_vec(),
+            location_names,
+        })
+    }
+
+    async fn cache_get(
+        &self,
+        req: &RetrieveHistoricalWeatherCacheRequest,
+        client: &ClientWithMiddleware,
+        cache_settings: &CacheServiceSettings,
+    ) -> Result<Vec<CurrentWeatherResponse>, AggregatorError> {
+        self.cache_service.get_cached_historical_weather_data_by_coordinates(req, client, cache_settings).await
+    }
+
+    async fn call_provider(
+        &self,
+        provider: &ProviderSettings,
+        req: &DownstreamHistoricalWeatherRequest,
+        client: &ClientWithMiddleware,
+    ) -> Result<Vec<CurrentWeatherResponse>, AggregatorError> {
+        let url = provider.endpoint_url(ProviderEndpoint::HistoricalWeather);
+
+        let response = client
+            .get(url)
+            .query(&req.location)
+            .query(&req.date_range)
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        parse_provider_response(provider, response).await
+    }
+
+    async fn cache_set(
+        &self,
+        req: &StoreHistoricalWeatherCacheRequest,
+        client: &ClientWithMiddleware,
+        cache_settings: &CacheServiceSettings,
+    ) -> Result<(), AggregatorError> {
+        self.cache_service.save_historical_weather_data_to_cache(client, cache_settings, req).await
+    }
+}
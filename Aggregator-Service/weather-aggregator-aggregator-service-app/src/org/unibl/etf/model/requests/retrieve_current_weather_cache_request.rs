@@ -6,5 +6,22 @@ pub struct RetrieveCurrentWeatherCacheRequest{
     pub lon: Option<f64>,
     pub country: Option<String>,
     pub state: Option<String>,
+    pub allow_stale: bool,
 
-}
\ No newline at end of file
+}
+impl RetrieveCurrentWeatherCacheRequest {
+    // Same fields the Cache-Service looks the entry up by.
+    pub fn local_cache_key(&self) -> String {
+        let normalize = |value: &Option<String>| value.as_deref().unwrap_or_default().trim().to_lowercase();
+
+        format!(
+            "{:?}:{:?}:{}:{}:{}:{}",
+            self.lat,
+            self.lon,
+            normalize(&self.location_name),
+            normalize(&self.country),
+            normalize(&self.state),
+            self.allow_stale,
+        )
+    }
+}
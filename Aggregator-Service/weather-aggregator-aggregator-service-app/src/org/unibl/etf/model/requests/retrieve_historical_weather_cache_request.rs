@@ -0,0 +1,8 @@
+use crate::org::unibl::etf::model::requests::date_range::DateRange;
+use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::RetrieveCurrentWeatherCacheRequest;
+
+#[derive(Clone, Debug)]
+pub struct RetrieveHistoricalWeatherCacheRequest {
+    pub location: RetrieveCurrentWeatherCacheRequest,
+    pub date_range: DateRange,
+}
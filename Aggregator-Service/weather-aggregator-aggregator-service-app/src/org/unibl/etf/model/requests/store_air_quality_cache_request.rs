@@ -0,0 +1,11 @@
+use serde::Serialize;
+use crate::org::unibl::etf::model::responses::air_quality_response::AirQualityResponse;
+
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StoreAirQualityCacheRequest {
+    pub lat: f64,
+    pub lon: f64,
+    pub air_quality_data: AirQualityResponse,
+    pub location_names: Vec<String>
+}
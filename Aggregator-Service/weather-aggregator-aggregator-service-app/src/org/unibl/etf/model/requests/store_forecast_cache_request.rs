@@ -0,0 +1,11 @@
+use serde::Serialize;
+use crate::org::unibl::etf::model::responses::forecast_response::ForecastResponse;
+
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StoreForecastCacheRequest {
+    pub lat: f64,
+    pub lon: f64,
+    pub forecast_data: ForecastResponse,
+    pub location_names: Vec<String>
+}
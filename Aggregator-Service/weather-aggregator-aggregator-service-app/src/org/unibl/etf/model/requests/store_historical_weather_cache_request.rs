@@ -0,0 +1,13 @@
+use serde::Serialize;
+use crate::org::unibl::etf::model::requests::date_range::DateRange;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StoreHistoricalWeatherCacheRequest {
+    pub lat: f64,
+    pub lon: f64,
+    pub date_range: DateRange,
+    pub observations: Vec<CurrentWeatherResponse>,
+    pub location_names: Vec<String>
+}
@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+use crate::org::unibl::etf::model::requests::date_range::{validate_date_range, DateRange};
+
+
+#[derive(Serialize, Debug, Deserialize, Validate, Clone)]
+#[validate(schema(function = "validate_request_date_range"))]
+pub struct UpstreamHistoricalWeatherRequestByCoordinates {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl UpstreamHistoricalWeatherRequestByCoordinates {
+    pub fn date_range(&self) -> DateRange {
+        DateRange {
+            start_date: self.start_date,
+            end_date: self.end_date,
+        }
+    }
+}
+
+fn validate_request_date_range(req: &UpstreamHistoricalWeatherRequestByCoordinates) -> Result<(), ValidationError> {
+    validate_date_range(req.start_date, req.end_date)
+}
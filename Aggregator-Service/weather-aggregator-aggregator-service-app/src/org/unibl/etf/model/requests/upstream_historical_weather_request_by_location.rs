@@ -0,0 +1,34 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+use crate::org::unibl::etf::model::requests::date_range::{validate_date_range, DateRange};
+
+
+#[derive(Serialize, Debug, Deserialize, Clone, Validate)]
+#[validate(schema(function = "validate_request_date_range"))]
+pub struct UpstreamHistoricalWeatherRequestByLocation {
+    #[validate(length(min = 2, message = "Location name must be at least 2 characters long"))]
+    pub location_name: String,
+
+    #[validate(length(min = 2, message = "Country name must be at least 2 characters long"))]
+    pub country: Option<String>,
+
+    #[validate(length(min = 2, message = "State name must be at least 2 characters long"))]
+    pub state: Option<String>,
+
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl UpstreamHistoricalWeatherRequestByLocation {
+    pub fn date_range(&self) -> DateRange {
+        DateRange {
+            start_date: self.start_date,
+            end_date: self.end_date,
+        }
+    }
+}
+
+fn validate_request_date_range(req: &UpstreamHistoricalWeatherRequestByLocation) -> Result<(), ValidationError> {
+    validate_date_range(req.start_date, req.end_date)
+}
@@ -0,0 +1,43 @@
+use reqwest_middleware::ClientWithMiddleware;
+
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+use crate::org::unibl::etf::configuration::settings::ProviderSettings;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::requests::downstream_current_weather_request::DownstreamCurrentWeatherRequest;
+use crate::org::unibl::etf::model::requests::upstream_current_weather_request_by_coordinates::UpstreamCurrentWeatherRequestByCoordinates;
+use crate::org::unibl::etf::model::requests::weather_alerts_query::WeatherAlertsQuery;
+use crate::org::unibl::etf::model::responses::weather_alerts_response::ProviderWeatherAlertsResponse;
+use crate::org::unibl::etf::util::parse_provider_response;
+
+#[derive(Debug, Clone)]
+pub struct WeatherAlertsCoordinatesQuery {
+    pub request: UpstreamCurrentWeatherRequestByCoordinates,
+}
+
+#[async_trait::async_trait]
+impl WeatherAlertsQuery for WeatherAlertsCoordinatesQuery {
+    type NewDataRequest = DownstreamCurrentWeatherRequest;
+
+    fn build_downstream_request(&self) -> Result<Self::NewDataRequest, AggregatorError> {
+        DownstreamCurrentWeatherRequest::try_from(&self.request)
+            .map_err(|e| AggregatorError::ServerError(Some(e.to_string())))
+    }
+
+    async fn call_provider(
+        &self,
+        provider: &ProviderSettings,
+        req: &DownstreamCurrentWeatherRequest,
+        client: &ClientWithMiddleware,
+    ) -> Result<ProviderWeatherAlertsResponse, AggregatorError> {
+        let url = provider.endpoint_url(ProviderEndpoint::WeatherAlerts);
+
+        let response = client
+            .get(url)
+            .query(&[("lat", req.lat), ("lon", req.lon)])
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        parse_provider_response(provider, response).await
+    }
+}
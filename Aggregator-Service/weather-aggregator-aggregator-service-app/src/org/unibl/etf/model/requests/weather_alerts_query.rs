@@ -0,0 +1,19 @@
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::ProviderSettings;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::responses::weather_alerts_response::ProviderWeatherAlertsResponse;
+
+// Alerts are not cached, they have to be as fresh as the providers can give them.
+#[async_trait::async_trait]
+pub trait WeatherAlertsQuery {
+    type NewDataRequest: Clone + Send + Sync;
+
+    fn build_downstream_request(&self) -> Result<Self::NewDataRequest, AggregatorError>;
+
+    async fn call_provider(
+        &self,
+        provider: &ProviderSettings,
+        req: &Self::NewDataRequest,
+        client: &ClientWithMiddleware,
+    ) -> Result<ProviderWeatherAlertsResponse, AggregatorError>;
+}
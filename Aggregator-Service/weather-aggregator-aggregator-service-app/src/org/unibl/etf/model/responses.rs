@@ -1,2 +1,11 @@
 pub mod health_check_response;
 pub mod current_weather_response;
+pub mod aggregation_metadata;
+pub mod forecast_response;
+pub mod weather_alerts_response;
+pub mod air_quality_response;
+pub mod nearby_current_weather_response;
+pub mod local_cache_stats_response;
+
+
+pub mod reverse_geocoding_response;
\ No newline at end of file
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+use crate::org::unibl::etf::services::current_weather_service::WeatherProviderResult;
+use crate::org::unibl::etf::strategy::aggregation::AggregatedField;
+use crate::org::unibl::etf::strategy::strategy_kind::StrategyKind;
+use crate::org::unibl::etf::strategy::weather_strategy::WeatherStrategy;
+
+// Only built for freshly aggregated responses. Cache hits were aggregated for an earlier request, possibly
+// with another strategy, so they are served without it.
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct AggregationMetadata {
+    pub strategy: StrategyKind,
+    pub contributing_providers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trimmed_providers: Vec<TrimmedProvider>,
+    pub failed_providers: Vec<FailedProvider>,
+    pub spread: FieldSpreads,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct TrimmedProvider {
+    pub provider: String,
+    pub fields: Vec<AggregatedField>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct FailedProvider {
+    pub provider: String,
+    pub error: AggregatorError,
+}
+
+// Spreads are computed over every provider that returned data, in metric units.
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct FieldSpreads {
+    pub temperature_metric: Option<FieldSpread>,
+    pub pressure_metric: Option<FieldSpread>,
+    pub humidity: Option<FieldSpread>,
+    pub wind_speed_metric: Option<FieldSpread>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct FieldSpread {
+    pub min: f64,
+    pub max: f64,
+    pub stddev: f64,
+    pub samples: usize,
+}
+
+impl AggregationMetadata {
+    pub fn new(
+        strategy_kind: StrategyKind,
+        strategy: &dyn WeatherStrategy,
+        results: &[WeatherProviderResult],
+        resolved: &CurrentWeatherResponse,
+    ) -> Self {
+        let valid: Vec<&CurrentWeatherResponse> =
+            results.iter().filter_map(|r| r.data.as_ref()).collect();
+
+        let failed_providers = results
+            .iter()
+            .filter_map(|r| {
+                r.error.as_ref().map(|e| FailedProvider {
+                    provider: r.provider.clone(),
+                    error: e.without_details(),
+                })
+            })
+            .collect();
+
+        Self {
+            strategy: strategy_kind,
+            contributing_providers: strategy.contributing_providers(results, resolved),
+            trimmed_providers: strategy.trimmed_providers(results),
+            failed_providers,
+            spread: FieldSpreads {
+                temperature_metric: FieldSpread::from_values(
+                    valid.iter().map(|r| Some(r.weather.temp_metric)),
+                ),
+                pressure_metric: FieldSpread::from_values(
+                    valid.iter().map(|r| r.weather.pressure_metric),
+                ),
+                humidity: FieldSpread::from_values(
+                    valid.iter().map(|r| r.weather.humidity.map(f64::from)),
+                ),
+                wind_speed_metric: FieldSpread::from_values(
+                    valid.iter().map(|r| r.wind.speed_metric),
+                ),
+            },
+        }
+    }
+}
+
+impl FieldSpread {
+    fn from_values(values: impl Iterator<Item = Option<f64>>) -> Option<Self> {
+        let values: Vec<f64> = values.flatten().collect();
+        if values.is_empty() {
+            return None;
+        }
+
+        let samples = values.len();
+        let mean = values.iter().sum::<f64>() / samples as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / samples as f64;
+
+        Some(Self {
+            min: values.iter().copied().fold(f64::INFINITY, f64::min),
+            max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            stddev: variance.sqrt(),
+            samples,
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::responses::current_weather_response::Location;
+use crate::org::unibl::etf::util::deserializers::{
+    deserialize_i64_or_empty_string_as_none,
+    deserialize_u16_or_empty_string_as_none,
+    deserialize_f64_or_empty_string_as_none
+};
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct AirQualityResponse {
+    pub provider: String,
+    pub location: Location,
+    pub pollutants: Pollutants,
+
+    #[serde(deserialize_with = "deserialize_u16_or_empty_string_as_none")]
+    pub aqi: Option<u16>,
+
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub aqi_category: Option<String>,
+
+    #[serde(serialize_with = "serialize_empty_i64")]
+    #[serde(deserialize_with = "deserialize_i64_or_empty_string_as_none")]
+    pub observed_at_timestamp: Option<i64>,
+}
+
+// Concentrations in µg/m³.
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct Pollutants {
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pm2_5: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pm10: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub o3: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub no2: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub so2: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub co: Option<f64>,
+}
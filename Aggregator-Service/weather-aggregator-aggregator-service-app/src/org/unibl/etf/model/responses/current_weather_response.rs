@@ -4,6 +4,7 @@ use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_u8;
 use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
 use crate::org::unibl::etf::util::deserializers::deserialize_f64_or_empty_string_as_nonee;
 use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::responses::aggregation_metadata::AggregationMetadata;
 use crate::org::unibl::etf::util::deserializers::{
     deserialize_i64_or_empty_string_as_none,
     deserialize_u8_or_empty_string_as_none,
@@ -20,6 +21,14 @@ pub struct CurrentWeatherResponse {
     #[serde(serialize_with = "serialize_empty_i64")]
     #[serde(deserialize_with = "deserialize_i64_or_empty_string_as_none")]
     pub observed_at_timestamp: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregation: Option<AggregationMetadata>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_stale: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stale_age_in_seconds: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_remaining_in_seconds: Option<i64>,
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
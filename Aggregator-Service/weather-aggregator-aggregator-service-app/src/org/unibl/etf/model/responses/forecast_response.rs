@@ -0,0 +1,90 @@
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_u8;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::responses::current_weather_response::{Location, Weather, Wind};
+use crate::org::unibl::etf::util::deserializers::{
+    deserialize_u8_or_empty_string_as_none,
+    deserialize_f64_or_empty_string_as_none
+};
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct ForecastResponse {
+    pub provider: String,
+    pub location: Location,
+    pub hourly: Vec<HourlyForecast>,
+    pub daily: Vec<DailyForecast>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct HourlyForecast {
+    pub timestamp: i64,
+    pub weather: Weather,
+    pub wind: Wind,
+
+    #[serde(deserialize_with = "deserialize_u8_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct DailyForecast {
+    pub date: String,
+    pub temp_min_metric: f64,
+    pub temp_min_imperial: f64,
+    pub temp_max_metric: f64,
+    pub temp_max_imperial: f64,
+
+    #[serde(deserialize_with = "deserialize_u8_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub humidity: Option<u8>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_metric: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_imperial: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_u8_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub condition: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HourlyForecastResponse {
+    pub provider: String,
+    pub location: Location,
+    pub hourly: Vec<HourlyForecast>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DailyForecastResponse {
+    pub provider: String,
+    pub location: Location,
+    pub daily: Vec<DailyForecast>,
+}
+
+impl From<ForecastResponse> for HourlyForecastResponse {
+    fn from(forecast: ForecastResponse) -> Self {
+        Self {
+            provider: forecast.provider,
+            location: forecast.location,
+            hourly: forecast.hourly,
+        }
+    }
+}
+
+impl From<ForecastResponse> for DailyForecastResponse {
+    fn from(forecast: ForecastResponse) -> Self {
+        Self {
+            provider: forecast.provider,
+            location: forecast.location,
+            daily: forecast.daily,
+        }
+    }
+}
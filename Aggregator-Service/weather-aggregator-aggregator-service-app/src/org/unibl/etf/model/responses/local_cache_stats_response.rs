@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LocalCacheStatsResponse {
+    pub enabled: bool,
+    pub entries: usize,
+    pub max_entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub hit_ratio: f64,
+}
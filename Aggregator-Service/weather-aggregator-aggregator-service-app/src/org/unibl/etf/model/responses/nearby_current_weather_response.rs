@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct NearbyCurrentWeatherResponse {
+    pub observations: Vec<NearbyCurrentWeatherObservation>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct NearbyCurrentWeatherObservation {
+    pub distance_km: f64,
+    pub data: CurrentWeatherResponse,
+}
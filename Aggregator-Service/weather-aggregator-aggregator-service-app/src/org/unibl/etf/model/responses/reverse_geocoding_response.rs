@@ -0,0 +1,7 @@
+use serde::Deserialize;
+use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::LocationCandidate;
+
+#[derive(Deserialize, Debug)]
+pub struct ReverseGeocodingResponse {
+    pub candidates: Vec<LocationCandidate>,
+}
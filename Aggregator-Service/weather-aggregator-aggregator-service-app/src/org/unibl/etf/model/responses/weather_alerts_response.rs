@@ -0,0 +1,43 @@
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::responses::current_weather_response::Location;
+use crate::org::unibl::etf::util::deserializers::deserialize_i64_or_empty_string_as_none;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProviderWeatherAlertsResponse {
+    pub provider: String,
+    pub location: Location,
+    pub alerts: Vec<ProviderWeatherAlert>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProviderWeatherAlert {
+    pub event: String,
+    pub severity: Option<String>,
+    pub start_timestamp: i64,
+    #[serde(deserialize_with = "deserialize_i64_or_empty_string_as_none")]
+    pub end_timestamp: Option<i64>,
+    pub description: Option<String>,
+    pub sender: Option<String>,
+    pub source_provider: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct WeatherAlertsResponse {
+    pub location: Location,
+    pub alerts: Vec<WeatherAlert>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct WeatherAlert {
+    pub event: String,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub severity: Option<String>,
+    pub start_timestamp: i64,
+    #[serde(serialize_with = "serialize_empty_i64")]
+    pub end_timestamp: Option<i64>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub description: Option<String>,
+    pub source_providers: Vec<String>,
+}
@@ -0,0 +1 @@
+pub mod client_rate_limit_repository;
\ No newline at end of file
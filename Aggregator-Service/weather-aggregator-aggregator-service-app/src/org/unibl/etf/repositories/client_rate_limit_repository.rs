@@ -0,0 +1,117 @@
+use std::sync::LazyLock;
+use deadpool_redis::redis::Script;
+use crate::org::unibl::etf::configuration::settings::ClientRateLimitWindow;
+use crate::org::unibl::etf::model::client_rate_limit_status::ClientRateLimitStatus;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+
+// Fixed window counters, one per window, started by the first request in the window.
+// A request is counted in every window or in none, so a rejected request does not use up quota.
+// A counter found without an expiry is given the full window again, it would otherwise never reset.
+// Returns whether the request is allowed with the limit, remaining count and reset of the window that constrains it most.
+static ACQUIRE_CLIENT_REQUEST_SCRIPT: LazyLock<Script> = LazyLock::new(|| Script::new(r#"
+local rejected_limit, rejected_reset = -1, -1
+
+for i = 1, #KEYS do
+    local window = tonumber(ARGV[i * 2 - 1])
+    local limit = tonumber(ARGV[i * 2])
+    local used = tonumber(redis.call('GET', KEYS[i]) or '0')
+    if used >= limit then
+        local reset = redis.call('TTL', KEYS[i])
+        if reset < 0 then
+            redis.call('EXPIRE', KEYS[i], window)
+            reset = window
+        end
+        if reset > rejected_reset then
+            rejected_limit, rejected_reset = limit, reset
+        end
+    end
+end
+
+if rejected_reset >= 0 then
+    return {0, rejected_limit, 0, rejected_reset}
+end
+
+local best_limit, best_remaining, best_reset = 0, -1, 0
+for i = 1, #KEYS do
+    local window = tonumber(ARGV[i * 2 - 1])
+    local limit = tonumber(ARGV[i * 2])
+    local used = redis.call('INCR', KEYS[i])
+    local reset = redis.call('TTL', KEYS[i])
+    if reset < 0 then
+        redis.call('EXPIRE', KEYS[i], window)
+        reset = window
+    end
+    local remaining = limit - used
+    if best_remaining < 0 or remaining < best_remaining then
+        best_limit, best_remaining, best_reset = limit, remaining, reset
+    end
+end
+
+return {1, best_limit, best_remaining, best_reset}
+"#));
+
+#[derive(Debug, Clone, Copy)]
+pub enum ClientRateLimitDecision {
+    Allowed(ClientRateLimitStatus),
+    Limited(ClientRateLimitStatus),
+}
+
+#[derive(Debug)]
+pub struct ClientRateLimitRepository {}
+
+impl ClientRateLimitRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tracing::instrument(name = "Acquire client request repository", skip(redis_pool))]
+    pub async fn acquire_client_request(
+        &self,
+        client_key: &str,
+        windows: &[ClientRateLimitWindow],
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<ClientRateLimitDecision, AggregatorError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(AggregatorError::ServerError(Some(error_message)));
+            }
+        };
+
+        let mut invocation = ACQUIRE_CLIENT_REQUEST_SCRIPT.prepare_invoke();
+        for window in windows {
+            invocation
+                .key(client_rate_limit_key(client_key, window.name))
+                .arg(window.window_in_seconds)
+                .arg(window.limit);
+        }
+
+        let (allowed, limit, remaining, reset_in_seconds): (u8, u64, u64, u64) = invocation
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| AggregatorError::ServerError(Some(e.to_string())))?;
+
+        let status = ClientRateLimitStatus {
+            limit,
+            remaining,
+            reset_in_seconds,
+        };
+
+        if allowed == 1 {
+            Ok(ClientRateLimitDecision::Allowed(status))
+        } else {
+            Ok(ClientRateLimitDecision::Limited(status))
+        }
+    }
+}
+
+impl Default for ClientRateLimitRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn client_rate_limit_key(client_key: &str, window_name: &str) -> String {
+    format!("aggregator:client_rate_limit:{}:{}", client_key, window_name)
+}
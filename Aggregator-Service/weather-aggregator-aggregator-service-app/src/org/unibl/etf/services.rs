@@ -1,2 +1,18 @@
 pub mod current_weather_service;
-pub mod current_weather_cache_service;
\ No newline at end of file
+pub mod current_weather_cache_service;
+pub mod forecast_service;
+pub mod forecast_cache_service;
+pub mod historical_weather_service;
+pub mod historical_weather_cache_service;
+pub mod weather_alerts_service;
+pub mod air_quality_service;
+pub mod air_quality_cache_service;
+pub mod circuit_breaker_service;
+pub mod provider_fan_out_service;
+pub mod request_coalescing_service;
+pub mod local_cache_service;
+
+
+pub mod jwt_service;
+pub mod client_rate_limit_service;
+pub mod reverse_geocoding_service;
\ No newline at end of file
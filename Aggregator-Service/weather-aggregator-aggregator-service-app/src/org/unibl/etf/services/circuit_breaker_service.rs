@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use crate::org::unibl::etf::configuration::settings::CircuitBreakerSettings;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct ProviderCircuit {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl ProviderCircuit {
+    fn new() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            opened_at: None,
+        }
+    }
+}
+
+// One circuit per provider and endpoint, so an endpoint the adapter can not serve does not take the others down with it.
+#[derive(Debug)]
+pub struct CircuitBreakerService {
+    settings: CircuitBreakerSettings,
+    circuits: Mutex<HashMap<(String, ProviderEndpoint), ProviderCircuit>>,
+}
+
+impl CircuitBreakerService {
+    pub fn new(settings: CircuitBreakerSettings) -> Self {
+        Self {
+            settings,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn call<T, F>(&self, provider_name: &str, endpoint: ProviderEndpoint, call: F) -> Result<T, AggregatorError>
+    where
+        F: Future<Output = Result<T, AggregatorError>>,
+    {
+        if !self.settings.enabled {
+            return call.await;
+        }
+
+        self.try_acquire(provider_name, endpoint)?;
+
+        let result = call.await;
+        match &result {
+            // Our own quota turned the call away, which says nothing about the provider either way.
+            Err(AggregatorError::ProviderRateLimitedError(_)) => {},
+            Err(e) if e.is_provider_failure() => self.record_failure(provider_name, endpoint),
+            _ => self.record_success(provider_name, endpoint),
+        }
+
+        result
+    }
+
+    fn try_acquire(&self, provider_name: &str, endpoint: ProviderEndpoint) -> Result<(), AggregatorError> {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = circuits
+            .entry((provider_name.to_string(), endpoint))
+            .or_insert_with(ProviderCircuit::new);
+
+        let cool_down = Duration::from_secs(self.settings.open_duration_in_seconds);
+        let cool_down_elapsed = circuit.opened_at.is_some_and(|opened_at| opened_at.elapsed() >= cool_down);
+
+        match circuit.state {
+            CircuitState::Closed => Ok(()),
+            // Restarting the cool-down lets a single probe through, the rest are skipped until it resolves.
+            // A probe whose request got cancelled is replaced once another cool-down passes.
+            CircuitState::Open | CircuitState::HalfOpen if cool_down_elapsed => {
+                tracing::info!("Circuit for provider {} on {} is half-open, probing.", provider_name, endpoint.as_str());
+                circuit.state = CircuitState::HalfOpen;
+                circuit.opened_at = Some(Instant::now());
+                Ok(())
+            },
+            _ => Err(AggregatorError::CircuitOpenError),
+        }
+    }
+
+    fn record_success(&self, provider_name: &str, endpoint: ProviderEndpoint) {
+        let mut circuits = self.circuits.lock().unwrap();
+        if let Some(circuit) = circuits.get_mut(&(provider_name.to_string(), endpoint)) {
+            if circuit.state != CircuitState::Closed {
+                tracing::info!("Circuit for provider {} on {} closed.", provider_name, endpoint.as_str());
+            }
+            *circuit = ProviderCircuit::new();
+        }
+    }
+
+    fn record_failure(&self, provider_name: &str, endpoint: ProviderEndpoint) {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = circuits
+            .entry((provider_name.to_string(), endpoint))
+            .or_insert_with(ProviderCircuit::new);
+
+        circuit.consecutive_failures += 1;
+
+        if circuit.state == CircuitState::HalfOpen
+            || circuit.consecutive_failures >= self.settings.failure_threshold {
+            tracing::warn!(
+                "Circuit for provider {} on {} opened after {} consecutive failures.",
+                provider_name,
+                endpoint.as_str(),
+                circuit.consecutive_failures
+            );
+            circuit.state = CircuitState::Open;
+            circuit.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+impl Default for CircuitBreakerService {
+    fn default() -> Self {
+        Self::new(CircuitBreakerSettings::default())
+    }
+}
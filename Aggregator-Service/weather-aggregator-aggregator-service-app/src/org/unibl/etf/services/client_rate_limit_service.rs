@@ -0,0 +1,109 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+use crate::org::unibl::etf::configuration::settings::{ClientRateLimitSettings, ClientRateLimitTierSettings};
+use crate::org::unibl::etf::model::client_rate_limit_status::ClientRateLimitStatus;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::user_type::UserType;
+use crate::org::unibl::etf::repositories::client_rate_limit_repository::{ClientRateLimitDecision, ClientRateLimitRepository};
+use crate::org::unibl::etf::services::jwt_service::JwtService;
+
+// Who a request is counted against.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientIdentity {
+    User(String, UserType),
+    Anonymous(IpAddr),
+}
+
+impl ClientIdentity {
+    fn key(&self) -> String {
+        match self {
+            ClientIdentity::User(user_id, _) => format!("user:{}", user_id),
+            ClientIdentity::Anonymous(ip) => format!("ip:{}", ip),
+        }
+    }
+}
+
+pub struct ClientRateLimitService {
+    settings: ClientRateLimitSettings,
+    // None when the Aggregator is not configured to verify User Identity Service tokens.
+    jwt_service: Option<Arc<JwtService>>,
+    client_rate_limit_repository: ClientRateLimitRepository,
+}
+
+impl ClientRateLimitService {
+    pub fn new(settings: ClientRateLimitSettings, jwt_service: Option<Arc<JwtService>>) -> Self {
+        Self {
+            settings,
+            jwt_service,
+            client_rate_limit_repository: ClientRateLimitRepository::default(),
+        }
+    }
+
+    // The peer is the client unless it is a trusted proxy. Each trusted proxy appends the address it received
+    // the request from, so the rightmost hop that is not one of them is the client; anything further left was
+    // written by the client itself.
+    pub fn resolve_client_ip(&self, peer_ip: Option<IpAddr>, forwarded_for: &[&str]) -> Option<IpAddr> {
+        let peer_ip = peer_ip?;
+        if !self.settings.trusted_proxies.contains(&peer_ip) {
+            return Some(peer_ip);
+        }
+
+        for hop in forwarded_for.iter().rev() {
+            let hop_ip = hop.parse::<IpAddr>().ok()?;
+            if !self.settings.trusted_proxies.contains(&hop_ip) {
+                return Some(hop_ip);
+            }
+        }
+
+        None
+    }
+
+    // Callers without a valid access token are not rejected here, they are limited by IP address instead.
+    // None when neither is available.
+    pub fn identify_client(&self, access_token: Option<&str>, ip: Option<IpAddr>) -> Option<ClientIdentity> {
+        let claims = match (&self.jwt_service, access_token) {
+            (Some(jwt_service), Some(access_token)) => jwt_service.validate_access_token(access_token),
+            _ => return ip.map(ClientIdentity::Anonymous),
+        };
+
+        match claims {
+            Ok(claims) => Some(ClientIdentity::User(claims.sub, claims.user_type)),
+            Err(e) => {
+                tracing::debug!("Access token not accepted, limiting by IP address. {}", e);
+                ip.map(ClientIdentity::Anonymous)
+            }
+        }
+    }
+
+    fn tier(&self, client: &ClientIdentity) -> &ClientRateLimitTierSettings {
+        match client {
+            ClientIdentity::User(_, UserType::GUEST) => &self.settings.guest,
+            ClientIdentity::User(_, UserType::STANDARD) => &self.settings.standard,
+            ClientIdentity::Anonymous(_) => &self.settings.anonymous,
+        }
+    }
+
+    // None when the client's tier has no limits configured.
+    #[tracing::instrument(name = "Acquire client request service", skip(self, redis_pool))]
+    pub async fn acquire_client_request(
+        &self,
+        client: &ClientIdentity,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Option<ClientRateLimitStatus>, AggregatorError> {
+        let windows = self.tier(client).windows();
+        if windows.is_empty() {
+            return Ok(None);
+        }
+
+        match self.client_rate_limit_repository
+            .acquire_client_request(&client.key(), &windows, redis_pool)
+            .await?
+        {
+            ClientRateLimitDecision::Allowed(status) => Ok(Some(status)),
+            ClientRateLimitDecision::Limited(status) => {
+                tracing::warn!("Client {:?} exceeded its rate limit of {} requests.", client, status.limit);
+                Err(AggregatorError::RateLimitExceeded(status))
+            }
+        }
+    }
+}
@@ -9,6 +9,7 @@ use crate::org::unibl::etf::model::requests::store_current_weather_cache_request
 
 
 use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+use crate::org::unibl::etf::model::responses::nearby_current_weather_response::NearbyCurrentWeatherResponse;
 
 
 #[derive(Debug, Clone)]
@@ -47,6 +48,7 @@ impl CurrentWeatherCacheService {
         let response = client
             .get(url)
             .query(&params)
+            .query(&stale_params(req))
             .send()
             .await
             .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
@@ -82,6 +84,7 @@ impl CurrentWeatherCacheService {
         let response = client
             .get(url)
             .query(&params)
+            .query(&stale_params(req))
             .send()
             .await
             .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
@@ -89,6 +92,55 @@ impl CurrentWeatherCacheService {
         self.process_cache_service_get_cached_current_weather_data_response(response).await
     }
 
+    #[tracing::instrument(name = "Get Nearby Cached Current Weather Data Function", skip(client, cache_service_settings))]
+    pub async fn get_nearby_cached_current_weather_data(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius_km: f64,
+        client: &ClientWithMiddleware,
+        cache_service_settings: &CacheServiceSettings,
+    ) -> Result<NearbyCurrentWeatherResponse, AggregatorError> {
+        let url = format!("{}://{}:{}/api/v1/current_weather_nearby", cache_service_settings.scheme, cache_service_settings.host, cache_service_settings.port);
+
+        let response = client
+            .get(url)
+            .query(&[("lat", lat), ("lon", lon), ("radius_km", radius_km)])
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        if response.status().is_success() {
+            let body_text = response.text()
+                .await
+                .map_err(|e| AggregatorError::ServerError(
+                    Some(format!("Failed to get Cache Service success response body text: {}", e))
+                ))?;
+
+            serde_json::from_str(&body_text)
+                .map_err(|e| {
+                    AggregatorError::ResponseParsingError(Some(format!(
+                        "Failed to parse Cache Service success response: JSON Error: {} | Raw Body: {}",
+                        e, body_text
+                    )))
+                })
+        } else {
+            let error_body_text = response.text().await.map_err(|e| {
+                AggregatorError::ServerError(Some(format!("Failed to get Cache Service error response body text: {}", e)))
+            })?;
+
+            let error_body: CacheServiceError = serde_json::from_str(&error_body_text)
+                .map_err(|e| {
+                    AggregatorError::ResponseParsingError(Some(format!(
+                        "Failed to parse Cache Service error response. JSON Error: {} | Raw Body: {}",
+                        e, error_body_text
+                    )))
+                })?;
+
+            Err(AggregatorError::from(error_body.error.code))
+        }
+    }
+
     #[tracing::instrument(name = "Send Current Weather Data to Cache Service function", skip(client, cache_service_settings))]
     pub async fn save_current_weather_data_to_cache(
         &self,
@@ -178,4 +230,12 @@ impl Default for CurrentWeatherCacheService {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+fn stale_params(req: &RetrieveCurrentWeatherCacheRequest) -> Vec<(&'static str, bool)> {
+    if req.allow_stale {
+        vec![("allow_stale", true)]
+    } else {
+        Vec::new()
+    }
+}
@@ -1,39 +1,168 @@
+use std::sync::Arc;
+use std::time::Duration;
 use actix_web::web;
-use futures::future::join_all;
 use reqwest_middleware::ClientWithMiddleware;
 use tracing::Instrument;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
 use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
-use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings};
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, LocalCacheSettings, ProviderSettings, StrategySettings};
 use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::LocationCandidate;
+use crate::org::unibl::etf::model::requests::aggregation_options::AggregationOptions;
 use crate::org::unibl::etf::model::requests::current_weather_query::CurrentWeatherQuery;
 
+use crate::org::unibl::etf::model::responses::aggregation_metadata::AggregationMetadata;
 use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
-use crate::org::unibl::etf::strategy::composite_strategy::CompositeStrategy;
-use crate::org::unibl::etf::strategy::priority_strategy::PriorityStrategy;
-use crate::org::unibl::etf::strategy::weather_strategy::WeatherStrategy;
+use crate::org::unibl::etf::model::responses::local_cache_stats_response::LocalCacheStatsResponse;
+use crate::org::unibl::etf::strategy::strategy_factory::build_strategy;
+use crate::org::unibl::etf::services::provider_fan_out_service::{ProviderCallResult, ProviderFanOutService};
+use crate::org::unibl::etf::services::local_cache_service::LocalCacheService;
+use crate::org::unibl::etf::services::request_coalescing_service::RequestCoalescingService;
+use crate::org::unibl::etf::services::reverse_geocoding_service::ReverseGeocodingService;
+
+// Shared with fetches that outlive the request, such as background refreshes.
+#[derive(Debug, Clone)]
+struct CurrentWeatherSources {
+    provider_fan_out_service: Arc<ProviderFanOutService>,
+    reverse_geocoding_service: Arc<ReverseGeocodingService>,
+}
 
 #[derive(Debug)]
 pub struct CurrentWeatherService {
-
+    sources: CurrentWeatherSources,
+    request_coalescing_service: RequestCoalescingService<Result<CurrentWeatherResponse, AggregatorError>>,
+    local_cache: Arc<LocalCacheService<CurrentWeatherResponse>>,
 }
 
-
 impl CurrentWeatherService {
-    fn new() -> Self {
+    pub fn new(
+        provider_fan_out_service: Arc<ProviderFanOutService>,
+        reverse_geocoding_service: Arc<ReverseGeocodingService>,
+        local_cache_settings: LocalCacheSettings,
+    ) -> Self {
         Self {
+            sources: CurrentWeatherSources {
+                provider_fan_out_service,
+                reverse_geocoding_service,
+            },
+            request_coalescing_service: RequestCoalescingService::new(),
+            local_cache: Arc::new(LocalCacheService::new(local_cache_settings)),
         }
     }
 
-    #[tracing::instrument(name = "Get Current Weather Data Service", skip(client, cache_service_settings, providers_settings))]
+    pub fn local_cache_stats(&self) -> LocalCacheStatsResponse {
+        self.local_cache.stats()
+    }
+
+    // Keeps what the Cache-Service would answer with, so aggregation details and stale fallbacks stay out.
+    fn store_locally(local_cache: &LocalCacheService<CurrentWeatherResponse>, key: &str, data: &CurrentWeatherResponse) {
+        if data.is_stale {
+            return;
+        }
+
+        let mut data = data.clone();
+        data.aggregation = None;
+        let ttl = data.ttl_remaining_in_seconds.map(|ttl| Duration::from_secs(ttl.max(0) as u64));
+        local_cache.insert(key.to_string(), data, ttl);
+    }
+
+    #[tracing::instrument(name = "Get Current Weather Data Service", skip(client, cache_service_settings, providers_settings, strategy_settings))]
     pub async fn get_current_weather<Q: CurrentWeatherQuery + std::fmt::Debug + Clone + 'static>(
         &self,
         query: Q,
+        options: AggregationOptions,
+        client: web::Data<ClientWithMiddleware>,
+        providers_settings: web::Data<Vec<ProviderSettings>>,
+        cache_service_settings: web::Data<CacheServiceSettings>,
+        strategy_settings: web::Data<StrategySettings>,
+    ) -> Result<CurrentWeatherResponse, AggregatorError> {
+        // The response depends on how it is aggregated, so only requests asking for the same view are merged.
+        let key = format!(
+            "{}:{:?}:{}",
+            query.coalescing_key(),
+            options.strategy.unwrap_or(strategy_settings.default),
+            options.include_aggregation
+        );
+
+        let local_cache_key = query
+            .local_cache_key()
+            .filter(|_| self.local_cache.is_enabled());
+        let local_hit = local_cache_key
+            .as_deref()
+            .and_then(|local_cache_key| self.local_cache.get(local_cache_key))
+            .map(|(mut cached_data, age)| {
+                cached_data.ttl_remaining_in_seconds = cached_data.ttl_remaining_in_seconds
+                    .map(|ttl| ttl - age.as_secs() as i64);
+                cached_data
+            });
+
+        let result = match local_hit {
+            Some(cached_data) => {
+                tracing::info!("Local cache hit for current weather data.");
+                Ok(cached_data)
+            },
+            None => {
+                let request = Self::fetch_current_weather(
+                    self.sources.clone(),
+                    query.clone(),
+                    options.clone(),
+                    client.clone(),
+                    providers_settings.clone(),
+                    cache_service_settings.clone(),
+                    strategy_settings.clone(),
+                );
+
+                let result = self.request_coalescing_service
+                    .coalesce(key.clone(), request)
+                    .await
+                    .unwrap_or(Err(AggregatorError::ServerError(Some("Coalesced current weather request failed.".to_string()))));
+
+                if let (Some(local_cache_key), Ok(data)) = (&local_cache_key, &result) {
+                    Self::store_locally(&self.local_cache, local_cache_key, data);
+                }
+                result
+            },
+        };
+
+        // Hits about to expire are served as they are while fresh data is fetched for the next request.
+        if let Ok(cached_data) = &result
+            && !cached_data.is_stale
+            && cached_data.ttl_remaining_in_seconds.is_some_and(|ttl| ttl <= cache_service_settings.refresh_ahead_in_seconds) {
+            tracing::info!("Cached current weather data expires in {:?} seconds, refreshing in background.", cached_data.ttl_remaining_in_seconds);
+            let refresh = Self::refresh_current_weather(
+                self.sources.clone(),
+                query,
+                options,
+                client,
+                providers_settings,
+                cache_service_settings,
+                strategy_settings,
+            );
+            // Without this, local hits would keep reporting the old TTL and trigger refreshes until they expire.
+            let local_cache = self.local_cache.clone();
+            let refresh = async move {
+                let result = refresh.await;
+                if let (Some(local_cache_key), Ok(data)) = (&local_cache_key, &result) {
+                    Self::store_locally(&local_cache, local_cache_key, data);
+                }
+                result
+            };
+            self.request_coalescing_service.start_in_background(format!("refresh:{}", key), refresh);
+        }
+
+        result
+    }
+
+    async fn fetch_current_weather<Q: CurrentWeatherQuery + std::fmt::Debug + Clone + 'static>(
+        sources: CurrentWeatherSources,
+        query: Q,
+        options: AggregationOptions,
         client: web::Data<ClientWithMiddleware>,
         providers_settings: web::Data<Vec<ProviderSettings>>,
         cache_service_settings: web::Data<CacheServiceSettings>,
+        strategy_settings: web::Data<StrategySettings>,
     ) -> Result<CurrentWeatherResponse, AggregatorError> {
         println!("op2");
-        let get_cache_req = query.build_retrieve_cache_request();
+        let get_cache_req = query.build_retrieve_cache_request(false);
 
         println!("op3");
         let cache_candidates: Vec<CurrentWeatherResponse> = match get_cache_req {
@@ -71,63 +200,66 @@ impl CurrentWeatherService {
         };
 
 
+        match Self::refresh_current_weather(
+            sources,
+            query,
+            options,
+            client,
+            providers_settings,
+            cache_service_settings,
+            strategy_settings,
+        ).await {
+            Err(AggregatorError::AmbiguousLocationNameError(mut candidates)) => {
+                // Cached entries without a name or country can not be told apart by the user, so they are left out.
+                let additional_candidates = cache_candidates
+                    .into_iter()
+                    .filter_map(|cache_candidate| {
+                        Some(LocationCandidate {
+                            location_name: cache_candidate.location.name?,
+                            state: cache_candidate.location.state_region_province_or_entity.unwrap_or_default(),
+                            country: cache_candidate.location.country?,
+                            lat: cache_candidate.location.lat,
+                            lon: cache_candidate.location.lon,
+                        })
+                    });
+                candidates.extend(additional_candidates);
+                Err(AggregatorError::AmbiguousLocationNameError(candidates))
+            },
+            result => result,
+        }
+    }
+
+    // Fans out to the providers, merges their answers and stores the result in the cache.
+    async fn refresh_current_weather<Q: CurrentWeatherQuery + std::fmt::Debug + Clone + 'static>(
+        sources: CurrentWeatherSources,
+        query: Q,
+        options: AggregationOptions,
+        client: web::Data<ClientWithMiddleware>,
+        providers_settings: web::Data<Vec<ProviderSettings>>,
+        cache_service_settings: web::Data<CacheServiceSettings>,
+        strategy_settings: web::Data<StrategySettings>,
+    ) -> Result<CurrentWeatherResponse, AggregatorError> {
         println!("op5");
         let req = query.build_downstream_request()?;
-        let futures = providers_settings.iter().map(|provider| {
-            let client = client.clone();
-            let req = req.clone();
-            let query = query.clone();
+        let downstream_client = client.clone();
+        let downstream_query = query.clone();
+        let (results, late_results) = sources.provider_fan_out_service
+            .fan_out(ProviderEndpoint::CurrentWeather, providers_settings.as_ref(), move |provider| {
+                let client = downstream_client.clone();
+                let req = req.clone();
+                let query = downstream_query.clone();
 
-            async move {
-                query.call_provider(provider, &req, &client).await
-            }
-        });
-        let results = join_all(futures).await;
+                async move {
+                    query.call_provider(&provider, &req, &client).await
+                }
+            })
+            .await;
 
         let normalized = results
             .into_iter()
-            .enumerate()
-            .map(|(i, res)| {
-                let provider = providers_settings[i].name.clone();
-                match res {
-                    Ok(data) => WeatherProviderResult {
-                        provider,
-                        data: Some(data),
-                        error: None,
-                    },
-                    Err(e) => WeatherProviderResult {
-                        provider,
-                        data: None,
-                        error: Some(e),
-                    },
-                }
-            })
+            .map(WeatherProviderResult::from)
             .collect::<Vec<_>>();
 
-        let errors: Vec<&AggregatorError> = normalized.iter().filter_map(|r| r.error.as_ref()).collect();
-        if let Some(mut candidates) = errors.iter().find_map(|e| {
-            if let AggregatorError::AmbiguousLocationNameError(candidates) = e {
-                Some(candidates.clone())
-            } else {
-                None
-            }
-        }) {
-            let mut additional_candidates: Vec<LocationCandidate> = Vec::new();
-            for cache_candidate in cache_candidates {
-                additional_candidates.push(
-                    LocationCandidate {
-                        location_name: cache_candidate.location.name.unwrap(),
-                        state: cache_candidate.location.state_region_province_or_entity.unwrap(),
-                        country: cache_candidate.location.country.unwrap(),
-                        lat: cache_candidate.location.lat,
-                        lon: cache_candidate.location.lon,
-                    }
-                );
-            }
-            candidates.extend(additional_candidates);
-            return Err(AggregatorError::AmbiguousLocationNameError(candidates));
-        }
-
         // Error precedence logic (unchanged)
         if let Some(candidates) = normalized.iter().find_map(|r| {
             if let Some(AggregatorError::AmbiguousLocationNameError(c)) = &r.error {
@@ -139,24 +271,44 @@ impl CurrentWeatherService {
             return Err(AggregatorError::AmbiguousLocationNameError(candidates));
         }
 
-        if normalized.iter().all(|r| matches!(r.error, Some(AggregatorError::LocationNotFoundError(_)))) {
+        if normalized
+            .iter()
+            .filter(|r| !r.error.as_ref().is_some_and(AggregatorError::is_provider_skipped))
+            .all(|r| matches!(r.error, Some(AggregatorError::LocationNotFoundError(_)))) {
             if let Some(AggregatorError::LocationNotFoundError(loc)) =
-                normalized.iter().filter_map(|r| r.error.as_ref()).next()
+                normalized.iter().filter_map(|r| r.error.as_ref()).find(|e| matches!(e, AggregatorError::LocationNotFoundError(_)))
             {
                 return Err(AggregatorError::LocationNotFoundError(loc.clone()));
             }
         }
 
-        let strategy = CompositeStrategy {
-            average_min: 2,
-            priority: PriorityStrategy {
-                order: vec!["openweathermap.org".into(), "weatherapi.com".into()],
-            },
+        let strategy_kind = options.strategy.unwrap_or(strategy_settings.default);
+        let strategy = build_strategy(strategy_kind, strategy_settings.as_ref(), providers_settings.as_ref());
+        let Some(mut result) = strategy.resolve(&normalized) else {
+            // An expired reading flagged as stale is more useful than no reading at all.
+            let stale_cache_req = query
+                .build_retrieve_cache_request(true)
+                .map_err(|_| AggregatorError::WeatherDataUnavailableError)?;
+            let mut stale = query
+                .cache_get(&stale_cache_req, client.as_ref(), cache_service_settings.as_ref())
+                .await
+                .map_err(|_| AggregatorError::WeatherDataUnavailableError)?;
+
+            tracing::warn!("No provider could be resolved, serving stale cached data aged {:?} seconds.", stale.stale_age_in_seconds);
+            stale.aggregation = options.include_aggregation
+                .then(|| AggregationMetadata::new(strategy_kind, strategy.as_ref(), &normalized, &stale));
+            return Ok(stale);
         };
-        let result = strategy
-            .resolve(&normalized)
-            .ok_or(AggregatorError::WeatherDataUnavailableError)?;
 
+        let location_details = sources.reverse_geocoding_service
+            .find_missing_location_details(&result.location, client.as_ref())
+            .await;
+        if let Some(location_details) = &location_details {
+            ReverseGeocodingService::fill_missing_location_details(&mut result.location, location_details);
+        }
+
+        let aggregation = options.include_aggregation
+            .then(|| AggregationMetadata::new(strategy_kind, strategy.as_ref(), &normalized, &result));
 
         let query_clone = query.clone();
         let result_clone = result.clone();
@@ -165,11 +317,31 @@ impl CurrentWeatherService {
 
 
         actix_web::rt::spawn(async move {
+            // Providers that missed the request budget still get a say in what is cached.
+            let (mut result_clone, normalized) = match late_results {
+                Some(late_results) => {
+                    let normalized = late_results
+                        .complete()
+                        .await
+                        .into_iter()
+                        .map(WeatherProviderResult::from)
+                        .collect::<Vec<_>>();
+                    let merged = strategy.resolve(&normalized).unwrap_or(result_clone);
+                    (merged, normalized)
+                },
+                None => (result_clone, normalized),
+            };
+
+            if let Some(location_details) = &location_details {
+                ReverseGeocodingService::fill_missing_location_details(&mut result_clone.location, location_details);
+            }
+
             let mut location_names: Vec<String> = normalized
                 .iter()
                 .filter_map(|res| {
                     res.data.as_ref().and_then(|d| d.location.name.clone())
                 })
+                .chain(location_details.map(|location_details| location_details.location_name))
                 .collect();
 
             location_names.sort();
@@ -188,19 +360,32 @@ impl CurrentWeatherService {
             }
         }.instrument(tracing::Span::current()));
 
+        result.aggregation = aggregation;
+
         Ok(result)
     }
 }
 
-
-impl Default for CurrentWeatherService {
-    fn default() -> Self {
-        Self::new()
-    }
-}
 #[derive(Debug)]
 pub struct WeatherProviderResult {
     pub provider: String,
     pub data: Option<CurrentWeatherResponse>,
     pub error: Option<AggregatorError>,
+}
+
+impl From<ProviderCallResult<CurrentWeatherResponse>> for WeatherProviderResult {
+    fn from(call_result: ProviderCallResult<CurrentWeatherResponse>) -> Self {
+        match call_result.result {
+            Ok(data) => Self {
+                provider: call_result.provider,
+                data: Some(data),
+                error: None,
+            },
+            Err(e) => Self {
+                provider: call_result.provider,
+                data: None,
+                error: Some(e),
+            },
+        }
+    }
 }
\ No newline at end of file
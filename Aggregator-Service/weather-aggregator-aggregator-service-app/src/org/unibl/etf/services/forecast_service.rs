@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use actix_web::web;
+use reqwest_middleware::ClientWithMiddleware;
+use tracing::Instrument;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+use crate::org::unibl::etf::configuration::settings::{CacheServiceSettings, ProviderSettings, StrategySettings};
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::requests::aggregation_options::AggregationOptions;
+use crate::org::unibl::etf::model::requests::forecast_query::ForecastQuery;
+use crate::org::unibl::etf::model::responses::forecast_response::ForecastResponse;
+use crate::org::unibl::etf::strategy::forecast_merger::merge_forecasts;
+use crate::org::unibl::etf::strategy::strategy_factory::build_strategy;
+use crate::org::unibl::etf::services::provider_fan_out_service::{ProviderCallResult, ProviderFanOutService};
+
+#[derive(Debug)]
+pub struct ForecastService {
+    provider_fan_out_service: Arc<ProviderFanOutService>,
+}
+
+impl ForecastService {
+    pub fn new(provider_fan_out_service: Arc<ProviderFanOutService>) -> Self {
+        Self {
+            provider_fan_out_service,
+        }
+    }
+
+    #[tracing::instrument(name = "Get Forecast Data Service", skip(client, cache_service_settings, providers_settings, strategy_settings))]
+    pub async fn get_forecast<Q: ForecastQuery + std::fmt::Debug + Clone + Send + Sync + 'static>(
+        &self,
+        query: Q,
+        options: AggregationOptions,
+        client: web::Data<ClientWithMiddleware>,
+        providers_settings: web::Data<Vec<ProviderSettings>>,
+        cache_service_settings: web::Data<CacheServiceSettings>,
+        strategy_settings: web::Data<StrategySettings>,
+    ) -> Result<ForecastResponse, AggregatorError> {
+        match query.build_retrieve_cache_request() {
+            Ok(get_cache_req) => {
+                match query.cache_get(&get_cache_req, client.as_ref(), cache_service_settings.as_ref()).await {
+                    Ok(cached_data) => {
+                        tracing::info!("Cache hit for forecast data.");
+                        return Ok(cached_data);
+                    },
+                    Err(e) => {
+                        tracing::info!("Was not able to get cached forecast data: {:?}", e);
+                    }
+                }
+            },
+            Err(AggregatorError::CacheNotSupported(_)) => {
+                tracing::info!("Cache not supported for forecast query.");
+            },
+            Err(e) => return Err(e),
+        }
+
+        let req = query.build_downstream_request()?;
+        let downstream_client = client.clone();
+        let downstream_query = query.clone();
+        let (results, late_results) = self.provider_fan_out_service
+            .fan_out(ProviderEndpoint::Forecast, providers_settings.as_ref(), move |provider| {
+                let client = downstream_client.clone();
+                let req = req.clone();
+                let query = downstream_query.clone();
+
+                async move {
+                    query.call_provider(&provider, &req, &client).await
+                }
+            })
+            .await;
+
+        let normalized = results
+            .into_iter()
+            .map(ForecastProviderResult::from)
+            .collect::<Vec<_>>();
+
+        if let Some(candidates) = normalized.iter().find_map(|r| {
+            if let Some(AggregatorError::AmbiguousLocationNameError(c)) = &r.error {
+                Some(c.clone())
+            } else {
+                None
+            }
+        }) {
+            return Err(AggregatorError::AmbiguousLocationNameError(candidates));
+        }
+
+        if normalized
+            .iter()
+            .filter(|r| !r.error.as_ref().is_some_and(AggregatorError::is_provider_skipped))
+            .all(|r| matches!(r.error, Some(AggregatorError::LocationNotFoundError(_))))
+            && let Some(AggregatorError::LocationNotFoundError(loc)) =
+                normalized.iter().filter_map(|r| r.error.as_ref()).find(|e| matches!(e, AggregatorError::LocationNotFoundError(_)))
+        {
+            return Err(AggregatorError::LocationNotFoundError(loc.clone()));
+        }
+
+        let strategy_kind = options.strategy.unwrap_or(strategy_settings.default);
+        let strategy = build_strategy(strategy_kind, strategy_settings.as_ref(), providers_settings.as_ref());
+        let result = merge_forecasts(&normalized, strategy.as_ref())
+            .ok_or(AggregatorError::WeatherDataUnavailableError)?;
+
+        let result_clone = result.clone();
+        let cache_service_settings_clone = cache_service_settings.clone();
+        let client_clone = client.clone();
+
+        actix_web::rt::spawn(async move {
+            // Providers that missed the request budget still get a say in what is cached.
+            let (result_clone, normalized) = match late_results {
+                Some(late_results) => {
+                    let normalized = late_results
+                        .complete()
+                        .await
+                        .into_iter()
+                        .map(ForecastProviderResult::from)
+                        .collect::<Vec<_>>();
+                    let merged = merge_forecasts(&normalized, strategy.as_ref()).unwrap_or(result_clone);
+                    (merged, normalized)
+                },
+                None => (result_clone, normalized),
+            };
+
+            let mut location_names: Vec<String> = normalized
+                .iter()
+                .filter_map(|res| res.data.as_ref().and_then(|d| d.location.name.clone()))
+                .collect();
+            location_names.sort();
+            location_names.dedup();
+
+            match query.build_store_cache_request(&result_clone, location_names) {
+                Ok(store_cache_request) => {
+                    let _ = query
+                        .cache_set(&store_cache_request, &client_clone, cache_service_settings_clone.as_ref())
+                        .await;
+                },
+                Err(e) => {
+                    tracing::error!("Storing forecast cache error: {:?}", e);
+                }
+            }
+        }.instrument(tracing::Span::current()));
+
+        Ok(result)
+    }
+}
+
+
+#[derive(Debug)]
+pub struct ForecastProviderResult {
+    pub provider: String,
+    pub data: Option<ForecastResponse>,
+    pub error: Option<AggregatorError>,
+}
+
+impl From<ProviderCallResult<ForecastResponse>> for ForecastProviderResult {
+    fn from(call_result: ProviderCallResult<ForecastResponse>) -> Self {
+        match call_result.result {
+            Ok(data) => Self {
+                provider: call_result.provider,
+                data: Some(data),
+                error: None,
+            },
+            Err(e) => Self {
+                provider: call_result.provider,
+                data: None,
+                error: Some(e),
+            },
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use reqwest::Response;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::CacheServiceSettings;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
+use crate::org::unibl::etf::model::requests::retrieve_historical_weather_cache_request::RetrieveHistoricalWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::store_historical_weather_cache_request::StoreHistoricalWeatherCacheRequest;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+
+
+#[derive(Debug, Clone)]
+pub struct HistoricalWeatherCacheService {
+
+}
+
+impl HistoricalWeatherCacheService {
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[tracing::instrument(name = "Get Cached Historical Weather Data Function", skip(client, cache_service_settings))]
+    pub async fn get_cached_historical_weather_data_by_coordinates(
+        &self,
+        req: &RetrieveHistoricalWeatherCacheRequest,
+        client: &ClientWithMiddleware,
+        cache_service_settings: &CacheServiceSettings,
+    ) -> Result<Vec<CurrentWeatherResponse>, AggregatorError> {
+        let url = format!("{}://{}:{}/api/v1/historical_weather_by_coordinates", cache_service_settings.scheme, cache_service_settings.host, cache_service_settings.port);
+
+        let (lat, lon) = match (req.location.lat, req.location.lon) {
+            (Some(lat), Some(lon)) => (lat, lon),
+            _ => return Err(AggregatorError::ServerError(Some("Latitude and longitude not provided".to_string()))),
+        };
+
+        let response = client
+            .get(url)
+            .query(&[("lat", lat), ("lon", lon)])
+            .query(&req.date_range)
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        self.process_cache_service_get_cached_historical_weather_data_response(response).await
+    }
+
+    #[tracing::instrument(name = "Get Cached Historical Weather Data Function", skip(client, cache_service_settings))]
+    pub async fn get_cached_historical_weather_data_by_location(
+        &self,
+        req: &RetrieveHistoricalWeatherCacheRequest,
+        client: &ClientWithMiddleware,
+        cache_service_settings: &CacheServiceSettings,
+    ) -> Result<Vec<CurrentWeatherResponse>, AggregatorError> {
+        let url = format!("{}://{}:{}/api/v1/historical_weather_by_location", cache_service_settings.scheme, cache_service_settings.host, cache_service_settings.port);
+
+        let location_name = match &req.location.location_name {
+            Some(location_name) => location_name.clone(),
+            None => return Err(AggregatorError::ServerError(Some("Location name not provided".to_string()))),
+        };
+
+        let mut params = vec![("location_name", location_name)];
+        if let Some(country) = &req.location.country {
+            params.push(("country", country.clone()));
+        }
+        if let Some(state) = &req.location.state {
+            params.push(("state", state.clone()));
+        }
+
+        let response = client
+            .get(url)
+            .query(&params)
+            .query(&req.date_range)
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        self.process_cache_service_get_cached_historical_weather_data_response(response).await
+    }
+
+    #[tracing::instrument(name = "Send Historical Weather Data to Cache Service function", skip(client, cache_service_settings, data))]
+    pub async fn save_historical_weather_data_to_cache(
+        &self,
+        client: &ClientWithMiddleware,
+        cache_service_settings: &CacheServiceSettings,
+        data: &StoreHistoricalWeatherCacheRequest,
+    ) -> Result<(), AggregatorError> {
+        let url = format!("{}://{}:{}/api/v1/historical_weather", cache_service_settings.scheme, cache_service_settings.host, cache_service_settings.port);
+
+        let response = client
+            .put(url)
+            .json(&data)
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        if response.status().is_success() {
+            return Ok(());
+        }
+
+        let error_body_text = response.text().await.map_err(|e| {
+            AggregatorError::ServerError(Some(format!("Failed to get error body text: {}", e)))
+        })?;
+
+        let error_body: CacheServiceError = serde_json::from_str(&error_body_text)
+            .map_err(|e| {
+                AggregatorError::ResponseParsingError(Some(format!(
+                    "Error parsing Cache Service error response: JSON Error: {} | Raw Body: {}",
+                    e, error_body_text
+                )))
+            })?;
+        tracing::error!("Cache Service Error while trying to save historical weather data to cache with error response: {:?}", error_body);
+
+        Err(AggregatorError::from(error_body.error.code))
+    }
+
+    async fn process_cache_service_get_cached_historical_weather_data_response(
+        &self,
+        response: Response
+    ) -> Result<Vec<CurrentWeatherResponse>, AggregatorError> {
+        if response.status().is_success() {
+            let body_text = response.text()
+                .await
+                .map_err(|e| AggregatorError::ServerError(
+                    Some(format!("Failed to get Cache Service success response body text: {}", e))
+                ))?;
+
+            serde_json::from_str(&body_text)
+                .map_err(|e| {
+                    AggregatorError::ResponseParsingError(Some(format!(
+                        "Failed to parse Cache Service success response: JSON Error: {} | Raw Body: {}",
+                        e, body_text
+                    )))
+                })
+        } else {
+            let error_body_text = response.text().await.map_err(|e| {
+                AggregatorError::ServerError(Some(format!("Failed to get Cache Service error response body text: {}", e)))
+            })?;
+
+            let error_body: CacheServiceError = serde_json::from_str(&error_body_text)
+                .map_err(|e| {
+                    AggregatorError::ResponseParsingError(Some(format!(
+                        "Failed to parse Cache Service error response. JSON Error: {} | Raw Body: {}",
+                        e, error_body_text
+                    )))
+                })?;
+
+            tracing::info!("Cache Service Error while trying to get historical weather cached data with error response: {:?}", error_body);
+
+            Err(AggregatorError::from(error_body.error.code))
+        }
+    }
+}
+
+impl Default for HistoricalWeatherCacheService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
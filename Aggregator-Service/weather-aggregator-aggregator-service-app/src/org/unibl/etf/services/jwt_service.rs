@@ -0,0 +1,37 @@
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use crate::org::unibl::etf::configuration::settings::JwtSettings;
+use crate::org::unibl::etf::jwt::claims::Claims;
+use crate::org::unibl::etf::jwt::token_type::TokenType;
+
+#[derive(Clone)]
+pub struct JwtService {
+    signer_public_key: DecodingKey,
+    jwt_settings: JwtSettings,
+}
+
+impl JwtService {
+    pub fn new(signer_public_key: DecodingKey, jwt_settings: JwtSettings) -> Self {
+        Self {
+            signer_public_key,
+            jwt_settings,
+        }
+    }
+
+    // Only access tokens signed by the configured key identify a user, refresh tokens are rejected.
+    pub fn validate_access_token(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let header = jsonwebtoken::decode_header(token)?;
+        if header.kid.is_some_and(|kid| kid != self.jwt_settings.kid) {
+            return Err(jsonwebtoken::errors::ErrorKind::InvalidKeyFormat.into());
+        }
+
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        validation.set_issuer(std::slice::from_ref(&self.jwt_settings.issuer));
+
+        let claims = decode::<Claims>(token, &self.signer_public_key, &validation)?.claims;
+        if claims.typ != TokenType::ACCESS {
+            return Err(jsonwebtoken::errors::ErrorKind::InvalidToken.into());
+        }
+
+        Ok(claims)
+    }
+}
@@ -0,0 +1,110 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use lru::LruCache;
+use crate::org::unibl::etf::configuration::settings::LocalCacheSettings;
+use crate::org::unibl::etf::model::responses::local_cache_stats_response::LocalCacheStatsResponse;
+
+#[derive(Debug)]
+struct LocalCacheEntry<T> {
+    value: T,
+    stored_at: Instant,
+    expires_at: Instant,
+}
+
+// Bounded LRU with a per entry expiry, shared by every worker.
+#[derive(Debug)]
+pub struct LocalCacheService<T> {
+    settings: LocalCacheSettings,
+    // None when the cache is disabled or sized to zero.
+    entries: Option<Mutex<LruCache<String, LocalCacheEntry<T>>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl<T: Clone> LocalCacheService<T> {
+    pub fn new(settings: LocalCacheSettings) -> Self {
+        let entries = NonZeroUsize::new(settings.max_entries)
+            .filter(|_| settings.enabled)
+            .map(|capacity| Mutex::new(LruCache::new(capacity)));
+
+        Self {
+            settings,
+            entries,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.entries.is_some()
+    }
+
+    // Returns the value along with how long ago it was stored.
+    pub fn get(&self, key: &str) -> Option<(T, Duration)> {
+        let entries = self.entries.as_ref()?;
+        let mut entries = entries.lock().unwrap();
+
+        let now = Instant::now();
+        let found = match entries.get(key) {
+            Some(entry) if entry.expires_at > now => Some((entry.value.clone(), now - entry.stored_at)),
+            Some(_) => {
+                entries.pop(key);
+                None
+            },
+            None => None,
+        };
+
+        let counter = if found.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
+    // The entry lives for the configured TTL, or less if the caller knows the value expires sooner.
+    pub fn insert(&self, key: String, value: T, ttl: Option<Duration>) {
+        let Some(entries) = self.entries.as_ref() else {
+            return;
+        };
+
+        let configured_ttl = Duration::from_secs(self.settings.ttl_in_seconds);
+        let ttl = ttl.map_or(configured_ttl, |ttl| ttl.min(configured_ttl));
+        if ttl.is_zero() {
+            return;
+        }
+
+        let now = Instant::now();
+        let entry = LocalCacheEntry {
+            value,
+            stored_at: now,
+            expires_at: now + ttl,
+        };
+
+        let evicted = entries.lock().unwrap().push(key.clone(), entry);
+        if evicted.is_some_and(|(evicted_key, _)| evicted_key != key) {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn stats(&self) -> LocalCacheStatsResponse {
+        let entries = self.entries
+            .as_ref()
+            .map(|entries| entries.lock().unwrap().len())
+            .unwrap_or_default();
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let lookups = hits + misses;
+
+        LocalCacheStatsResponse {
+            enabled: self.is_enabled(),
+            entries,
+            max_entries: self.settings.max_entries,
+            hits,
+            misses,
+            evictions: self.evictions.load(Ordering::Relaxed),
+            hit_ratio: if lookups == 0 { 0.0 } else { hits as f64 / lookups as f64 },
+        }
+    }
+}
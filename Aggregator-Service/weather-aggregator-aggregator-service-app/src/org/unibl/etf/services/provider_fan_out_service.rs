@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use actix_web::rt::time::{sleep, timeout};
+use futures::channel::mpsc;
+use futures::future::{select, select_ok, Either};
+use futures::StreamExt;
+use tracing::Instrument;
+use crate::org::unibl::etf::configuration::settings::{FanOutSettings, ProviderSettings};
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+use crate::org::unibl::etf::services::circuit_breaker_service::CircuitBreakerService;
+
+#[derive(Debug, Clone)]
+pub struct ProviderCallResult<T> {
+    pub provider: String,
+    pub result: Result<T, AggregatorError>,
+}
+
+// Providers that were still in flight when the request budget ran out.
+pub struct PendingProviderResults<T> {
+    results: Vec<ProviderCallResult<T>>,
+    receiver: mpsc::UnboundedReceiver<(usize, Result<T, AggregatorError>)>,
+    pending: usize,
+}
+
+impl<T> PendingProviderResults<T> {
+    // Every call is bounded by its provider timeout, so this always finishes.
+    pub async fn complete(mut self) -> Vec<ProviderCallResult<T>> {
+        while self.pending > 0 {
+            match self.receiver.next().await {
+                Some((i, result)) => {
+                    self.results[i].result = result;
+                    self.pending -= 1;
+                },
+                None => break,
+            }
+        }
+        self.results
+    }
+}
+
+// Providers whose adapter reported a used-up quota are left out until the quota frees up again.
+#[derive(Debug, Default)]
+struct ProviderRateLimitCoolDowns {
+    rate_limited_until: Mutex<HashMap<String, Instant>>,
+}
+
+impl ProviderRateLimitCoolDowns {
+    fn remaining_in_seconds(&self, provider_name: &str) -> Option<u64> {
+        let mut rate_limited_until = self.rate_limited_until.lock().unwrap();
+        let remaining = rate_limited_until
+            .get(provider_name)
+            .map(|until| until.saturating_duration_since(Instant::now()))?;
+
+        if remaining.is_zero() {
+            rate_limited_until.remove(provider_name);
+            return None;
+        }
+
+        Some(remaining.as_secs().max(1))
+    }
+
+    fn record<T>(&self, provider_name: &str, result: &Result<T, AggregatorError>) {
+        if let Err(AggregatorError::ProviderRateLimitedError(Some(retry_after_in_seconds))) = result {
+            tracing::info!("Provider {} is rate limited for {} seconds, skipping it until then.", provider_name, retry_after_in_seconds);
+            self.rate_limited_until
+                .lock()
+                .unwrap()
+                .insert(provider_name.to_string(), Instant::now() + Duration::from_secs(*retry_after_in_seconds));
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ProviderFanOutService {
+    circuit_breaker_service: Arc<CircuitBreakerService>,
+    rate_limit_cool_downs: Arc<ProviderRateLimitCoolDowns>,
+    settings: FanOutSettings,
+}
+
+impl ProviderFanOutService {
+    pub fn new(circuit_breaker_service: Arc<CircuitBreakerService>, settings: FanOutSettings) -> Self {
+        Self {
+            circuit_breaker_service,
+            rate_limit_cool_downs: Arc::new(ProviderRateLimitCoolDowns::default()),
+            settings,
+        }
+    }
+
+    // Providers whose adapter does not serve the endpoint are left out entirely.
+    #[tracing::instrument(name = "Provider fan out", skip(self, providers, call))]
+    pub async fn fan_out<T, F, Fut>(
+        &self,
+        endpoint: ProviderEndpoint,
+        providers: &[ProviderSettings],
+        call: F,
+    ) -> (Vec<ProviderCallResult<T>>, Option<PendingProviderResults<T>>)
+    where
+        T: Clone + 'static,
+        F: Fn(ProviderSettings) -> Fut + Clone + 'static,
+        Fut: Future<Output = Result<T, AggregatorError>> + 'static,
+    {
+        let providers: Vec<ProviderSettings> = providers
+            .iter()
+            .filter(|provider| provider.supports(endpoint))
+            .cloned()
+            .collect();
+        let (sender, mut receiver) = mpsc::unbounded();
+
+        // Calls run as their own tasks so the ones that miss the budget can still finish in the background.
+        for (i, provider) in providers.iter().cloned().enumerate() {
+            if let Some(retry_after_in_seconds) = self.rate_limit_cool_downs.remaining_in_seconds(&provider.name) {
+                let _ = sender.unbounded_send((i, Err(AggregatorError::ProviderRateLimitedError(Some(retry_after_in_seconds)))));
+                continue;
+            }
+
+            let sender = sender.clone();
+            let call = call.clone();
+            let circuit_breaker_service = self.circuit_breaker_service.clone();
+            let rate_limit_cool_downs = self.rate_limit_cool_downs.clone();
+
+            actix_web::rt::spawn(async move {
+                let provider_name = provider.name.clone();
+                let result = circuit_breaker_service
+                    .call(&provider_name, endpoint, call_with_deadline(provider, call))
+                    .await;
+                rate_limit_cool_downs.record(&provider_name, &result);
+                let _ = sender.unbounded_send((i, result));
+            }.instrument(tracing::Span::current()));
+        }
+        drop(sender);
+
+        let mut results: Vec<ProviderCallResult<T>> = providers
+            .iter()
+            .map(|provider| ProviderCallResult {
+                provider: provider.name.clone(),
+                result: Err(AggregatorError::ProviderTimeoutError),
+            })
+            .collect();
+        let mut pending = providers.len();
+
+        let budget = sleep(Duration::from_millis(self.settings.request_budget_in_ms));
+        futures::pin_mut!(budget);
+
+        while pending > 0 {
+            match select(receiver.next(), budget.as_mut()).await {
+                Either::Left((Some((i, result)), _)) => {
+                    results[i].result = result;
+                    pending -= 1;
+                },
+                Either::Left((None, _)) => break,
+                Either::Right(_) => {
+                    tracing::warn!("Request budget exhausted with {} provider(s) still pending.", pending);
+                    break;
+                },
+            }
+        }
+
+        if pending == 0 {
+            return (results, None);
+        }
+
+        let pending_results = PendingProviderResults {
+            results: results.clone(),
+            receiver,
+            pending,
+        };
+
+        (results, Some(pending_results))
+    }
+}
+
+async fn call_with_deadline<T, F, Fut>(provider: ProviderSettings, call: F) -> Result<T, AggregatorError>
+where
+    F: Fn(ProviderSettings) -> Fut,
+    Fut: Future<Output = Result<T, AggregatorError>>,
+{
+    let deadline = Duration::from_millis(provider.timeout_in_ms);
+
+    let hedged_call = async {
+        let primary = Box::pin(call(provider.clone()));
+
+        let Some(hedge_after_in_ms) = provider.hedge_after_in_ms else {
+            return primary.await;
+        };
+
+        match select(primary, Box::pin(sleep(Duration::from_millis(hedge_after_in_ms)))).await {
+            Either::Left((result, _)) => result,
+            Either::Right((_, primary)) => {
+                tracing::info!("Provider {} is slow, sending hedged request.", provider.name);
+                let hedge = Box::pin(call(provider.clone()));
+                select_ok([primary, hedge]).await.map(|(result, _)| result)
+            },
+        }
+    };
+
+    timeout(deadline, hedged_call)
+        .await
+        .unwrap_or(Err(AggregatorError::ProviderTimeoutError))
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use futures::future::{BoxFuture, Shared};
+use futures::FutureExt;
+use tracing::Instrument;
+
+type InFlightRequest<T> = Shared<BoxFuture<'static, Option<T>>>;
+
+// Unregisters a request once its task ends, including when the task panics.
+struct InFlightRegistration<T> {
+    registry: Arc<Mutex<HashMap<String, InFlightRequest<T>>>>,
+    key: String,
+}
+
+impl<T> Drop for InFlightRegistration<T> {
+    fn drop(&mut self) {
+        if let Ok(mut registry) = self.registry.lock() {
+            registry.remove(&self.key);
+        }
+    }
+}
+
+// Single-flight deduplication: concurrent requests with the same key share one execution.
+pub struct RequestCoalescingService<T> {
+    in_flight: Arc<Mutex<HashMap<String, InFlightRequest<T>>>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> RequestCoalescingService<T> {
+    pub fn new() -> Self {
+        Self {
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // Returns None only if the shared execution panicked.
+    pub async fn coalesce<F>(&self, key: String, request: F) -> Option<T>
+    where
+        F: Future<Output = T> + 'static,
+    {
+        self.join_or_start(key, request).await
+    }
+
+    // Starts the request without waiting for it, unless one with the same key is already running.
+    pub fn start_in_background<F>(&self, key: String, request: F)
+    where
+        F: Future<Output = T> + 'static,
+    {
+        drop(self.join_or_start(key, request));
+    }
+
+    fn join_or_start<F>(&self, key: String, request: F) -> InFlightRequest<T>
+    where
+        F: Future<Output = T> + 'static,
+    {
+        let mut in_flight = self.in_flight.lock().unwrap();
+
+        if let Some(in_flight_request) = in_flight.get(&key) {
+            tracing::info!("Joining in-flight request for key {}.", key);
+            return in_flight_request.clone();
+        }
+
+        let registration = InFlightRegistration {
+            registry: self.in_flight.clone(),
+            key: key.clone(),
+        };
+
+        // Runs as its own task so a disconnecting client does not cancel it for everyone else.
+        let handle = actix_web::rt::spawn(async move {
+            let _registration = registration;
+            request.await
+        }.instrument(tracing::Span::current()));
+
+        let in_flight_request = async move { handle.await.ok() }.boxed().shared();
+        in_flight.insert(key, in_flight_request.clone());
+        in_flight_request
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Default for RequestCoalescingService<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for RequestCoalescingService<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let in_flight = self.in_flight.lock().map(|in_flight| in_flight.len()).unwrap_or_default();
+        f.debug_struct("RequestCoalescingService")
+            .field("in_flight", &in_flight)
+            .finish()
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::GeocodingServiceSettings;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::LocationCandidate;
+use crate::org::unibl::etf::model::responses::current_weather_response::Location;
+use crate::org::unibl::etf::model::responses::reverse_geocoding_response::ReverseGeocodingResponse;
+
+fn is_missing(value: &Option<String>) -> bool {
+    value.as_deref().is_none_or(str::is_empty)
+}
+
+#[derive(Debug)]
+pub struct ReverseGeocodingService {
+    // None when no Geocoding-Service is configured.
+    settings: Option<GeocodingServiceSettings>,
+}
+
+impl ReverseGeocodingService {
+    pub fn new(settings: Option<GeocodingServiceSettings>) -> Self {
+        Self { settings }
+    }
+
+    // Providers often answer coordinate queries without a place name or country, and the Cache-Service
+    // can only key such readings by geohash. None when nothing is missing or the lookup failed.
+    #[tracing::instrument(name = "Find Missing Location Details Function", skip(self, client))]
+    pub async fn find_missing_location_details(
+        &self,
+        location: &Location,
+        client: &ClientWithMiddleware,
+    ) -> Option<LocationCandidate> {
+        let settings = self.settings.as_ref()?;
+        if !is_missing(&location.name) && !is_missing(&location.country) {
+            return None;
+        }
+
+        match self.reverse_geocode(location.lat, location.lon, client, settings).await {
+            Ok(candidate) => candidate,
+            Err(e) => {
+                tracing::warn!("Was not able to reverse geocode {}, {}: {:?}", location.lat, location.lon, e);
+                None
+            }
+        }
+    }
+
+    // Only fills what the providers left out, their own names are kept.
+    pub fn fill_missing_location_details(location: &mut Location, details: &LocationCandidate) {
+        if is_missing(&location.name) {
+            location.name = Some(details.location_name.clone());
+        }
+        if is_missing(&location.country) {
+            location.country = Some(details.country.clone());
+        }
+        if is_missing(&location.state_region_province_or_entity) && !details.state.is_empty() {
+            location.state_region_province_or_entity = Some(details.state.clone());
+        }
+    }
+
+    async fn reverse_geocode(
+        &self,
+        lat: f64,
+        lon: f64,
+        client: &ClientWithMiddleware,
+        settings: &GeocodingServiceSettings,
+    ) -> Result<Option<LocationCandidate>, AggregatorError> {
+        let url = format!("{}://{}:{}/api/v1/reverse_geocode", settings.scheme, settings.host, settings.port);
+
+        let response = client
+            .get(url)
+            .query(&[("lat", lat.to_string()), ("lon", lon.to_string()), ("limit", "1".to_string())])
+            .timeout(Duration::from_millis(settings.timeout_in_ms))
+            .send()
+            .await
+            .map_err(|e| AggregatorError::ConnectionError(Some(e.to_string())))?;
+
+        let status = response.status();
+        let body_text = response.text()
+            .await
+            .map_err(|e| AggregatorError::ServerError(
+                Some(format!("Failed to get Geocoding Service response body text: {}", e))
+            ))?;
+
+        // Coordinates in the open sea have no name, which is not an error here.
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(AggregatorError::ServerError(Some(format!(
+                "Geocoding Service responded with status {}: {}", status, body_text
+            ))));
+        }
+
+        let response: ReverseGeocodingResponse = serde_json::from_str(&body_text)
+            .map_err(|e| {
+                AggregatorError::ResponseParsingError(Some(format!(
+                    "Failed to parse Geocoding Service response: JSON Error: {} | Raw Body: {}",
+                    e, body_text
+                )))
+            })?;
+
+        Ok(response.candidates.into_iter().next())
+    }
+}
+
+impl Default for ReverseGeocodingService {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+use actix_web::web;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::model::provider_endpoint::ProviderEndpoint;
+use crate::org::unibl::etf::configuration::settings::ProviderSettings;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::requests::weather_alerts_query::WeatherAlertsQuery;
+use crate::org::unibl::etf::model::responses::weather_alerts_response::{ProviderWeatherAlertsResponse, WeatherAlertsResponse};
+use crate::org::unibl::etf::strategy::alerts_merger::merge_alerts;
+use crate::org::unibl::etf::services::provider_fan_out_service::{ProviderCallResult, ProviderFanOutService};
+
+#[derive(Debug)]
+pub struct WeatherAlertsService {
+    provider_fan_out_service: Arc<ProviderFanOutService>,
+}
+
+impl WeatherAlertsService {
+    pub fn new(provider_fan_out_service: Arc<ProviderFanOutService>) -> Self {
+        Self {
+            provider_fan_out_service,
+        }
+    }
+
+    #[tracing::instrument(name = "Get Weather Alerts Service", skip(client, providers_settings))]
+    pub async fn get_weather_alerts<Q: WeatherAlertsQuery + std::fmt::Debug + Clone + Send + Sync + 'static>(
+        &self,
+        query: Q,
+        client: web::Data<ClientWithMiddleware>,
+        providers_settings: web::Data<Vec<ProviderSettings>>,
+    ) -> Result<WeatherAlertsResponse, AggregatorError> {
+        let req = query.build_downstream_request()?;
+        let downstream_client = client.clone();
+        let downstream_query = query.clone();
+        // Alerts are not cached, so providers that miss the request budget are simply dropped.
+        let (results, _) = self.provider_fan_out_service
+            .fan_out(ProviderEndpoint::WeatherAlerts, providers_settings.as_ref(), move |provider| {
+                let client = downstream_client.clone();
+                let req = req.clone();
+                let query = downstream_query.clone();
+
+                async move {
+                    query.call_provider(&provider, &req, &client).await
+                }
+            })
+            .await;
+
+        let normalized = results
+            .into_iter()
+            .map(WeatherAlertsProviderResult::from)
+            .collect::<Vec<_>>();
+
+        if let Some(candidates) = normalized.iter().find_map(|r| {
+            if let Some(AggregatorError::AmbiguousLocationNameError(c)) = &r.error {
+                Some(c.clone())
+            } else {
+                None
+            }
+        }) {
+            return Err(AggregatorError::AmbiguousLocationNameError(candidates));
+        }
+
+        if normalized
+            .iter()
+            .filter(|r| !r.error.as_ref().is_some_and(AggregatorError::is_provider_skipped))
+            .all(|r| matches!(r.error, Some(AggregatorError::LocationNotFoundError(_))))
+            && let Some(AggregatorError::LocationNotFoundError(loc)) =
+                normalized.iter().filter_map(|r| r.error.as_ref()).find(|e| matches!(e, AggregatorError::LocationNotFoundError(_)))
+        {
+            return Err(AggregatorError::LocationNotFoundError(loc.clone()));
+        }
+
+        // No alerts is a valid answer, only a failure of every provider is not.
+        let location = normalized
+            .iter()
+            .find_map(|r| r.data.as_ref().map(|d| d.location.clone()))
+            .ok_or(AggregatorError::WeatherDataUnavailableError)?;
+
+        Ok(WeatherAlertsResponse {
+            location,
+            alerts: merge_alerts(&normalized),
+        })
+    }
+}
+
+
+#[derive(Debug)]
+pub struct WeatherAlertsProviderResult {
+    pub provider: String,
+    pub data: Option<ProviderWeatherAlertsResponse>,
+    pub error: Option<AggregatorError>,
+}
+
+impl From<ProviderCallResult<ProviderWeatherAlertsResponse>> for WeatherAlertsProviderResult {
+    fn from(call_result: ProviderCallResult<ProviderWeatherAlertsResponse>) -> Self {
+        match call_result.result {
+            Ok(data) => Self {
+                provider: call_result.provider,
+                data: Some(data),
+                error: None,
+            },
+            Err(e) => Self {
+                provider: call_result.provider,
+                data: None,
+                error: Some(e),
+            },
+        }
+    }
+}
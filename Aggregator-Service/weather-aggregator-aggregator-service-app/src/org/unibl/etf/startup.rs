@@ -1,18 +1,33 @@
 
 use std::net::TcpListener;
+use std::sync::Arc;
 use actix_web::{web, App, HttpResponse, HttpServer, Responder};
 use actix_web::dev::Server;
 use actix_web_validator::QueryConfig;
 use chrono::Utc;
+use jsonwebtoken::DecodingKey;
 
 use reqwest_middleware::{ClientBuilder};
 use reqwest_tracing::TracingMiddleware;
 use crate::org::unibl::etf::configuration::Settings;
-use crate::org::unibl::etf::controllers::current_weather_controller;
+use crate::org::unibl::etf::controllers::{current_weather_controller, forecast_controller, historical_weather_controller, weather_alerts_controller, air_quality_controller};
 use crate::org::unibl::etf::handlers::query_error_handler::handle_validation_error;
 use crate::org::unibl::etf::services::current_weather_service::CurrentWeatherService;
+use crate::org::unibl::etf::services::forecast_service::ForecastService;
+use crate::org::unibl::etf::services::historical_weather_service::HistoricalWeatherService;
+use crate::org::unibl::etf::services::weather_alerts_service::WeatherAlertsService;
+use crate::org::unibl::etf::services::air_quality_service::AirQualityService;
+use crate::org::unibl::etf::services::circuit_breaker_service::CircuitBreakerService;
+use crate::org::unibl::etf::services::provider_fan_out_service::ProviderFanOutService;
+use crate::org::unibl::etf::services::client_rate_limit_service::ClientRateLimitService;
+use crate::org::unibl::etf::services::jwt_service::JwtService;
+use crate::org::unibl::etf::services::reverse_geocoding_service::ReverseGeocodingService;
 use crate::org::unibl::etf::model::responses::health_check_response::HealthCheckResponse;
 
+async fn local_cache_stats(current_weather_service: web::Data<CurrentWeatherService>) -> impl Responder {
+    HttpResponse::Ok().json(current_weather_service.local_cache_stats())
+}
+
 async fn health_check() -> impl Responder {
     let res = HealthCheckResponse {
         status: "UP".to_string(),
@@ -28,6 +43,7 @@ use rustls::ServerConfig;
 use tracing_actix_web::TracingLogger;
 use crate::org::unibl::etf::configuration::settings::HttpClientTlsIdentityBundle;
 use crate::org::unibl::etf::middlewares::json_500_middleware::Json500Middleware;
+use crate::org::unibl::etf::middlewares::client_rate_limit_middleware::ClientRateLimitMiddleware;
 
 // struct LogHeaders;
 //
@@ -52,9 +68,12 @@ use crate::org::unibl::etf::middlewares::json_500_middleware::Json500Middleware;
 
 pub fn run(
     tcp_listener: TcpListener,
+    internal_tcp_listener: Option<TcpListener>,
     configuration: Settings,
     server_config: Option<ServerConfig>,
-    client_config: HttpClientTlsIdentityBundle
+    client_config: HttpClientTlsIdentityBundle,
+    redis_pool: Option<deadpool_redis::Pool>,
+    signer_jwt_public_key: Option<DecodingKey>,
 ) -> std::io::Result<Server> {
 
     let mut client_builder = reqwest::Client::builder();
@@ -68,22 +87,52 @@ pub fn run(
             .build()
     );
 
-    let current_weather_service = web::Data::new(CurrentWeatherService::default());
+    let circuit_breaker_service = Arc::new(CircuitBreakerService::new(configuration.circuit_breaker));
+    let provider_fan_out_service = Arc::new(ProviderFanOutService::new(circuit_breaker_service, configuration.fan_out));
+    let reverse_geocoding_service = Arc::new(ReverseGeocodingService::new(configuration.geocoding_service));
+    let current_weather_service = web::Data::new(CurrentWeatherService::new(
+        provider_fan_out_service.clone(),
+        reverse_geocoding_service,
+        configuration.local_cache,
+    ));
+    let internal_current_weather_service = current_weather_service.clone();
+    let forecast_service = web::Data::new(ForecastService::new(provider_fan_out_service.clone()));
+    let historical_weather_service = web::Data::new(HistoricalWeatherService::new(provider_fan_out_service.clone()));
+    let weather_alerts_service = web::Data::new(WeatherAlertsService::new(provider_fan_out_service.clone()));
+    let air_quality_service = web::Data::new(AirQualityService::new(provider_fan_out_service.clone()));
     let providers_settings = web::Data::new(configuration.providers);
     let cache_service_settings = web::Data::new(configuration.cache_service);
+    let strategy_settings = web::Data::new(configuration.strategy);
+    let jwt_service = signer_jwt_public_key
+        .zip(configuration.jwt)
+        .map(|(signer_jwt_public_key, jwt_settings)| Arc::new(JwtService::new(signer_jwt_public_key, jwt_settings)));
+    let client_rate_limit_service = Arc::new(ClientRateLimitService::new(configuration.client_rate_limit, jwt_service));
 
     let mut server = HttpServer::new(move || {
         App::new()
             .app_data(http_client.clone())
             .app_data(current_weather_service.clone())
+            .app_data(forecast_service.clone())
+            .app_data(historical_weather_service.clone())
+            .app_data(weather_alerts_service.clone())
+            .app_data(air_quality_service.clone())
             .app_data(providers_settings.clone())
             .app_data(cache_service_settings.clone())
+            .app_data(strategy_settings.clone())
             .app_data(QueryConfig::default().error_handler(handle_validation_error))
             .wrap(TracingLogger::default())
             .wrap(Json500Middleware)
             .service(
                 web::scope("/api/v1")
+                    .wrap(ClientRateLimitMiddleware {
+                        client_rate_limit_service: Arc::clone(&client_rate_limit_service),
+                        redis_pool: redis_pool.clone(),
+                    })
                     .configure(current_weather_controller::routes)
+                    .configure(forecast_controller::routes)
+                    .configure(historical_weather_controller::routes)
+                    .configure(weather_alerts_controller::routes)
+                    .configure(air_quality_controller::routes)
             )
             .route("/health_check", web::get().to(health_check))
     });
@@ -92,6 +141,24 @@ pub fn run(
         None => server.listen(tcp_listener)?,
     };
 
+    if let Some(internal_tcp_listener) = internal_tcp_listener {
+        let internal_server = HttpServer::new(move || {
+            App::new()
+                .app_data(internal_current_weather_service.clone())
+                .wrap(TracingLogger::default())
+                .route("/local_cache_stats", web::get().to(local_cache_stats))
+        })
+        .workers(1)
+        .listen(internal_tcp_listener)?
+        .run();
+
+        actix_web::rt::spawn(async move {
+            if let Err(e) = internal_server.await {
+                tracing::error!("Internal server stopped with error: {}", e);
+            }
+        });
+    }
+
 
     Ok(server.run())
 
@@ -1,4 +1,13 @@
 pub mod weather_strategy;
 pub mod average_strategy;
 pub mod composite_strategy;
-pub mod priority_strategy;
\ No newline at end of file
+pub mod priority_strategy;
+pub mod weighted_average_strategy;
+pub mod trimmed_mean_strategy;
+pub mod aggregation;
+pub mod strategy_kind;
+pub mod strategy_factory;
+pub mod forecast_merger;
+pub mod historical_merger;
+pub mod alerts_merger;
+pub mod air_quality_merger;
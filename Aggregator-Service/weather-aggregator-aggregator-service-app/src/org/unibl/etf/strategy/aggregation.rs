@@ -0,0 +1,177 @@
+use std::f64::consts::PI;
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::responses::current_weather_response::{CurrentWeatherResponse, Location, Weather, Wind};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregatedField {
+    Location,
+    Temperature,
+    TemperatureFeelsLike,
+    Humidity,
+    Pressure,
+    WindSpeed,
+    WindGust,
+    WindDirection,
+    Condition,
+    ObservedAt,
+}
+
+/// Builds a single aggregated response out of the valid provider responses.
+/// `weight` returns how much the response at a given index contributes to a field,
+/// a weight of 0.0 excludes that response from the field entirely.
+pub fn aggregate_weighted(
+    valid: &[&CurrentWeatherResponse],
+    weight: impl Fn(AggregatedField, usize) -> f64,
+) -> Option<CurrentWeatherResponse> {
+    if valid.is_empty() {
+        return None;
+    }
+
+    let weighted = |field: AggregatedField| {
+        let weight = &weight;
+        valid
+            .iter()
+            .enumerate()
+            .map(move |(i, r)| (*r, weight(field, i)))
+            .filter(|(_, w)| *w > 0.0)
+    };
+
+    Some(CurrentWeatherResponse {
+        provider: "aggregated".to_string(),
+
+        location: Location {
+            name: valid.iter().find_map(|r| r.location.name.clone()),
+            country: valid.iter().find_map(|r| r.location.country.clone()),
+            lat: weighted_mean(weighted(AggregatedField::Location).map(|(r, w)| (r.location.lat, w))).unwrap_or(0.0),
+            lon: weighted_mean(weighted(AggregatedField::Location).map(|(r, w)| (r.location.lon, w))).unwrap_or(0.0),
+            state_region_province_or_entity: valid.iter().find_map(|r| r.location.state_region_province_or_entity.clone()),
+        },
+
+        weather: Weather {
+            temp_metric: weighted_mean(
+                weighted(AggregatedField::Temperature).map(|(r, w)| (r.weather.temp_metric, w)),
+            )?,
+            temp_imperial: weighted_mean(
+                weighted(AggregatedField::Temperature).map(|(r, w)| (r.weather.temp_imperial, w)),
+            )?,
+
+            temp_feelslike_metric: weighted_mean_opt(
+                weighted(AggregatedField::TemperatureFeelsLike).map(|(r, w)| (r.weather.temp_feelslike_metric, w)),
+            ),
+            temp_feelslike_imperial: weighted_mean_opt(
+                weighted(AggregatedField::TemperatureFeelsLike).map(|(r, w)| (r.weather.temp_feelslike_imperial, w)),
+            ),
+
+            humidity: weighted_mean_opt(
+                weighted(AggregatedField::Humidity).map(|(r, w)| (r.weather.humidity.map(f64::from), w)),
+            ).map(|v| v.round() as u8),
+
+            pressure_metric: weighted_mean_opt(
+                weighted(AggregatedField::Pressure).map(|(r, w)| (r.weather.pressure_metric, w)),
+            ),
+            pressure_imperial: weighted_mean_opt(
+                weighted(AggregatedField::Pressure).map(|(r, w)| (r.weather.pressure_imperial, w)),
+            ),
+
+            condition: majority_vote(
+                weighted(AggregatedField::Condition).map(|(r, w)| (r.weather.condition.as_deref(), w)),
+            ),
+        },
+
+        wind: Wind {
+            speed_metric: weighted_mean_opt(
+                weighted(AggregatedField::WindSpeed).map(|(r, w)| (r.wind.speed_metric, w)),
+            ),
+            speed_imperial: weighted_mean_opt(
+                weighted(AggregatedField::WindSpeed).map(|(r, w)| (r.wind.speed_imperial, w)),
+            ),
+            gust_metric: weighted_mean_opt(
+                weighted(AggregatedField::WindGust).map(|(r, w)| (r.wind.gust_metric, w)),
+            ),
+            gust_imperial: weighted_mean_opt(
+                weighted(AggregatedField::WindGust).map(|(r, w)| (r.wind.gust_imperial, w)),
+            ),
+
+            direction: majority_vote(
+                weighted(AggregatedField::WindDirection).map(|(r, w)| (r.wind.direction.as_deref(), w)),
+            ),
+            degrees: circular_mean_degrees(
+                weighted(AggregatedField::WindDirection).map(|(r, w)| (r.wind.degrees, w)),
+            ),
+        },
+
+        observed_at_timestamp: weighted_mean_opt(
+            weighted(AggregatedField::ObservedAt).map(|(r, w)| (r.observed_at_timestamp.map(|v| v as f64), w)),
+        ).map(|v| v as i64),
+        aggregation: None,
+        is_stale: false,
+        stale_age_in_seconds: None,
+        ttl_remaining_in_seconds: None,
+    })
+}
+
+pub fn weighted_mean(values: impl Iterator<Item = (f64, f64)>) -> Option<f64> {
+    let (sum, total_weight) = values.fold((0.0, 0.0), |(s, tw), (v, w)| (s + v * w, tw + w));
+    if total_weight > 0.0 { Some(sum / total_weight) } else { None }
+}
+
+pub fn weighted_mean_opt(values: impl Iterator<Item = (Option<f64>, f64)>) -> Option<f64> {
+    weighted_mean(values.filter_map(|(v, w)| v.map(|v| (v, w))))
+}
+
+pub fn median(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        Some((sorted[middle - 1] + sorted[middle]) / 2.0)
+    } else {
+        Some(sorted[middle])
+    }
+}
+
+/// Picks the value with the highest total weight. Values are compared case-insensitively,
+/// ties are resolved in favour of the value seen first.
+pub fn majority_vote<'a>(values: impl Iterator<Item = (Option<&'a str>, f64)>) -> Option<String> {
+    let mut votes: Vec<(&str, f64)> = Vec::new();
+
+    for (value, w) in values {
+        let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+            continue;
+        };
+
+        match votes.iter_mut().find(|(v, _)| v.eq_ignore_ascii_case(value)) {
+            Some((_, total)) => *total += w,
+            None => votes.push((value, w)),
+        }
+    }
+
+    votes
+        .into_iter()
+        .fold(None, |best: Option<(&str, f64)>, (v, w)| match best {
+            Some((_, best_w)) if best_w >= w => best,
+            _ => Some((v, w)),
+        })
+        .map(|(v, _)| v.to_string())
+}
+
+/// Averages angles on the unit circle, so 350° and 10° give 0° instead of 180°.
+/// Returns None when the directions cancel each other out.
+fn circular_mean_degrees(values: impl Iterator<Item = (Option<u16>, f64)>) -> Option<u16> {
+    let (sin_sum, cos_sum, total_weight) = values
+        .filter_map(|(v, w)| v.map(|v| (f64::from(v) * PI / 180.0, w)))
+        .fold((0.0, 0.0, 0.0), |(s, c, tw), (rad, w)| (s + rad.sin() * w, c + rad.cos() * w, tw + w));
+
+    if total_weight <= 0.0 || sin_sum.hypot(cos_sum) / total_weight < 1e-6 {
+        return None;
+    }
+
+    let degrees = sin_sum.atan2(cos_sum).to_degrees().rem_euclid(360.0).round();
+    Some((degrees as u16) % 360)
+}
@@ -0,0 +1,39 @@
+use crate::org::unibl::etf::model::responses::air_quality_response::{AirQualityResponse, Pollutants};
+use crate::org::unibl::etf::services::air_quality_service::AirQualityProviderResult;
+use crate::org::unibl::etf::strategy::aggregation::weighted_mean_opt;
+
+/// Averages the pollutant concentrations of all providers. The index is not averaged,
+/// the worst one reported is kept together with its category.
+pub fn merge_air_quality(results: &[AirQualityProviderResult]) -> Option<AirQualityResponse> {
+    let valid: Vec<&AirQualityResponse> = results
+        .iter()
+        .filter_map(|r| r.data.as_ref())
+        .collect();
+
+    let first = valid.first()?;
+    if valid.len() == 1 {
+        return Some((*first).clone());
+    }
+
+    let mean = |value: fn(&Pollutants) -> Option<f64>| weighted_mean_opt(valid.iter().map(|d| (value(&d.pollutants), 1.0)));
+    let worst = valid
+        .iter()
+        .filter(|d| d.aqi.is_some())
+        .max_by_key(|d| d.aqi);
+
+    Some(AirQualityResponse {
+        provider: "aggregated".to_string(),
+        location: first.location.clone(),
+        pollutants: Pollutants {
+            pm2_5: mean(|p| p.pm2_5),
+            pm10: mean(|p| p.pm10),
+            o3: mean(|p| p.o3),
+            no2: mean(|p| p.no2),
+            so2: mean(|p| p.so2),
+            co: mean(|p| p.co),
+        },
+        aqi: worst.and_then(|d| d.aqi),
+        aqi_category: worst.and_then(|d| d.aqi_category.clone()),
+        observed_at_timestamp: valid.iter().filter_map(|d| d.observed_at_timestamp).max(),
+    })
+}
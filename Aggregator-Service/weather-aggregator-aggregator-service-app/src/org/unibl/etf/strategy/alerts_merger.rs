@@ -0,0 +1,92 @@
+use crate::org::unibl::etf::model::responses::weather_alerts_response::{ProviderWeatherAlert, WeatherAlert};
+use crate::org::unibl::etf::services::weather_alerts_service::WeatherAlertsProviderResult;
+
+/// Collects the alerts from all providers, folding alerts which describe the same event
+/// over overlapping time windows into one entry listing every provider that reported it.
+pub fn merge_alerts(results: &[WeatherAlertsProviderResult]) -> Vec<WeatherAlert> {
+    let mut alerts: Vec<ProviderWeatherAlert> = results
+        .iter()
+        .filter_map(|r| r.data.as_ref())
+        .flat_map(|d| d.alerts.iter().cloned())
+        .collect();
+    alerts.sort_by_key(|a| a.start_timestamp);
+
+    let mut merged: Vec<WeatherAlert> = Vec::new();
+
+    for alert in alerts {
+        match merged.iter_mut().find(|m| is_same_alert(m, &alert)) {
+            Some(existing) => absorb(existing, alert),
+            None => merged.push(WeatherAlert {
+                event: alert.event,
+                severity: non_empty(alert.severity),
+                start_timestamp: alert.start_timestamp,
+                end_timestamp: alert.end_timestamp,
+                description: non_empty(alert.description),
+                source_providers: vec![alert.source_provider],
+            }),
+        }
+    }
+
+    merged
+}
+
+fn is_same_alert(existing: &WeatherAlert, alert: &ProviderWeatherAlert) -> bool {
+    let a = normalize_event(&existing.event);
+    let b = normalize_event(&alert.event);
+    if a.is_empty() || b.is_empty() || !(a.contains(&b) || b.contains(&a)) {
+        return false;
+    }
+
+    // An alert without an end time is treated as still active.
+    let starts_before_other_ends = existing.start_timestamp <= alert.end_timestamp.unwrap_or(i64::MAX);
+    let ends_after_other_starts = existing.end_timestamp.unwrap_or(i64::MAX) >= alert.start_timestamp;
+
+    starts_before_other_ends && ends_after_other_starts
+}
+
+fn absorb(existing: &mut WeatherAlert, alert: ProviderWeatherAlert) {
+    existing.start_timestamp = existing.start_timestamp.min(alert.start_timestamp);
+    existing.end_timestamp = match (existing.end_timestamp, alert.end_timestamp) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        _ => None,
+    };
+
+    let severity = non_empty(alert.severity);
+    if severity_rank(severity.as_deref()) > severity_rank(existing.severity.as_deref()) {
+        existing.severity = severity;
+    }
+
+    // The most detailed description wins.
+    let description = non_empty(alert.description);
+    if description.as_ref().map(|d| d.len()) > existing.description.as_ref().map(|d| d.len()) {
+        existing.description = description;
+    }
+
+    if !existing.source_providers.contains(&alert.source_provider) {
+        existing.source_providers.push(alert.source_provider);
+    }
+}
+
+fn normalize_event(event: &str) -> String {
+    event
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// CAP severity levels, anything else ranks below them.
+fn severity_rank(severity: Option<&str>) -> u8 {
+    match severity.map(|s| s.to_lowercase()).as_deref() {
+        Some("minor") => 1,
+        Some("moderate") => 2,
+        Some("severe") => 3,
+        Some("extreme") => 4,
+        _ => 0,
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.trim().is_empty())
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, BTreeSet};
+use crate::org::unibl::etf::model::responses::current_weather_response::{CurrentWeatherResponse, Weather, Wind};
+use crate::org::unibl::etf::model::responses::forecast_response::{DailyForecast, ForecastResponse, HourlyForecast};
+use crate::org::unibl::etf::services::current_weather_service::WeatherProviderResult;
+use crate::org::unibl::etf::services::forecast_service::ForecastProviderResult;
+use crate::org::unibl::etf::strategy::aggregation::weighted_mean_opt;
+use crate::org::unibl::etf::strategy::weather_strategy::WeatherStrategy;
+
+/// Merges the provider forecasts into one. Hourly slots are matched by timestamp and daily entries by date,
+/// both are resolved with the same strategy used for current weather.
+pub fn merge_forecasts(
+    results: &[ForecastProviderResult],
+    strategy: &dyn WeatherStrategy,
+) -> Option<ForecastResponse> {
+    let valid: Vec<(&str, &ForecastResponse)> = results
+        .iter()
+        .filter_map(|r| r.data.as_ref().map(|d| (r.provider.as_str(), d)))
+        .collect();
+
+    let (_, first) = valid.first()?;
+
+    let hourly = merge_hourly(&valid, strategy);
+    let daily = merge_daily(&valid, strategy);
+
+    if hourly.is_empty() && daily.is_empty() {
+        return None;
+    }
+
+    let provider = if valid.len() > 1 {
+        "aggregated".to_string()
+    } else {
+        first.provider.clone()
+    };
+
+    Some(ForecastResponse {
+        provider,
+        location: first.location.clone(),
+        hourly,
+        daily,
+    })
+}
+
+fn merge_hourly(valid: &[(&str, &ForecastResponse)], strategy: &dyn WeatherStrategy) -> Vec<HourlyForecast> {
+    let timestamps: BTreeSet<i64> = valid
+        .iter()
+        .flat_map(|(_, f)| f.hourly.iter().map(|h| h.timestamp))
+        .collect();
+
+    timestamps
+        .into_iter()
+        .filter_map(|timestamp| {
+            let slots: Vec<(&str, &ForecastResponse, &HourlyForecast)> = valid
+                .iter()
+                .filter_map(|(provider, f)| {
+                    f.hourly.iter().find(|h| h.timestamp == timestamp).map(|h| (*provider, *f, h))
+                })
+                .collect();
+
+            let slot_results: Vec<WeatherProviderResult> = slots
+                .iter()
+                .map(|(provider, f, h)| slot_result(provider, f, h.weather.clone(), h.wind.clone(), Some(timestamp)))
+                .collect();
+
+            let resolved = strategy.resolve(&slot_results)?;
+
+            Some(HourlyForecast {
+                timestamp,
+                weather: resolved.weather,
+                wind: resolved.wind,
+                precipitation_probability: weighted_mean_opt(
+                    slots.iter().map(|(_, _, h)| (h.precipitation_probability.map(f64::from), 1.0)),
+                ).map(|p| p.round() as u8),
+            })
+        })
+        .collect()
+}
+
+// A day carries two temperatures, so the minimum and the maximum are resolved as separate slots.
+// Everything else is taken from the maximum slot.
+fn merge_daily(valid: &[(&str, &ForecastResponse)], strategy: &dyn WeatherStrategy) -> Vec<DailyForecast> {
+    let mut days: BTreeMap<&str, Vec<(&str, &ForecastResponse, &DailyForecast)>> = BTreeMap::new();
+    for (provider, f) in valid {
+        for day in &f.daily {
+            days.entry(day.date.as_str()).or_default().push((*provider, *f, day));
+        }
+    }
+
+    days.into_iter()
+        .filter_map(|(date, entries)| {
+            let max_results: Vec<WeatherProviderResult> = entries
+                .iter()
+                .map(|(provider, f, d)| {
+                    slot_result(provider, f, daily_weather(d, d.temp_max_metric, d.temp_max_imperial), daily_wind(d), None)
+                })
+                .collect();
+            let min_results: Vec<WeatherProviderResult> = entries
+                .iter()
+                .map(|(provider, f, d)| {
+                    slot_result(provider, f, daily_weather(d, d.temp_min_metric, d.temp_min_imperial), daily_wind(d), None)
+                })
+                .collect();
+
+            let max = strategy.resolve(&max_results)?;
+            let min = strategy.resolve(&min_results)?;
+
+            Some(DailyForecast {
+                date: date.to_string(),
+                temp_min_metric: min.weather.temp_metric,
+                temp_min_imperial: min.weather.temp_imperial,
+                temp_max_metric: max.weather.temp_metric,
+                temp_max_imperial: max.weather.temp_imperial,
+                humidity: max.weather.humidity,
+                wind_speed_max_metric: max.wind.speed_metric,
+                wind_speed_max_imperial: max.wind.speed_imperial,
+                precipitation_probability: weighted_mean_opt(
+                    entries.iter().map(|(_, _, d)| (d.precipitation_probability.map(f64::from), 1.0)),
+                ).map(|p| p.round() as u8),
+                condition: max.weather.condition,
+            })
+        })
+        .collect()
+}
+
+fn daily_weather(day: &DailyForecast, temp_metric: f64, temp_imperial: f64) -> Weather {
+    Weather {
+        temp_metric,
+        temp_imperial,
+        temp_feelslike_metric: None,
+        temp_feelslike_imperial: None,
+        humidity: day.humidity,
+        pressure_metric: None,
+        pressure_imperial: None,
+        condition: day.condition.clone(),
+    }
+}
+
+fn daily_wind(day: &DailyForecast) -> Wind {
+    Wind {
+        speed_metric: day.wind_speed_max_metric,
+        speed_imperial: day.wind_speed_max_imperial,
+        gust_metric: None,
+        gust_imperial: None,
+        direction: None,
+        degrees: None,
+    }
+}
+
+fn slot_result(
+    provider: &str,
+    forecast: &ForecastResponse,
+    weather: Weather,
+    wind: Wind,
+    observed_at_timestamp: Option<i64>,
+) -> WeatherProviderResult {
+    WeatherProviderResult {
+        provider: provider.to_string(),
+        data: Some(CurrentWeatherResponse {
+            provider: forecast.provider.clone(),
+            location: forecast.location.clone(),
+            weather,
+            wind,
+            observed_at_timestamp,
+            aggregation: None,
+            is_stale: false,
+            stale_age_in_seconds: None,
+            ttl_remaining_in_seconds: None,
+        }),
+        error: None,
+    }
+}
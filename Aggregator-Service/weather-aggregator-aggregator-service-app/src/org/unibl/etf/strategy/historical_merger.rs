@@ -0,0 +1,41 @@
+use std::collections::BTreeSet;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+use crate::org::unibl::etf::services::current_weather_service::WeatherProviderResult;
+use crate::org::unibl::etf::services::historical_weather_service::HistoricalWeatherProviderResult;
+use crate::org::unibl::etf::strategy::weather_strategy::WeatherStrategy;
+
+/// Resolves the provider observations timestamp by timestamp with the given strategy.
+/// Observations without a timestamp can't be matched across providers and are dropped.
+pub fn merge_observations(
+    results: &[HistoricalWeatherProviderResult],
+    strategy: &dyn WeatherStrategy,
+) -> Vec<CurrentWeatherResponse> {
+    let timestamps: BTreeSet<i64> = results
+        .iter()
+        .filter_map(|r| r.data.as_ref())
+        .flat_map(|observations| observations.iter().filter_map(|o| o.observed_at_timestamp))
+        .collect();
+
+    timestamps
+        .into_iter()
+        .filter_map(|timestamp| {
+            let slot_results: Vec<WeatherProviderResult> = results
+                .iter()
+                .filter_map(|r| {
+                    let observation = r.data
+                        .as_ref()?
+                        .iter()
+                        .find(|o| o.observed_at_timestamp == Some(timestamp))?;
+
+                    Some(WeatherProviderResult {
+                        provider: r.provider.clone(),
+                        data: Some(observation.clone()),
+                        error: None,
+                    })
+                })
+                .collect();
+
+            strategy.resolve(&slot_results)
+        })
+        .collect()
+}
@@ -0,0 +1,41 @@
+use crate::org::unibl::etf::configuration::settings::{ProviderSettings, StrategySettings};
+use crate::org::unibl::etf::strategy::average_strategy::AverageStrategy;
+use crate::org::unibl::etf::strategy::composite_strategy::CompositeStrategy;
+use crate::org::unibl::etf::strategy::priority_strategy::PriorityStrategy;
+use crate::org::unibl::etf::strategy::strategy_kind::StrategyKind;
+use crate::org::unibl::etf::strategy::trimmed_mean_strategy::TrimmedMeanStrategy;
+use crate::org::unibl::etf::strategy::weather_strategy::WeatherStrategy;
+use crate::org::unibl::etf::strategy::weighted_average_strategy::WeightedAverageStrategy;
+
+pub fn build_strategy(
+    kind: StrategyKind,
+    strategy_settings: &StrategySettings,
+    providers_settings: &[ProviderSettings],
+) -> Box<dyn WeatherStrategy> {
+    match kind {
+        StrategyKind::Priority => Box::new(build_priority_strategy(strategy_settings)),
+        StrategyKind::Average => Box::new(AverageStrategy),
+        StrategyKind::Composite => Box::new(CompositeStrategy {
+            average_min: strategy_settings.composite.average_min,
+            priority: build_priority_strategy(strategy_settings),
+        }),
+        StrategyKind::WeightedAverage => Box::new(WeightedAverageStrategy {
+            weights: providers_settings
+                .iter()
+                .map(|provider| (provider.name.clone(), provider.weight))
+                .collect(),
+        }),
+        StrategyKind::TrimmedMean => Box::new(TrimmedMeanStrategy {
+            max_temperature_deviation: strategy_settings.trimmed_mean.max_temperature_deviation,
+            max_pressure_deviation: strategy_settings.trimmed_mean.max_pressure_deviation,
+            max_humidity_deviation: strategy_settings.trimmed_mean.max_humidity_deviation,
+            max_wind_speed_deviation: strategy_settings.trimmed_mean.max_wind_speed_deviation,
+        }),
+    }
+}
+
+fn build_priority_strategy(strategy_settings: &StrategySettings) -> PriorityStrategy {
+    PriorityStrategy {
+        order: strategy_settings.priority.order.clone(),
+    }
+}
@@ -0,0 +1,128 @@
+use crate::org::unibl::etf::model::responses::aggregation_metadata::TrimmedProvider;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+use crate::org::unibl::etf::services::current_weather_service::WeatherProviderResult;
+use crate::org::unibl::etf::strategy::aggregation::{aggregate_weighted, median, AggregatedField};
+use crate::org::unibl::etf::strategy::weather_strategy::WeatherStrategy;
+
+// Deviations are absolute and expressed in metric units (°C, hPa, %, m/s).
+// The imperial counterpart of a field always uses the same set of providers as the metric one.
+pub struct TrimmedMeanStrategy {
+    pub max_temperature_deviation: f64,
+    pub max_pressure_deviation: f64,
+    pub max_humidity_deviation: f64,
+    pub max_wind_speed_deviation: f64,
+}
+
+impl TrimmedMeanStrategy {
+    // For every trimmed field, which of the valid responses are kept.
+    fn kept_by_field(&self, valid: &[&CurrentWeatherResponse]) -> Vec<(AggregatedField, Vec<bool>)> {
+        vec![
+            (AggregatedField::Temperature, within_deviation(
+                valid.iter().map(|r| Some(r.weather.temp_metric)),
+                self.max_temperature_deviation,
+            )),
+            (AggregatedField::TemperatureFeelsLike, within_deviation(
+                valid.iter().map(|r| r.weather.temp_feelslike_metric),
+                self.max_temperature_deviation,
+            )),
+            (AggregatedField::Humidity, within_deviation(
+                valid.iter().map(|r| r.weather.humidity.map(f64::from)),
+                self.max_humidity_deviation,
+            )),
+            (AggregatedField::Pressure, within_deviation(
+                valid.iter().map(|r| r.weather.pressure_metric),
+                self.max_pressure_deviation,
+            )),
+            (AggregatedField::WindSpeed, within_deviation(
+                valid.iter().map(|r| r.wind.speed_metric),
+                self.max_wind_speed_deviation,
+            )),
+            (AggregatedField::WindGust, within_deviation(
+                valid.iter().map(|r| r.wind.gust_metric),
+                self.max_wind_speed_deviation,
+            )),
+        ]
+    }
+
+    // Fields each provider with data was trimmed from, in the order of `results`.
+    fn trimmed_fields<'a>(&self, results: &'a [WeatherProviderResult]) -> Vec<(&'a str, Vec<AggregatedField>)> {
+        let (providers, valid): (Vec<&str>, Vec<&CurrentWeatherResponse>) = results
+            .iter()
+            .filter_map(|r| r.data.as_ref().map(|data| (r.provider.as_str(), data)))
+            .unzip();
+        let kept_by_field = self.kept_by_field(&valid);
+
+        providers
+            .into_iter()
+            .enumerate()
+            .map(|(i, provider)| {
+                let fields = kept_by_field
+                    .iter()
+                    .filter(|(_, kept)| !kept[i])
+                    .map(|(field, _)| *field)
+                    .collect();
+                (provider, fields)
+            })
+            .collect()
+    }
+}
+
+impl WeatherStrategy for TrimmedMeanStrategy {
+    fn resolve(&self, results: &[WeatherProviderResult]) -> Option<CurrentWeatherResponse> {
+        let valid: Vec<&CurrentWeatherResponse> =
+            results.iter().filter_map(|r| r.data.as_ref()).collect();
+        let kept_by_field = self.kept_by_field(&valid);
+
+        aggregate_weighted(&valid, |field, i| {
+            let kept = kept_by_field
+                .iter()
+                .find(|(trimmed_field, _)| *trimmed_field == field)
+                .is_none_or(|(_, kept)| kept[i]);
+
+            if kept { 1.0 } else { 0.0 }
+        })
+    }
+
+    fn contributing_providers(&self, results: &[WeatherProviderResult], _resolved: &CurrentWeatherResponse) -> Vec<String> {
+        self.trimmed_fields(results)
+            .into_iter()
+            .filter(|(_, fields)| fields.is_empty())
+            .map(|(provider, _)| provider.to_string())
+            .collect()
+    }
+
+    fn trimmed_providers(&self, results: &[WeatherProviderResult]) -> Vec<TrimmedProvider> {
+        self.trimmed_fields(results)
+            .into_iter()
+            .filter(|(_, fields)| !fields.is_empty())
+            .map(|(provider, fields)| TrimmedProvider {
+                provider: provider.to_string(),
+                fields,
+            })
+            .collect()
+    }
+}
+
+// Marks which values lie within `max_deviation` of the median. Missing values are kept,
+// they are skipped later on when the field is averaged anyway.
+// The value(s) closest to the median are always kept, otherwise two providers that disagree
+// (each as far from the median as the other) would both be trimmed and leave the field empty.
+fn within_deviation(values: impl Iterator<Item = Option<f64>>, max_deviation: f64) -> Vec<bool> {
+    let values: Vec<Option<f64>> = values.collect();
+    let present: Vec<f64> = values.iter().flatten().copied().collect();
+
+    let Some(consensus) = median(&present) else {
+        return vec![true; values.len()];
+    };
+
+    let closest = present
+        .iter()
+        .map(|v| (v - consensus).abs())
+        .fold(f64::INFINITY, f64::min);
+    let allowed_deviation = max_deviation.max(closest);
+
+    values
+        .iter()
+        .map(|v| v.is_none_or(|v| (v - consensus).abs() <= allowed_deviation))
+        .collect()
+}
@@ -1,6 +1,26 @@
+use crate::org::unibl::etf::model::responses::aggregation_metadata::TrimmedProvider;
 use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
 use crate::org::unibl::etf::services::current_weather_service::WeatherProviderResult;
 
 pub trait WeatherStrategy {
     fn resolve(&self, results: &[WeatherProviderResult]) -> Option<CurrentWeatherResponse>;
-}
\ No newline at end of file
+
+    // Providers whose data went into every field of the resolved response.
+    // Strategies that pick a single provider keep its name on the response.
+    fn contributing_providers(&self, results: &[WeatherProviderResult], resolved: &CurrentWeatherResponse) -> Vec<String> {
+        if results.iter().any(|r| r.data.is_some() && r.provider == resolved.provider) {
+            return vec![resolved.provider.clone()];
+        }
+
+        results
+            .iter()
+            .filter(|r| r.data.is_some())
+            .map(|r| r.provider.clone())
+            .collect()
+    }
+
+    // Providers that returned data but were left out of some fields of the resolved response.
+    fn trimmed_providers(&self, _results: &[WeatherProviderResult]) -> Vec<TrimmedProvider> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use crate::org::unibl::etf::model::responses::current_weather_response::CurrentWeatherResponse;
+use crate::org::unibl::etf::services::current_weather_service::WeatherProviderResult;
+use crate::org::unibl::etf::strategy::aggregation::aggregate_weighted;
+use crate::org::unibl::etf::strategy::weather_strategy::WeatherStrategy;
+
+pub struct WeightedAverageStrategy {
+    pub weights: HashMap<String, f64>,
+}
+
+impl WeatherStrategy for WeightedAverageStrategy {
+    fn resolve(&self, results: &[WeatherProviderResult]) -> Option<CurrentWeatherResponse> {
+        let (valid, weights): (Vec<&CurrentWeatherResponse>, Vec<f64>) = results
+            .iter()
+            .filter_map(|r| {
+                r.data.as_ref().map(|data| {
+                    (data, self.weights.get(&r.provider).copied().unwrap_or(1.0))
+                })
+            })
+            .unzip();
+
+        aggregate_weighted(&valid, |_, i| weights[i])
+    }
+
+    // A weight of 0.0 keeps a provider out of the response altogether.
+    fn contributing_providers(&self, results: &[WeatherProviderResult], _resolved: &CurrentWeatherResponse) -> Vec<String> {
+        results
+            .iter()
+            .filter(|r| r.data.is_some() && self.weights.get(&r.provider).copied().unwrap_or(1.0) > 0.0)
+            .map(|r| r.provider.clone())
+            .collect()
+    }
+}
@@ -1,4 +1,9 @@
 use std::net::IpAddr;
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use crate::org::unibl::etf::configuration::settings::ProviderSettings;
+use crate::org::unibl::etf::model::errors::aggregator_error::AggregatorError;
+use crate::org::unibl::etf::model::errors::external_api_adapter_error_message::AdapterServiceError;
 
 pub mod serializers;
 pub mod deserializers;
@@ -12,4 +17,41 @@ pub fn is_local_ip(ip: IpAddr) -> bool {
             ipv6.is_loopback() || ipv6.is_unique_local() || ipv6.is_unicast_link_local()
         }
     }
-}
\ No newline at end of file
+}
+
+pub async fn parse_provider_response<T: DeserializeOwned>(
+    provider: &ProviderSettings,
+    response: Response,
+) -> Result<T, AggregatorError> {
+    if response.status().is_success() {
+        let body_text = response.text()
+            .await
+            .map_err(|e| AggregatorError::ServerError(
+                Some(format!("Failed to get {} Adapter Service success response body text: {}", provider.name, e))
+            ))?;
+
+        serde_json::from_str(&body_text)
+            .map_err(|e| {
+                AggregatorError::ResponseParsingError(Some(format!(
+                    "Failed to parse {} Adapter Service success response: JSON Error: {} | Raw Body: {}",
+                    provider.name, e, body_text
+                )))
+            })
+    } else {
+        let error_body_text = response.text().await.map_err(|e| {
+            AggregatorError::ServerError(
+                Some(format!("Failed to get {} Adapter Service error body text: {}", provider.name, e))
+            )
+        })?;
+
+        let error_body: AdapterServiceError = serde_json::from_str(&error_body_text)
+            .map_err(|e| {
+                AggregatorError::ResponseParsingError(Some(format!(
+                    "Failed to parse {} Adapter Service error response: JSON Error: {} | Raw Body: {}",
+                    provider.name, e, error_body_text
+                )))
+            })?;
+
+        Err(AggregatorError::from(error_body.error.code))
+    }
+}
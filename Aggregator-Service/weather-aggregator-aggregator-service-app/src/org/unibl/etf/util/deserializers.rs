@@ -138,6 +138,9 @@ where
         crate::org::unibl::etf::model::errors::external_api_adapter_error_message::RawAdapterError::AmbiguousLocationNameError(candidates) => {
             Ok(AdapterError::AmbiguousLocationNameError(candidates))
         },
+        crate::org::unibl::etf::model::errors::external_api_adapter_error_message::RawAdapterError::RateLimitExceeded(retry_after_in_seconds) => {
+            Ok(AdapterError::ProviderRateLimitedError(retry_after_in_seconds))
+        },
         crate::org::unibl::etf::model::errors::external_api_adapter_error_message::RawAdapterError::Unknown => {
             Ok(AdapterError::ServerError)
         },
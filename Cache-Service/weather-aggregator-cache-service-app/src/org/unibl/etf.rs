@@ -8,5 +8,6 @@ pub mod util;
 pub mod handlers;
 pub mod external_dependency_systems;
 pub mod repositories;
+pub mod security;
 
 pub mod telemetry;
\ No newline at end of file
@@ -14,6 +14,16 @@ pub struct Settings {
     pub application: ApplicationSettings,
     pub redis_store: RedisStoreSettings,
     pub tracing_agent: TracingSettings,
+    pub cache: CacheSettings,
+    #[serde(default)]
+    pub admin: AdminSettings,
+}
+
+// Admin endpoints are only served to mTLS clients whose certificate carries one of these DNS names.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct AdminSettings {
+    #[serde(default)]
+    pub allowed_client_identities: Vec<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -32,6 +42,62 @@ pub struct ApplicationSettings {
     pub ca_cert_file_path: String,
 }
 
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct CacheSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub forecast_ttl_in_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub historical_ttl_in_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub air_quality_ttl_in_seconds: u64,
+    #[serde(default)]
+    pub current_weather: CurrentWeatherCacheSettings,
+}
+
+// Coordinate keys are geohash cells, location keys are country:state:name, each with its own TTL.
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct CurrentWeatherCacheSettings {
+    #[serde(default = "default_geohash_precision", deserialize_with = "deserialize_number_from_string")]
+    pub geohash_precision: usize,
+    #[serde(default = "default_current_weather_ttl_in_seconds", deserialize_with = "deserialize_number_from_string")]
+    pub coordinates_ttl_in_seconds: u64,
+    #[serde(default = "default_current_weather_ttl_in_seconds", deserialize_with = "deserialize_number_from_string")]
+    pub location_ttl_in_seconds: u64,
+    #[serde(default = "default_stale_current_weather_ttl_in_seconds", deserialize_with = "deserialize_number_from_string")]
+    pub stale_ttl_in_seconds: u64,
+    // Zero disables the lookup in the 8 cells around a missed one.
+    #[serde(default = "default_neighbour_search_radius_in_meters", deserialize_with = "deserialize_number_from_string")]
+    pub neighbour_search_radius_in_meters: f64,
+}
+
+impl Default for CurrentWeatherCacheSettings {
+    fn default() -> Self {
+        Self {
+            geohash_precision: default_geohash_precision(),
+            coordinates_ttl_in_seconds: default_current_weather_ttl_in_seconds(),
+            location_ttl_in_seconds: default_current_weather_ttl_in_seconds(),
+            stale_ttl_in_seconds: default_stale_current_weather_ttl_in_seconds(),
+            neighbour_search_radius_in_meters: default_neighbour_search_radius_in_meters(),
+        }
+    }
+}
+
+fn default_geohash_precision() -> usize {
+    5
+}
+
+fn default_current_weather_ttl_in_seconds() -> u64 {
+    1800
+}
+
+fn default_stale_current_weather_ttl_in_seconds() -> u64 {
+    86400
+}
+
+fn default_neighbour_search_radius_in_meters() -> f64 {
+    2500.0
+}
+
 #[derive(serde::Deserialize, Debug)]
 pub struct RedisStoreSettings {
     #[serde(deserialize_with = "deserialize_number_from_string")]
@@ -0,0 +1,132 @@
+use actix_web::{web, HttpResponse, Responder};
+use actix_web_validator::Query;
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
+use crate::org::unibl::etf::model::requests::evict_location_request::EvictLocationRequest;
+use crate::org::unibl::etf::model::requests::list_cache_keys_request::ListCacheKeysRequest;
+use crate::org::unibl::etf::model::requests::raw_cache_entry_request::RawCacheEntryRequest;
+use crate::org::unibl::etf::security::client_identity::AdminClient;
+use crate::org::unibl::etf::services::cache_admin_service::CacheAdminService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .service(web::resource("/keys")
+            .route(web::get().to(list_keys))
+        )
+        .service(web::resource("/current_weather/raw")
+            .route(web::get().to(get_raw_current_weather_entry))
+        )
+        .service(web::resource("/current_weather/location")
+            .route(web::delete().to(evict_location))
+        )
+        .service(web::resource("/current_weather/geohash/{geohash}")
+            .route(web::delete().to(evict_geohash))
+        )
+        .service(web::resource("/current_weather")
+            .route(web::delete().to(flush_current_weather))
+        )
+        .service(web::resource("/migrate_location_keys")
+            .route(web::post().to(migrate_location_keys))
+        );
+}
+
+fn to_generic_error(e: CacheServiceError) -> GenericServiceError {
+    tracing::error!("Cache admin operation failed with error: {:?}", e);
+    GenericServiceError {
+        error: GenericServiceErrorDetails::new_cache_error(e)
+    }
+}
+
+#[tracing::instrument(name = "List Cache Keys Admin Controller",
+    skip(cache_admin_service, redis_pool))]
+async fn list_keys(
+    admin: AdminClient,
+    cache_admin_service: web::Data<CacheAdminService>,
+    query: Query<ListCacheKeysRequest>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    let res = cache_admin_service
+        .list_keys(query.as_ref(), redis_pool.get_ref())
+        .await
+        .map_err(to_generic_error)?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
+
+#[tracing::instrument(name = "Get Raw Current Weather Cache Entry Admin Controller",
+    skip(cache_admin_service, redis_pool))]
+async fn get_raw_current_weather_entry(
+    admin: AdminClient,
+    cache_admin_service: web::Data<CacheAdminService>,
+    query: Query<RawCacheEntryRequest>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    let res = cache_admin_service
+        .get_raw_current_weather_entry(&query.key, redis_pool.get_ref())
+        .await
+        .map_err(to_generic_error)?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
+
+#[tracing::instrument(name = "Evict Location Cache Admin Controller",
+    skip(cache_admin_service, redis_pool))]
+async fn evict_location(
+    admin: AdminClient,
+    cache_admin_service: web::Data<CacheAdminService>,
+    query: Query<EvictLocationRequest>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    let res = cache_admin_service
+        .evict_location(query.as_ref(), redis_pool.get_ref())
+        .await
+        .map_err(to_generic_error)?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
+
+#[tracing::instrument(name = "Evict Geohash Cache Admin Controller",
+    skip(cache_admin_service, redis_pool))]
+async fn evict_geohash(
+    admin: AdminClient,
+    cache_admin_service: web::Data<CacheAdminService>,
+    geohash: web::Path<String>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    let res = cache_admin_service
+        .evict_geohash(&geohash, redis_pool.get_ref())
+        .await
+        .map_err(to_generic_error)?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
+
+#[tracing::instrument(name = "Flush Current Weather Cache Admin Controller",
+    skip(cache_admin_service, redis_pool))]
+async fn flush_current_weather(
+    admin: AdminClient,
+    cache_admin_service: web::Data<CacheAdminService>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    let res = cache_admin_service
+        .flush_current_weather(redis_pool.get_ref())
+        .await
+        .map_err(to_generic_error)?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
+
+#[tracing::instrument(name = "Migrate Location Cache Keys Admin Controller",
+    skip(cache_admin_service, redis_pool))]
+async fn migrate_location_keys(
+    admin: AdminClient,
+    cache_admin_service: web::Data<CacheAdminService>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    let res = cache_admin_service
+        .migrate_location_keys(redis_pool.get_ref())
+        .await
+        .map_err(to_generic_error)?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
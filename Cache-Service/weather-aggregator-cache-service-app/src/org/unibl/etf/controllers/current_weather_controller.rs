@@ -1,7 +1,9 @@
 use actix_web::{web, HttpResponse, Responder};
 use actix_web_validator::Query;
+use crate::org::unibl::etf::configuration::settings::CacheSettings;
 use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
 use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::{RetrieveCurrentWeatherCacheRequest};
+use crate::org::unibl::etf::model::requests::retrieve_nearby_current_weather_cache_request::RetrieveNearbyCurrentWeatherCacheRequest;
 use crate::org::unibl::etf::model::requests::store_current_weather_data_request::StoreCurrentWeatherDataRequest;
 use crate::org::unibl::etf::services::cache_service::{CacheService};
 
@@ -13,21 +15,26 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         .service(web::resource("/current_weather_by_location")
                      .route(web::get().to(get_current_weather_cache_data_by_location))
         )
+        .service(web::resource("/current_weather_nearby")
+            .route(web::get().to(get_nearby_current_weather_cache_data))
+        )
         .service(web::resource("/current_weather")
             .route(web::put().to(store_current_weather))
         );
 }
 
 #[tracing::instrument(name = "Get Current Weather Data Cache Controller",
-    skip(cache_service, redis_pool))]
+    skip(cache_service, redis_pool, cache_settings))]
 async fn get_current_weather_cache_data_by_coordinates(
     cache_service: web::Data<CacheService>,
     query: Query<RetrieveCurrentWeatherCacheRequest>,
     redis_pool: web::Data<deadpool_redis::Pool>,
+    cache_settings: web::Data<CacheSettings>,
 ) -> Result<impl Responder, GenericServiceError> {
     Ok(cache_service
         .get_current_weather_cache_data_by_coordinates(
             query.as_ref(),
+            &cache_settings.current_weather,
             redis_pool.get_ref(),
         )
         .await
@@ -70,16 +77,41 @@ async fn get_current_weather_cache_data_by_location(
 }
 
 
-#[tracing::instrument(name = "Store Current Weather Data Cache Controller",
+#[tracing::instrument(name = "Get Nearby Current Weather Data Cache Controller",
     skip(cache_service, redis_pool))]
+async fn get_nearby_current_weather_cache_data(
+    cache_service: web::Data<CacheService>,
+    query: Query<RetrieveNearbyCurrentWeatherCacheRequest>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    cache_service
+        .get_nearby_current_weather_cache_data(
+            query.as_ref(),
+            redis_pool.get_ref(),
+        )
+        .await
+        .map(|res| HttpResponse::Ok().json(res))
+        .map_err(|e| {
+            tracing::error!("Was not able to get nearby current weather cache data with error: {:?}", e);
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_cache_error(e)
+            }
+        })
+}
+
+
+#[tracing::instrument(name = "Store Current Weather Data Cache Controller",
+    skip(cache_service, redis_pool, cache_settings))]
 async fn store_current_weather(
     cache_service: web::Data<CacheService>,
     req: web::Json<StoreCurrentWeatherDataRequest>,
     redis_pool: web::Data<deadpool_redis::Pool>,
+    cache_settings: web::Data<CacheSettings>,
 ) -> Result<impl Responder, GenericServiceError> {
     Ok(cache_service
         .store_current_weather_result_as_cache(
             &req.into_inner(),
+            &cache_settings.current_weather,
             redis_pool.get_ref(),
         )
         .await
@@ -47,6 +47,9 @@ impl error::ResponseError for GenericServiceError {
             CacheServiceError::RequestValidationError(_) => {
                 StatusCode::BAD_REQUEST
             },
+            CacheServiceError::AdminAccessDeniedError(_) => {
+                StatusCode::FORBIDDEN
+            },
             _ => StatusCode::INTERNAL_SERVER_ERROR
         };
         status_code
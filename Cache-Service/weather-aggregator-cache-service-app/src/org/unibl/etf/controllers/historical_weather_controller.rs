@@ -0,0 +1,93 @@
+use actix_web::{web, HttpResponse, Responder};
+use actix_web_validator::Query;
+use crate::org::unibl::etf::configuration::settings::CacheSettings;
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::{RetrieveCurrentWeatherCacheRequest};
+use crate::org::unibl::etf::model::requests::date_range_request::DateRangeRequest;
+use crate::org::unibl::etf::model::requests::store_historical_weather_data_request::StoreHistoricalWeatherDataRequest;
+use crate::org::unibl::etf::services::cache_service::{CacheService};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg
+        .service(web::resource("/historical_weather_by_coordinates")
+            .route(web::get().to(get_historical_weather_cache_data_by_coordinates))
+        )
+        .service(web::resource("/historical_weather_by_location")
+            .route(web::get().to(get_historical_weather_cache_data_by_location))
+        )
+        .service(web::resource("/historical_weather")
+            .route(web::put().to(store_historical_weather))
+        );
+}
+
+#[tracing::instrument(name = "Get Historical Weather Data Cache Controller",
+    skip(cache_service, redis_pool))]
+async fn get_historical_weather_cache_data_by_coordinates(
+    cache_service: web::Data<CacheService>,
+    query: Query<RetrieveCurrentWeatherCacheRequest>,
+    date_range: Query<DateRangeRequest>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    cache_service
+        .get_historical_weather_cache_data_by_coordinates(
+            query.as_ref(),
+            date_range.as_ref(),
+            redis_pool.get_ref(),
+        )
+        .await
+        .map(|res| HttpResponse::Ok().json(res))
+        .map_err(|e| {
+            tracing::error!("Was not able to get historical weather cache data with error: {:?}", e);
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_cache_error(e)
+            }
+        })
+}
+
+#[tracing::instrument(name = "Get Historical Weather Data Cache Controller",
+    skip(cache_service, redis_pool))]
+async fn get_historical_weather_cache_data_by_location(
+    cache_service: web::Data<CacheService>,
+    query: Query<RetrieveCurrentWeatherCacheRequest>,
+    date_range: Query<DateRangeRequest>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    cache_service
+        .get_historical_weather_cache_data_by_location(
+            query.as_ref(),
+            date_range.as_ref(),
+            redis_pool.get_ref(),
+        )
+        .await
+        .map(|res| HttpResponse::Ok().json(res))
+        .map_err(|e| {
+            tracing::error!("Was not able to get historical weather cache data with error: {:?}", e);
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_cache_error(e)
+            }
+        })
+}
+
+#[tracing::instrument(name = "Store Historical Weather Data Cache Controller",
+    skip(cache_service, redis_pool, cache_settings, req))]
+async fn store_historical_weather(
+    cache_service: web::Data<CacheService>,
+    req: web::Json<StoreHistoricalWeatherDataRequest>,
+    cache_settings: web::Data<CacheSettings>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> Result<impl Responder, GenericServiceError> {
+    cache_service
+        .store_historical_weather_result_as_cache(
+            &req.into_inner(),
+            cache_settings.historical_ttl_in_seconds,
+            redis_pool.get_ref(),
+        )
+        .await
+        .map(|res| HttpResponse::Ok().json(res))
+        .map_err(|e| {
+            tracing::error!("Was not able to store historical weather cache data with error: {:?}", e);
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_cache_error(e)
+            }
+        })
+}
@@ -39,6 +39,8 @@ async fn main() -> std::io::Result<()> {
     let res = run(
         listener,
         configuration.redis_store,
+        configuration.cache,
+        configuration.admin,
         redis_pool,
         http_server_config
     )?.await;
@@ -13,6 +13,7 @@ pub enum CacheServiceError {
     StoringCacheError(Option<String>),
     OnlyPotentialMatchesFoundError(Vec<CurrentWeatherCacheResponse>),
     MultipleCachedResultsError(Vec<String>),
+    AdminAccessDeniedError(Option<String>),
 }
 
 
@@ -25,6 +26,7 @@ impl CacheServiceError {
                 Self::CacheMissError(lat.clone(), lon.clone(), location_name.clone(), country.clone()),
             Self::RequestValidationError(err) => Self::RequestValidationError(err.clone()),
             Self::OnlyPotentialMatchesFoundError(candidates) => Self::OnlyPotentialMatchesFoundError(candidates.clone()),
+            Self::AdminAccessDeniedError(_) => Self::AdminAccessDeniedError(None),
             _ => Self::ServerError(None),
         }
     }
@@ -49,6 +51,7 @@ impl CacheServiceError {
             Self::StoringCacheError(_) => "STORING_CACHE_ERROR",
             Self::OnlyPotentialMatchesFoundError(_) => "ONLY_POTENTIAL_MATCHES_FOUND_ERROR",
             Self::MultipleCachedResultsError(_) => "MULTIPLE_CACHE_RESULTS_ERROR",
+            Self::AdminAccessDeniedError(_) => "ADMIN_ACCESS_DENIED_ERROR",
         }
     }
 
@@ -74,6 +77,7 @@ impl CacheServiceError {
                 format!("Only potential matched found for requested location.")
             }
             CacheServiceError::RedisError(_, msg) => msg.clone().unwrap_or(String::default()),
+            CacheServiceError::AdminAccessDeniedError(msg) => msg.clone().unwrap_or(String::default()),
             _ => { String::default() }
         }
     }
@@ -89,6 +93,7 @@ impl CacheServiceError {
             CacheServiceError::StoringCacheError(_) => 1007,
             CacheServiceError::OnlyPotentialMatchesFoundError(_) => 1008,
             CacheServiceError::MultipleCachedResultsError(_) => 1009,
+            CacheServiceError::AdminAccessDeniedError(_) => 1010,
         }
     }
 }
@@ -1,2 +1,10 @@
 pub mod retrieve_current_weather_cache_request;
 pub mod store_current_weather_data_request;
+pub mod store_forecast_data_request;
+pub mod date_range_request;
+pub mod store_historical_weather_data_request;
+pub mod store_air_quality_data_request;
+pub mod retrieve_nearby_current_weather_cache_request;
+pub mod list_cache_keys_request;
+pub mod raw_cache_entry_request;
+pub mod evict_location_request;
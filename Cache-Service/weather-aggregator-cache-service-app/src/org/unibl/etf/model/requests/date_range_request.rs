@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Deserialize)]
+pub struct DateRangeRequestRaw {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Deserialize, Serialize, Validate, Debug, Clone)]
+#[serde(try_from = "DateRangeRequestRaw")]
+pub struct DateRangeRequest {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl TryFrom<DateRangeRequestRaw> for DateRangeRequest {
+    type Error = String;
+
+    fn try_from(raw: DateRangeRequestRaw) -> Result<Self, Self::Error> {
+        if raw.start_date > raw.end_date {
+            return Err(String::from("Start date must not be after end date"));
+        }
+
+        Ok(DateRangeRequest {
+            start_date: raw.start_date,
+            end_date: raw.end_date,
+        })
+    }
+}
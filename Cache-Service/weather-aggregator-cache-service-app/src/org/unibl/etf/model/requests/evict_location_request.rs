@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct EvictLocationRequest {
+    #[validate(length(min = 1, message = "Location name must not be empty"))]
+    pub location_name: String,
+    pub country: Option<String>,
+    pub state: Option<String>,
+}
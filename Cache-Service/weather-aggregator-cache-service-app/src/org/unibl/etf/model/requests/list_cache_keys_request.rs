@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct ListCacheKeysRequest {
+    #[serde(default = "default_pattern")]
+    pub pattern: String,
+
+    #[validate(range(min = 1, max = 1000, message = "Limit must be between 1 and 1000"))]
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_pattern() -> String {
+    "weather:current:*".to_string()
+}
+
+fn default_limit() -> usize {
+    100
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct RawCacheEntryRequest {
+    #[validate(length(min = 1, message = "Key must not be empty"))]
+    pub key: String,
+}
@@ -17,6 +17,8 @@ pub struct RetrieveCurrentWeatherCacheRequest {
     pub country: Option<String>,
     pub state: Option<String>,
 
+    // Falls back to the long-lived stale copy when the regular entry has expired.
+    pub allow_stale: bool,
 }
 
 
@@ -28,6 +30,8 @@ pub struct RetrieveCurrentWeatherCacheRequestRaw {
     pub lon: Option<f64>,
     pub country: Option<String>,
     pub state: Option<String>,
+    #[serde(default)]
+    pub allow_stale: bool,
 }
 
 impl TryFrom<RetrieveCurrentWeatherCacheRequestRaw> for RetrieveCurrentWeatherCacheRequest {
@@ -62,7 +66,8 @@ impl TryFrom<RetrieveCurrentWeatherCacheRequestRaw> for RetrieveCurrentWeatherCa
                 lon: raw.lon,
                 location_name: raw.location_name,
                 country: raw.country,
-                state: raw.state
+                state: raw.state,
+                allow_stale: raw.allow_stale,
             });
         } else if !lat_validated {
             return Err(format!("Invalid latitude: {}", raw.lat.ok_or("")?));
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Debug)]
+pub struct RetrieveNearbyCurrentWeatherCacheRequest {
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90"))]
+    pub lat: f64,
+
+    #[validate(range(min = -180.0, max = 180.0, message = "Longitude must be between -180 and 180"))]
+    pub lon: f64,
+
+    #[validate(range(min = 0.0, max = 100.0, message = "Radius must be between 0 and 100 km"))]
+    pub radius_km: f64,
+
+    #[validate(range(min = 1, max = 50, message = "Limit must be between 1 and 50"))]
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_limit() -> usize {
+    5
+}
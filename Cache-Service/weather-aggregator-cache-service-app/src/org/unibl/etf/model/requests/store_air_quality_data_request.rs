@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use validator::Validate;
+use crate::org::unibl::etf::model::responses::air_quality_cache_response::AirQualityCacheResponse;
+
+#[derive(Deserialize, Clone, Debug, Validate)]
+pub struct StoreAirQualityDataRequest {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    pub air_quality_data: AirQualityCacheResponse,
+    pub location_names: Vec<String>,
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use validator::Validate;
+use crate::org::unibl::etf::model::responses::forecast_cache_response::ForecastCacheResponse;
+
+#[derive(Deserialize, Clone, Debug, Validate)]
+pub struct StoreForecastDataRequest {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    pub forecast_data: ForecastCacheResponse,
+    pub location_names: Vec<String>,
+}
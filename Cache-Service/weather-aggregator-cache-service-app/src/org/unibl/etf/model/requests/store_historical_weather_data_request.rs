@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+use crate::org::unibl::etf::model::requests::date_range_request::DateRangeRequest;
+use crate::org::unibl::etf::model::responses::current_weather_cache_response::CurrentWeatherCacheResponse;
+
+#[derive(Deserialize, Clone, Debug, Validate)]
+pub struct StoreHistoricalWeatherDataRequest {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    pub date_range: DateRangeRequest,
+    pub observations: Vec<CurrentWeatherCacheResponse>,
+    pub location_names: Vec<String>,
+}
@@ -1,4 +1,9 @@
 pub mod health_check_response;
 pub mod current_weather_cache_response;
 pub mod multiple_cached_locations_response;
-
+pub mod forecast_cache_response;
+pub mod air_quality_cache_response;
+pub mod nearby_current_weather_cache_response;
+pub mod cache_keys_response;
+pub mod eviction_response;
+pub mod key_migration_response;
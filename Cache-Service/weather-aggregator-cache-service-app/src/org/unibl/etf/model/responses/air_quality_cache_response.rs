@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::util::deserializers::{
+    deserialize_i64_or_empty_string_as_none,
+    deserialize_u16_or_empty_string_as_none,
+    deserialize_f64_or_empty_string_as_none,
+};
+
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use crate::org::unibl::etf::model::responses::current_weather_cache_response::Location;
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct AirQualityCacheResponse {
+    pub provider: String,
+    pub location: Location,
+    pub pollutants: Pollutants,
+    #[serde(deserialize_with = "deserialize_u16_or_empty_string_as_none")]
+    pub aqi: Option<u16>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub aqi_category: Option<String>,
+    #[serde(serialize_with = "serialize_empty_i64")]
+    #[serde(deserialize_with = "deserialize_i64_or_empty_string_as_none")]
+    pub observed_at_timestamp: Option<i64>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct Pollutants {
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    pub pm2_5: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    pub pm10: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    pub o3: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    pub no2: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    pub so2: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    pub co: Option<f64>,
+}
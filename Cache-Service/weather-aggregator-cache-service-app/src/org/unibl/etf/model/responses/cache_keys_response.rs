@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CacheKeysResponse {
+    pub keys: Vec<CacheKeyEntry>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CacheKeyEntry {
+    pub key: String,
+    pub ttl_in_seconds: Option<i64>,
+}
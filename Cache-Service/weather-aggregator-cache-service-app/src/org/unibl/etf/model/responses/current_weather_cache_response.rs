@@ -23,6 +23,24 @@ pub struct CurrentWeatherCacheResponse {
     #[serde(serialize_with = "serialize_empty_i64")]
     #[serde(deserialize_with = "deserialize_i64_or_empty_string_as_none")]
     pub observed_at_timestamp: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_at_timestamp: Option<i64>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_stale: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stale_age_in_seconds: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_remaining_in_seconds: Option<i64>,
+}
+
+impl CurrentWeatherCacheResponse {
+    pub fn into_stale(mut self, now_timestamp: i64) -> Self {
+        self.is_stale = true;
+        // The stale copy's own expiry says nothing about when fresh data is due.
+        self.ttl_remaining_in_seconds = None;
+        self.stale_age_in_seconds = self.cached_at_timestamp.map(|cached_at| (now_timestamp - cached_at).max(0));
+        self
+    }
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
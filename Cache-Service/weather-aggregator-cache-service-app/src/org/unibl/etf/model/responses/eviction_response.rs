@@ -0,0 +1,6 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct EvictionResponse {
+    pub evicted_keys: u64,
+}
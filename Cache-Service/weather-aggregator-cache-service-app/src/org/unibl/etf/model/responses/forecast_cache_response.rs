@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::util::deserializers::{
+    deserialize_u8_or_empty_string_as_none,
+    deserialize_f64_or_empty_string_as_none,
+};
+
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_u8;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use crate::org::unibl::etf::model::responses::current_weather_cache_response::{Location, Weather, Wind};
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct ForecastCacheResponse {
+    pub provider: String,
+    pub location: Location,
+    pub hourly: Vec<HourlyForecast>,
+    pub daily: Vec<DailyForecast>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct HourlyForecast {
+    pub timestamp: i64,
+    pub weather: Weather,
+    pub wind: Wind,
+    #[serde(deserialize_with = "deserialize_u8_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct DailyForecast {
+    pub date: String,
+
+    pub temp_min_metric: f64,
+
+    pub temp_min_imperial: f64,
+
+    pub temp_max_metric: f64,
+
+    pub temp_max_imperial: f64,
+
+    #[serde(deserialize_with = "deserialize_u8_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub humidity: Option<u8>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_metric: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_f64_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_imperial: Option<f64>,
+
+    #[serde(deserialize_with = "deserialize_u8_or_empty_string_as_none")]
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub condition: Option<String>,
+}
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct KeyMigrationResponse {
+    pub migrated_keys: u64,
+    pub indexed_keys: u64,
+}
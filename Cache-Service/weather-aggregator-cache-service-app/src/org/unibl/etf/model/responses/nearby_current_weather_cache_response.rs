@@ -0,0 +1,13 @@
+use serde::Serialize;
+use crate::org::unibl::etf::model::responses::current_weather_cache_response::CurrentWeatherCacheResponse;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NearbyCurrentWeatherCacheResponse {
+    pub observations: Vec<NearbyCurrentWeatherObservation>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NearbyCurrentWeatherObservation {
+    pub distance_km: f64,
+    pub data: CurrentWeatherCacheResponse,
+}
@@ -1 +1,5 @@
-pub mod current_weather_cache_repository;
\ No newline at end of file
+pub mod current_weather_cache_repository;
+pub mod forecast_cache_repository;
+pub mod historical_weather_cache_repository;
+pub mod air_quality_cache_repository;
+pub mod cache_admin_repository;
@@ -0,0 +1,174 @@
+use deadpool_redis::redis::{AsyncCommands, RedisResult};
+
+use geohash::{encode, Coord};
+use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
+use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::RetrieveCurrentWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::store_air_quality_data_request::StoreAirQualityDataRequest;
+use crate::org::unibl::etf::util::location_normalization::{location_key, location_key_pattern};
+
+#[derive(Debug)]
+pub struct AirQualityRepository {}
+
+impl AirQualityRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tracing::instrument(name = "Get Air Quality Cached Data by Coordinates Repository", skip(redis_pool))]
+    pub async fn retrieve_air_quality_cache_result_by_coordinates(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Option<String>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let (lat, lon) = match (req.lat, req.lon) {
+            (Some(lat), Some(lon)) => (lat, lon),
+            _ => return Err(CacheServiceError::ServerError(Some("Latitude and longitude not provided".to_string()))),
+        };
+        let hash = encode(Coord { x: lon, y: lat }, 5)
+            .map_err(|e| CacheServiceError::ServerError(Some(e.to_string())))?;
+        let cache_key = format!("weather:air_quality:{}", hash);
+        let result: Option<String> = conn.get(&cache_key)
+            .await
+            .map_err(|e| {
+                CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(name = "Get Air Quality Cached Data by Location Repository", skip(redis_pool))]
+    pub async fn retrieve_air_quality_cache_result_by_location(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Option<String>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let location_name = req.location_name.clone().unwrap_or_default();
+
+        if let (Some(country), Some(state)) = (&req.country, &req.state) {
+            let cache_key = format!("weather:air_quality:{}", location_key(country, state, &location_name));
+            let result: Option<String> = conn.get(&cache_key)
+                .await
+                .map_err(|e| {
+                    CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+                })?;
+
+            return Ok(result);
+        }
+
+        let pattern = format!(
+            "weather:air_quality:{}",
+            location_key_pattern(req.country.as_deref(), req.state.as_deref(), &location_name),
+        );
+
+        let mut keys: Vec<String> = Vec::new();
+        {
+            let mut iter = conn.scan_match::<_, String>(pattern).await.map_err(|e| {
+                CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })?;
+            while let Some(key) = iter.next_item().await {
+                keys.push(key);
+            }
+        }
+
+        // Air quality is only served from cache when the location is unambiguous, otherwise
+        // the aggregator resolves the candidates through the providers.
+        if keys.len() != 1 {
+            return Ok(None);
+        }
+
+        let result: Option<String> = conn.get(&keys[0])
+            .await
+            .map_err(|e| {
+                CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(name = "Store Air Quality Cache Data Repository", skip(redis_pool, data))]
+    pub async fn store_air_quality_result_as_cache(
+        &self,
+        data: &StoreAirQualityDataRequest,
+        ttl_in_seconds: u64,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<bool, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let hash = encode(Coord { x: data.lon, y: data.lat }, 5)
+            .map_err(|e| CacheServiceError::StoringCacheError(Some(e.to_string())))?;
+        let cache_key = format!("weather:air_quality:{}", hash);
+        let json = serde_json::to_string(&data.air_quality_data)
+            .map_err(|e| CacheServiceError::StoringCacheError(Some(e.to_string())))?;
+        let result: RedisResult<()> = conn.set_ex(cache_key, json.clone(), ttl_in_seconds).await;
+
+        match result {
+            Ok(_) => {
+                tracing::info!("Successfully stored air quality data by coordinates to redis store.");
+            },
+            Err(e) => {
+                tracing::error!("Failed to store air quality data to redis store.");
+                return Err(CacheServiceError::StoringCacheError(Some(e.to_string())));
+            }
+        };
+
+        let country = match &data.air_quality_data.location.country {
+            Some(country) => country.clone(),
+            None => return Ok(true),
+        };
+
+        for location_name in &data.location_names {
+            let cache_key = format!(
+                "weather:air_quality:{}",
+                location_key(
+                    &country,
+                    &data.air_quality_data.location.state_region_province_or_entity.clone().unwrap_or("NoState".to_string()),
+                    location_name,
+                ),
+            );
+            let result: RedisResult<()> = conn.set_ex(cache_key, json.clone(), ttl_in_seconds).await;
+            match result {
+                Ok(_) => {
+                    tracing::info!(
+                        "Successfully stored air quality data by location {} and country {} to redis store.",
+                        location_name,
+                        country
+                    );
+                },
+                Err(e) => {
+                    tracing::error!("Failed to store air quality data to redis store.");
+                    return Err(CacheServiceError::StoringCacheError(Some(e.to_string())));
+                }
+            };
+        }
+
+        Ok(true)
+    }
+}
+
+impl Default for AirQualityRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,243 @@
+use deadpool_redis::redis::AsyncCommands;
+use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
+
+const DELETE_BATCH_SIZE: usize = 500;
+
+fn redis_error(e: deadpool_redis::redis::RedisError) -> CacheServiceError {
+    CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+}
+
+async fn scan_keys(
+    conn: &mut deadpool_redis::Connection,
+    pattern: &str,
+    limit: Option<usize>,
+) -> Result<Vec<String>, CacheServiceError> {
+    let mut iter = conn.scan_match::<_, String>(pattern).await.map_err(redis_error)?;
+
+    let mut keys = Vec::new();
+    while let Some(key) = iter.next_item().await {
+        keys.push(key);
+        if limit.is_some_and(|limit| keys.len() >= limit) {
+            break;
+        }
+    }
+
+    Ok(keys)
+}
+
+#[derive(Debug)]
+pub struct MigratedKey {
+    pub key: String,
+    pub ttl_in_seconds: Option<i64>,
+    pub renamed: bool,
+}
+
+#[derive(Debug)]
+pub struct CacheAdminRepository {}
+
+impl CacheAdminRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tracing::instrument(name = "List Cache Keys Repository", skip(redis_pool))]
+    pub async fn list_keys_with_ttl(
+        &self,
+        pattern: &str,
+        limit: usize,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<(String, Option<i64>)>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let keys = scan_keys(&mut conn, pattern, Some(limit)).await?;
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut pipe = deadpool_redis::redis::pipe();
+        for key in &keys {
+            pipe.ttl(key);
+        }
+        let ttls: Vec<i64> = pipe.query_async(&mut conn).await.map_err(redis_error)?;
+
+        Ok(keys
+            .into_iter()
+            .zip(ttls)
+            .map(|(key, ttl)| (key, (ttl >= 0).then_some(ttl)))
+            .collect())
+    }
+
+    #[tracing::instrument(name = "Get Raw Cache Entry Repository", skip(redis_pool))]
+    pub async fn get_raw_entry(
+        &self,
+        key: &str,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Option<String>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        conn.get(key).await.map_err(redis_error)
+    }
+
+    #[tracing::instrument(name = "Delete Cache Keys Repository", skip(redis_pool))]
+    pub async fn delete_keys(
+        &self,
+        keys: &[String],
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<u64, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let mut deleted = 0;
+        for batch in keys.chunks(DELETE_BATCH_SIZE) {
+            let count: u64 = conn.del(batch).await.map_err(redis_error)?;
+            deleted += count;
+        }
+
+        Ok(deleted)
+    }
+
+    #[tracing::instrument(name = "Find Cache Keys By Pattern Repository", skip(redis_pool))]
+    pub async fn find_keys_matching(
+        &self,
+        pattern: &str,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<String>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        scan_keys(&mut conn, pattern, None).await
+    }
+
+    #[tracing::instrument(name = "Delete Cache Keys By Pattern Repository", skip(redis_pool))]
+    pub async fn delete_keys_matching(
+        &self,
+        pattern: &str,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<u64, CacheServiceError> {
+        let keys = self.find_keys_matching(pattern, redis_pool).await?;
+
+        self.delete_keys(&keys, redis_pool).await
+    }
+
+    // Where the target key already exists it holds a newer write, so the old key is dropped instead.
+    #[tracing::instrument(name = "Rename Cache Keys Repository", skip(rename, redis_pool))]
+    pub async fn rename_keys_matching<F>(
+        &self,
+        pattern: &str,
+        rename: F,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<MigratedKey>, CacheServiceError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let keys = scan_keys(&mut conn, pattern, None).await?;
+
+        let mut migrated_keys = Vec::new();
+        for key in keys {
+            let Some(target) = rename(&key) else {
+                continue;
+            };
+
+            let renamed = target != key;
+            if renamed {
+                let moved: bool = conn.rename_nx(&key, &target).await.map_err(redis_error)?;
+                if !moved {
+                    let _: u64 = conn.del(&key).await.map_err(redis_error)?;
+                }
+            }
+
+            let ttl: i64 = conn.ttl(&target).await.map_err(redis_error)?;
+            // -2 means the entry expired while migrating.
+            if ttl == -2 {
+                continue;
+            }
+            migrated_keys.push(MigratedKey {
+                key: target,
+                ttl_in_seconds: (ttl >= 0).then_some(ttl),
+                renamed,
+            });
+        }
+
+        Ok(migrated_keys)
+    }
+
+    #[tracing::instrument(name = "Add Location Index Members Repository", skip(members, redis_pool))]
+    pub async fn add_location_index_members(
+        &self,
+        index_key: &str,
+        members: &[(String, i64)],
+        ttl_in_seconds: i64,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<(), CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let mut pipe = deadpool_redis::redis::pipe();
+        pipe.atomic();
+        for (member, expires_at) in members {
+            pipe.zadd(index_key, member, *expires_at).ignore();
+        }
+        pipe.expire(index_key, ttl_in_seconds).ignore();
+
+        pipe.query_async(&mut conn).await.map_err(redis_error)
+    }
+
+    #[tracing::instrument(name = "Remove Geo Index Members Repository", skip(redis_pool))]
+    pub async fn remove_geo_index_members(
+        &self,
+        geo_index_key: &str,
+        members: &[String],
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<u64, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        conn.zrem(geo_index_key, members).await.map_err(redis_error)
+    }
+}
+
+impl Default for CacheAdminRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
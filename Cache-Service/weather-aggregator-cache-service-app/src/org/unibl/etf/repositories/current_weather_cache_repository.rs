@@ -1,13 +1,92 @@
 
 use deadpool_redis::redis::{AsyncCommands, RedisResult};
 
-use geohash::{encode, Coord};
+use chrono::Utc;
+use geohash::{encode, neighbors, Coord};
+use crate::org::unibl::etf::configuration::settings::CurrentWeatherCacheSettings;
 use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
 use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::RetrieveCurrentWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::retrieve_nearby_current_weather_cache_request::RetrieveNearbyCurrentWeatherCacheRequest;
 use crate::org::unibl::etf::model::requests::store_current_weather_data_request::StoreCurrentWeatherDataRequest;
+use crate::org::unibl::etf::util::location_normalization::{location_key, normalize_country, normalize_location_component};
 
 
 
+// Stale copies outlive the regular entries so a reading can still be served when every provider is down.
+// The prefix must not start with weather:current, otherwise location scans would pick stale copies up.
+pub(crate) const CURRENT_WEATHER_KEY_PREFIX: &str = "weather:current";
+pub(crate) const STALE_CURRENT_WEATHER_KEY_PREFIX: &str = "weather:stale_current";
+// Members are geohash cells pointing at weather:current:<cell>, positioned at the last stored coordinates.
+pub(crate) const CURRENT_WEATHER_GEO_INDEX_KEY: &str = "weather:current_geo";
+// Sorted set per location name holding the location keys stored under it, scored by their expiry timestamp.
+pub(crate) const LOCATION_INDEX_KEY_SUFFIX: &str = "_location_index";
+
+fn key_prefix(stale: bool) -> &'static str {
+    if stale { STALE_CURRENT_WEATHER_KEY_PREFIX } else { CURRENT_WEATHER_KEY_PREFIX }
+}
+
+pub(crate) fn location_index_key(prefix: &str, location_name: &str) -> String {
+    format!("{}{}:{}", prefix, LOCATION_INDEX_KEY_SUFFIX, normalize_location_component(location_name))
+}
+
+// Location keys look like <prefix>:<country>:<state>:<name>.
+fn matches_location(cache_key: &str, prefix: &str, country: Option<&str>, state: Option<&str>) -> bool {
+    let Some(rest) = cache_key.strip_prefix(prefix).and_then(|rest| rest.strip_prefix(':')) else {
+        return false;
+    };
+    let mut parts = rest.splitn(3, ':');
+    let (Some(key_country), Some(key_state)) = (parts.next(), parts.next()) else {
+        return false;
+    };
+
+    country.is_none_or(|country| normalize_country(country) == key_country)
+        && state.is_none_or(|state| normalize_location_component(state) == key_state)
+}
+
+#[derive(Debug)]
+pub struct CachedEntry {
+    pub value: String,
+    // None when the key has no expiry set.
+    pub ttl_in_seconds: Option<i64>,
+}
+
+async fn get_many_with_ttl(
+    conn: &mut deadpool_redis::Connection,
+    cache_keys: &[String],
+) -> Result<Vec<Option<CachedEntry>>, CacheServiceError> {
+    let mut pipe = deadpool_redis::redis::pipe();
+    for cache_key in cache_keys {
+        pipe.get(cache_key).ttl(cache_key);
+    }
+
+    let results: Vec<(Option<String>, i64)> = pipe
+        .query_async(conn)
+        .await
+        .map_err(|e| {
+            CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+        })?;
+
+    Ok(results
+        .into_iter()
+        .map(|(value, ttl)| value.map(|value| CachedEntry {
+            value,
+            ttl_in_seconds: (ttl >= 0).then_some(ttl),
+        }))
+        .collect())
+}
+
+async fn get_with_ttl(
+    conn: &mut deadpool_redis::Connection,
+    cache_key: &str,
+) -> Result<Option<CachedEntry>, CacheServiceError> {
+    Ok(get_many_with_ttl(conn, &[cache_key.to_string()]).await?.pop().flatten())
+}
+
+fn geohash_of(lat: f64, lon: f64, precision: usize) -> Result<String, CacheServiceError> {
+    encode(Coord { x: lon, y: lat }, precision)
+        .map_err(|e| CacheServiceError::ServerError(Some(e.to_string())))
+}
+
 #[derive(Debug)]
 pub struct CurrentWeatherRepository {}
 
@@ -23,8 +102,10 @@ impl CurrentWeatherRepository {
     pub async fn retrieve_current_weather_cache_result_by_coordinates(
         &self,
         req: &RetrieveCurrentWeatherCacheRequest,
+        stale: bool,
+        geohash_precision: usize,
         redis_pool: &deadpool_redis::Pool,
-    ) -> Result<Option<String>, CacheServiceError> {
+    ) -> Result<Option<CachedEntry>, CacheServiceError> {
         let mut conn = match redis_pool.get().await {
             Ok(c) => c,
             Err(e) => {
@@ -36,25 +117,110 @@ impl CurrentWeatherRepository {
         if !req.lat.is_some() || !req.lon.is_some() {
             return Err(CacheServiceError::ServerError(Some("Latitude and longitude not provided".to_string())));
         }
-        let coord = Coord { x: req.lon.unwrap(), y: req.lat.unwrap() };
-        let hash = encode(coord, 5).expect("Invalid coordinates");
-        let cache_key = format!("weather:current:{}", hash);
-        let result: Option<String> = conn.get(&cache_key)
+        let hash = geohash_of(req.lat.unwrap(), req.lon.unwrap(), geohash_precision)?;
+        let cache_key = format!("{}:{}", key_prefix(stale), hash);
+
+        get_with_ttl(&mut conn, &cache_key).await
+    }
+
+    #[tracing::instrument(name = "Get Neighbouring Current Weather Cached Data Repository", skip(redis_pool))]
+    pub async fn retrieve_current_weather_cache_results_around_coordinates(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        stale: bool,
+        geohash_precision: usize,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<CachedEntry>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let (Some(lat), Some(lon)) = (req.lat, req.lon) else {
+            return Err(CacheServiceError::ServerError(Some("Latitude and longitude not provided".to_string())));
+        };
+        let hash = geohash_of(lat, lon, geohash_precision)?;
+        let neighbours = neighbors(&hash)
+            .map_err(|e| CacheServiceError::ServerError(Some(e.to_string())))?;
+
+        let cache_keys: Vec<String> = [
+            neighbours.n, neighbours.ne, neighbours.e, neighbours.se,
+            neighbours.s, neighbours.sw, neighbours.w, neighbours.nw,
+        ]
+            .iter()
+            .map(|cell| format!("{}:{}", key_prefix(stale), cell))
+            .collect();
+
+        Ok(get_many_with_ttl(&mut conn, &cache_keys).await?.into_iter().flatten().collect())
+    }
+
+    #[tracing::instrument(name = "Get Nearby Current Weather Cached Data Repository", skip(redis_pool))]
+    pub async fn retrieve_nearby_current_weather_cache_results(
+        &self,
+        req: &RetrieveNearbyCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<(f64, CachedEntry)>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let members: Vec<(String, f64)> = deadpool_redis::redis::cmd("GEOSEARCH")
+            .arg(CURRENT_WEATHER_GEO_INDEX_KEY)
+            .arg("FROMLONLAT").arg(req.lon).arg(req.lat)
+            .arg("BYRADIUS").arg(req.radius_km).arg("km")
+            .arg("ASC")
+            .arg("COUNT").arg(req.limit)
+            .arg("WITHDIST")
+            .query_async(&mut conn)
             .await
             .map_err(|e| {
                 CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
-            },
-            )?;
+            })?;
+
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        Ok(result)
+        let cache_keys: Vec<String> = members
+            .iter()
+            .map(|(cell, _)| format!("{}:{}", CURRENT_WEATHER_KEY_PREFIX, cell))
+            .collect();
+        let entries = get_many_with_ttl(&mut conn, &cache_keys).await?;
+
+        let mut nearby = Vec::new();
+        let mut expired_cells = Vec::new();
+        for ((cell, distance_km), entry) in members.into_iter().zip(entries) {
+            match entry {
+                Some(entry) => nearby.push((distance_km, entry)),
+                None => expired_cells.push(cell),
+            }
+        }
+
+        // The GEO set has no per member expiry, so cells whose entry expired are dropped as they are found.
+        if !expired_cells.is_empty() {
+            let result: RedisResult<()> = conn.zrem(CURRENT_WEATHER_GEO_INDEX_KEY, &expired_cells).await;
+            if let Err(e) = result {
+                tracing::warn!("Failed to remove expired cells from the current weather geo index: {}", e);
+            }
+        }
+
+        Ok(nearby)
     }
 
     #[tracing::instrument(name = "Get Current Weather Cached Data Repository", skip(redis_pool))]
     pub async fn retrieve_current_weather_cache_result_by_location(
         &self,
         req: &RetrieveCurrentWeatherCacheRequest,
+        stale: bool,
         redis_pool: &deadpool_redis::Pool,
-    ) -> Result<Option<String>, CacheServiceError> {
+    ) -> Result<Option<CachedEntry>, CacheServiceError> {
         let mut conn = match redis_pool.get().await {
             Ok(c) => c,
             Err(e) => {
@@ -64,54 +230,53 @@ impl CurrentWeatherRepository {
         };
 
         if req.country.is_some() && req.state.is_some() {
-            let cache_key = format!("weather:current:{}:{}:{}", req.country.clone().unwrap(),
-                                    req.state.clone().unwrap(),
-                                    req.location_name.clone().unwrap());
-            let result: Option<String> = conn.get(&cache_key)
-                .await
-                .map_err(|e| {
-                    CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
-                },
-                )?;
+            let cache_key = format!("{}:{}", key_prefix(stale), location_key(
+                &req.country.clone().unwrap(),
+                &req.state.clone().unwrap(),
+                &req.location_name.clone().unwrap(),
+            ));
 
-            return Ok(result);
+            return get_with_ttl(&mut conn, &cache_key).await;
         }
         else {
-            let mut cached_data: Vec<String> = Vec::new();
-
-            let pattern =  format!(
-            "weather:current:{}:{}:{}",
-            req.country.clone().unwrap_or("*".to_string()),
-            req.state.clone().unwrap_or("*".to_string()),
-            req.location_name.clone().unwrap(),
-            );
-            let mut iter = conn.scan_match::<_, String>(pattern).await.map_err(|e| {
+            let prefix = key_prefix(stale);
+            let index_key = location_index_key(prefix, &req.location_name.clone().unwrap());
+            let now = Utc::now().timestamp();
+
+            let indexed_keys: Vec<String> = conn.zrangebyscore(&index_key, now, "+inf").await.map_err(|e| {
                 CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
             })?;
-
-            let mut keys = Vec::new();
-            while let Some(key) = iter.next_item().await {
-                println!("key: {:?}", key);
-                keys.push(key);
-            }
+            let keys: Vec<String> = indexed_keys
+                .into_iter()
+                .filter(|key| matches_location(key, prefix, req.country.as_deref(), req.state.as_deref()))
+                .collect();
 
             if keys.is_empty() {
                 return Err(CacheServiceError::CacheMissError(None, None, req.country.clone(), req.state.clone()));
             }
 
-            let mut conn = match redis_pool.get().await {
-                Ok(c) => c,
-                Err(e) => {
-                    let error_message = format!("Failed to get connection from pool: {}", e);
-                    return Err(CacheServiceError::ServerError(Some(error_message)));
-                }
-            };
-
             let values: Vec<Option<String>> = conn.mget(&keys).await
                 .map_err(|e| {
                     CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
                 })?;
-            cached_data = values.into_iter().flatten().collect();
+
+            // Entries evicted before their indexed expiry leave members behind, drop them as they are found.
+            let evicted_keys: Vec<&String> = keys
+                .iter()
+                .zip(&values)
+                .filter_map(|(key, value)| value.is_none().then_some(key))
+                .collect();
+            if !evicted_keys.is_empty() {
+                let result: RedisResult<()> = conn.zrem(&index_key, &evicted_keys).await;
+                if let Err(e) = result {
+                    tracing::warn!("Failed to remove evicted keys from the location index {}: {}", index_key, e);
+                }
+            }
+
+            let cached_data: Vec<String> = values.into_iter().flatten().collect();
+            if cached_data.is_empty() {
+                return Err(CacheServiceError::CacheMissError(None, None, req.country.clone(), req.state.clone()));
+            }
             return Err(CacheServiceError::MultipleCachedResultsError(cached_data));
         }
 
@@ -121,6 +286,7 @@ impl CurrentWeatherRepository {
     pub async fn store_current_weather_result_as_cache(
         &self,
         data: &StoreCurrentWeatherDataRequest,
+        settings: &CurrentWeatherCacheSettings,
         redis_pool: &deadpool_redis::Pool,
     )-> Result<bool, CacheServiceError> {
         let mut conn = match redis_pool.get().await {
@@ -131,38 +297,32 @@ impl CurrentWeatherRepository {
             }
         };
 
-        let coord = Coord { x: data.lon, y: data.lat };
-        let hash = encode(coord, 5).expect("Invalid coordinates"); //kao env
-        let cache_key = format!("weather:current:{}", hash);
-        let json = serde_json::to_string(&data.current_weather_data).unwrap();
-        let result: RedisResult<()> = conn.set_ex(cache_key, json.clone(), 1800).await;
+        let hash = geohash_of(data.lat, data.lon, settings.geohash_precision)?;
 
-        match result {
-            Ok(_) => {
-                tracing::info!("Successfully stored current weather data by coordinates to redis store.");
-            },
-            Err(e) => {
-                tracing::error!("Failed to store current weather data to redis store.");
-                return Err(CacheServiceError::StoringCacheError(Some(e.to_string())));
-            }
-        };
+        let mut current_weather_data = data.current_weather_data.clone();
+        current_weather_data.cached_at_timestamp = Some(Utc::now().timestamp());
+        let json = serde_json::to_string(&current_weather_data).unwrap();
 
-        for location_name in &data.location_names {
-            let cache_key = format!(
-                "weather:current:{}:{}:{}",
-                data.current_weather_data.location.country.clone().unwrap(),
-                data.current_weather_data.location.state_region_province_or_entity.clone().unwrap_or("NoState".to_string()),
-                location_name.clone(),
+        let result: RedisResult<()> = deadpool_redis::redis::cmd("GEOADD")
+            .arg(CURRENT_WEATHER_GEO_INDEX_KEY)
+            .arg(data.lon).arg(data.lat).arg(&hash)
+            .query_async(&mut conn)
+            .await;
+        if let Err(e) = result {
+            tracing::error!("Failed to index current weather data in the geo index.");
+            return Err(CacheServiceError::StoringCacheError(Some(e.to_string())));
+        }
+
+        for (prefix, coordinates_ttl, location_ttl) in [
+            (CURRENT_WEATHER_KEY_PREFIX, settings.coordinates_ttl_in_seconds, settings.location_ttl_in_seconds),
+            (STALE_CURRENT_WEATHER_KEY_PREFIX, settings.stale_ttl_in_seconds, settings.stale_ttl_in_seconds),
+        ] {
+            let cache_key = format!("{}:{}", prefix, hash);
+            let result: RedisResult<()> = conn.set_ex(cache_key, json.clone(), coordinates_ttl).await;
 
-            );
-            let result: RedisResult<()> = conn.set_ex(cache_key, json.clone(), 1800).await;
             match result {
                 Ok(_) => {
-                    tracing::info!(
-                        "Successfully stored current weather data by location {} and country {} to redis store.",
-                        location_name.clone(),
-                        data.current_weather_data.location.country.clone().unwrap()
-                    );
+                    tracing::info!("Successfully stored current weather data by coordinates to redis store under {}.", prefix);
                 },
                 Err(e) => {
                     tracing::error!("Failed to store current weather data to redis store.");
@@ -170,6 +330,44 @@ impl CurrentWeatherRepository {
                 }
             };
 
+            for location_name in &data.location_names {
+                let cache_key = format!(
+                    "{}:{}",
+                    prefix,
+                    location_key(
+                        &data.current_weather_data.location.country.clone().unwrap(),
+                        &data.current_weather_data.location.state_region_province_or_entity.clone().unwrap_or("NoState".to_string()),
+                        location_name,
+                    ),
+                );
+                let index_key = location_index_key(prefix, location_name);
+                let now = Utc::now().timestamp();
+                let location_ttl_in_seconds = location_ttl as i64;
+
+                // The entry and its index member are written together so lookups never see one without the other.
+                let result: RedisResult<()> = deadpool_redis::redis::pipe()
+                    .atomic()
+                    .set_ex(&cache_key, json.clone(), location_ttl).ignore()
+                    .zadd(&index_key, &cache_key, now + location_ttl_in_seconds).ignore()
+                    .zrembyscore(&index_key, "-inf", now).ignore()
+                    .expire(&index_key, location_ttl_in_seconds).ignore()
+                    .query_async(&mut conn)
+                    .await;
+                match result {
+                    Ok(_) => {
+                        tracing::info!(
+                            "Successfully stored current weather data by location {} and country {} to redis store.",
+                            location_name.clone(),
+                            data.current_weather_data.location.country.clone().unwrap()
+                        );
+                    },
+                    Err(e) => {
+                        tracing::error!("Failed to store current weather data to redis store.");
+                        return Err(CacheServiceError::StoringCacheError(Some(e.to_string())));
+                    }
+                };
+
+            }
         }
 
         Ok(true)
@@ -0,0 +1,172 @@
+use deadpool_redis::redis::{AsyncCommands, RedisResult};
+
+use geohash::{encode, Coord};
+use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
+use crate::org::unibl::etf::model::requests::date_range_request::DateRangeRequest;
+use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::RetrieveCurrentWeatherCacheRequest;
+use crate::org::unibl::etf::model::requests::store_historical_weather_data_request::StoreHistoricalWeatherDataRequest;
+use crate::org::unibl::etf::util::location_normalization::{location_key, location_key_pattern};
+
+#[derive(Debug)]
+pub struct HistoricalWeatherRepository {}
+
+impl HistoricalWeatherRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tracing::instrument(name = "Get Historical Weather Cached Data by Coordinates Repository", skip(redis_pool))]
+    pub async fn retrieve_historical_weather_cache_result_by_coordinates(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        date_range: &DateRangeRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Option<String>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let (lat, lon) = match (req.lat, req.lon) {
+            (Some(lat), Some(lon)) => (lat, lon),
+            _ => return Err(CacheServiceError::ServerError(Some("Latitude and longitude not provided".to_string()))),
+        };
+        let hash = encode(Coord { x: lon, y: lat }, 5)
+            .map_err(|e| CacheServiceError::ServerError(Some(e.to_string())))?;
+        let cache_key = format!("weather:historical:{}:{}", hash, date_range_suffix(date_range));
+        let result: Option<String> = conn.get(&cache_key)
+            .await
+            .map_err(|e| {
+                CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(name = "Get Historical Weather Cached Data by Location Repository", skip(redis_pool))]
+    pub async fn retrieve_historical_weather_cache_result_by_location(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        date_range: &DateRangeRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Option<String>, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let location_name = req.location_name.clone().unwrap_or_default();
+        let suffix = date_range_suffix(date_range);
+
+        if let (Some(country), Some(state)) = (&req.country, &req.state) {
+            let cache_key = format!("weather:historical:{}:{}", location_key(country, state, &location_name), suffix);
+            let result: Option<String> = conn.get(&cache_key)
+                .await
+                .map_err(|e| {
+                    CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+                })?;
+
+            return Ok(result);
+        }
+
+        let pattern = format!(
+            "weather:historical:{}:{}",
+            location_key_pattern(req.country.as_deref(), req.state.as_deref(), &location_name),
+            suffix,
+        );
+
+        let mut keys: Vec<String> = Vec::new();
+        {
+            let mut iter = conn.scan_match::<_, String>(pattern).await.map_err(|e| {
+                CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })?;
+            while let Some(key) = iter.next_item().await {
+                keys.push(key);
+            }
+        }
+
+        // Only an unambiguous location is served from cache.
+        if keys.len() != 1 {
+            return Ok(None);
+        }
+
+        let result: Option<String> = conn.get(&keys[0])
+            .await
+            .map_err(|e| {
+                CacheServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })?;
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(name = "Store Historical Weather Cache Data Repository", skip(redis_pool, data))]
+    pub async fn store_historical_weather_result_as_cache(
+        &self,
+        data: &StoreHistoricalWeatherDataRequest,
+        ttl_in_seconds: u64,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<bool, CacheServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(CacheServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let suffix = date_range_suffix(&data.date_range);
+        let hash = encode(Coord { x: data.lon, y: data.lat }, 5)
+            .map_err(|e| CacheServiceError::StoringCacheError(Some(e.to_string())))?;
+        let json = serde_json::to_string(&data.observations)
+            .map_err(|e| CacheServiceError::StoringCacheError(Some(e.to_string())))?;
+
+        let mut cache_keys = vec![format!("weather:historical:{}:{}", hash, suffix)];
+
+        let location = data.observations.first().map(|o| &o.location);
+        if let Some(country) = location.and_then(|l| l.country.as_ref()) {
+            let state = location
+                .and_then(|l| l.state_region_province_or_entity.clone())
+                .unwrap_or("NoState".to_string());
+            for location_name in &data.location_names {
+                cache_keys.push(format!("weather:historical:{}:{}", location_key(country, &state, location_name), suffix));
+            }
+        }
+
+        for cache_key in cache_keys {
+            // Past observations never change, a TTL of 0 keeps them until evicted.
+            let result: RedisResult<()> = if ttl_in_seconds == 0 {
+                conn.set(&cache_key, json.clone()).await
+            } else {
+                conn.set_ex(&cache_key, json.clone(), ttl_in_seconds).await
+            };
+
+            match result {
+                Ok(_) => {
+                    tracing::info!("Successfully stored historical weather data under key {} to redis store.", cache_key);
+                },
+                Err(e) => {
+                    tracing::error!("Failed to store historical weather data to redis store.");
+                    return Err(CacheServiceError::StoringCacheError(Some(e.to_string())));
+                }
+            };
+        }
+
+        Ok(true)
+    }
+}
+
+impl Default for HistoricalWeatherRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn date_range_suffix(date_range: &DateRangeRequest) -> String {
+    format!("{}:{}", date_range.start_date.format("%Y-%m-%d"), date_range.end_date.format("%Y-%m-%d"))
+}
@@ -0,0 +1,69 @@
+use std::any::Any;
+use std::future::{ready, Ready};
+use actix_tls::accept::rustls_0_23::TlsStream;
+use actix_web::dev::{Extensions, Payload};
+use actix_web::rt::net::TcpStream;
+use actix_web::{web, FromRequest, HttpRequest};
+use rustls::pki_types::{CertificateDer, ServerName};
+use webpki::EndEntityCert;
+use crate::org::unibl::etf::configuration::settings::AdminSettings;
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
+
+#[derive(Debug, Clone)]
+pub struct ClientCertificate(pub CertificateDer<'static>);
+
+// Registered through HttpServer::on_connect, the certificate is already verified against the CA by rustls.
+pub fn capture_client_certificate(connection: &dyn Any, data: &mut Extensions) {
+    if let Some(tls_stream) = connection.downcast_ref::<TlsStream<TcpStream>>() {
+        let (_, session) = tls_stream.get_ref();
+        if let Some(certificate) = session.peer_certificates().and_then(|certificates| certificates.first()) {
+            data.insert(ClientCertificate(certificate.clone().into_owned()));
+        }
+    }
+}
+
+// Extracting it rejects the request unless the peer certificate matches an allowed admin identity.
+#[derive(Debug)]
+pub struct AdminClient {
+    pub identity: String,
+}
+
+impl FromRequest for AdminClient {
+    type Error = GenericServiceError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(authorize_admin_client(req))
+    }
+}
+
+fn authorize_admin_client(req: &HttpRequest) -> Result<AdminClient, GenericServiceError> {
+    let denied = |message: &str| {
+        tracing::warn!("Admin access denied: {}", message);
+        GenericServiceError {
+            error: GenericServiceErrorDetails::new_cache_error(
+                CacheServiceError::AdminAccessDeniedError(Some(message.to_string()))
+            )
+        }
+    };
+
+    let certificate = req
+        .conn_data::<ClientCertificate>()
+        .ok_or_else(|| denied("Admin endpoints require an mTLS client certificate."))?;
+    let admin_settings = req
+        .app_data::<web::Data<AdminSettings>>()
+        .ok_or_else(|| denied("Admin access is not configured."))?;
+    let end_entity = EndEntityCert::try_from(&certificate.0)
+        .map_err(|_| denied("Client certificate could not be parsed."))?;
+
+    admin_settings
+        .allowed_client_identities
+        .iter()
+        .find(|identity| {
+            ServerName::try_from(identity.as_str())
+                .is_ok_and(|name| end_entity.verify_is_valid_for_subject_name(&name).is_ok())
+        })
+        .map(|identity| AdminClient { identity: identity.clone() })
+        .ok_or_else(|| denied("Client certificate is not allowed to use admin endpoints."))
+}
@@ -1 +1,2 @@
-pub mod cache_service;
\ No newline at end of file
+pub mod cache_service;
+pub mod cache_admin_service;
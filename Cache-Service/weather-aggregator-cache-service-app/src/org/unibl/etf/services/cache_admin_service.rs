@@ -0,0 +1,268 @@
+use crate::org::unibl::etf::model::errors::cache_service_error::CacheServiceError;
+use crate::org::unibl::etf::model::requests::evict_location_request::EvictLocationRequest;
+use crate::org::unibl::etf::model::requests::list_cache_keys_request::ListCacheKeysRequest;
+use crate::org::unibl::etf::model::responses::cache_keys_response::{CacheKeyEntry, CacheKeysResponse};
+use crate::org::unibl::etf::model::responses::current_weather_cache_response::CurrentWeatherCacheResponse;
+use std::collections::HashMap;
+use chrono::Utc;
+use crate::org::unibl::etf::model::responses::eviction_response::EvictionResponse;
+use crate::org::unibl::etf::model::responses::key_migration_response::KeyMigrationResponse;
+use crate::org::unibl::etf::repositories::cache_admin_repository::CacheAdminRepository;
+use crate::org::unibl::etf::repositories::current_weather_cache_repository::{
+    location_index_key, CURRENT_WEATHER_GEO_INDEX_KEY, CURRENT_WEATHER_KEY_PREFIX, LOCATION_INDEX_KEY_SUFFIX,
+    STALE_CURRENT_WEATHER_KEY_PREFIX,
+};
+use crate::org::unibl::etf::util::location_normalization::{location_key, normalize_country, normalize_location_component};
+
+// Admins may only look at keys this service owns.
+const CACHE_KEY_NAMESPACE: &str = "weather:";
+
+// Location keyed families and how many segments follow <country>:<state>:<name> in their keys.
+const LOCATION_KEYED_FAMILIES: [(&str, usize); 5] = [
+    (CURRENT_WEATHER_KEY_PREFIX, 0),
+    (STALE_CURRENT_WEATHER_KEY_PREFIX, 0),
+    ("weather:forecast", 0),
+    ("weather:air_quality", 0),
+    ("weather:historical", 2),
+];
+
+// User supplied names must not widen a SCAN pattern.
+fn escape_glob(value: &str) -> String {
+    value
+        .chars()
+        .flat_map(|c| match c {
+            '*' | '?' | '[' | ']' | '\\' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
+// Keys written before normalization are rebuilt from their segments. Geohash keyed entries have too few segments and are left alone.
+fn normalized_location_cache_key(key: &str, prefix: &str, suffix_segments: usize) -> Option<String> {
+    let rest = key.strip_prefix(prefix)?.strip_prefix(':')?;
+    let mut segments: Vec<&str> = rest.split(':').collect();
+    if segments.len() < 3 + suffix_segments {
+        return None;
+    }
+
+    let suffix = segments.split_off(segments.len() - suffix_segments);
+    let location_name = segments[2..].join(":");
+    let mut normalized_key = format!("{}:{}", prefix, location_key(segments[0], segments[1], &location_name));
+    for segment in suffix {
+        normalized_key.push(':');
+        normalized_key.push_str(segment);
+    }
+
+    Some(normalized_key)
+}
+
+#[derive(Debug)]
+pub struct CacheAdminService {
+    cache_admin_repository: CacheAdminRepository,
+}
+
+impl CacheAdminService {
+    fn new() -> Self {
+        Self {
+            cache_admin_repository: CacheAdminRepository::default(),
+        }
+    }
+
+    #[tracing::instrument(name = "List Cache Keys Service", skip(redis_pool))]
+    pub async fn list_keys(
+        &self,
+        req: &ListCacheKeysRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<CacheKeysResponse, CacheServiceError> {
+        if !req.pattern.starts_with(CACHE_KEY_NAMESPACE) {
+            return Err(CacheServiceError::RequestValidationError(
+                Some(format!("Pattern must start with {}", CACHE_KEY_NAMESPACE))
+            ));
+        }
+
+        let keys = self.cache_admin_repository
+            .list_keys_with_ttl(&req.pattern, req.limit, redis_pool)
+            .await?
+            .into_iter()
+            .map(|(key, ttl_in_seconds)| CacheKeyEntry { key, ttl_in_seconds })
+            .collect();
+
+        Ok(CacheKeysResponse { keys })
+    }
+
+    #[tracing::instrument(name = "Get Raw Current Weather Cache Entry Service", skip(redis_pool))]
+    pub async fn get_raw_current_weather_entry(
+        &self,
+        key: &str,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<CurrentWeatherCacheResponse, CacheServiceError> {
+        let is_current_weather_key = [CURRENT_WEATHER_KEY_PREFIX, STALE_CURRENT_WEATHER_KEY_PREFIX]
+            .iter()
+            .any(|prefix| key.starts_with(&format!("{}:", prefix)));
+        if !is_current_weather_key {
+            return Err(CacheServiceError::RequestValidationError(
+                Some(format!("{} is not a current weather cache key", key))
+            ));
+        }
+
+        let raw = self.cache_admin_repository
+            .get_raw_entry(key, redis_pool)
+            .await?
+            .ok_or(CacheServiceError::CacheMissError(None, None, None, None))?;
+
+        serde_json::from_str(&raw)
+            .map_err(|e| CacheServiceError::ResponseParsingError(Some(e.to_string())))
+    }
+
+    #[tracing::instrument(name = "Evict Location Cache Service", skip(redis_pool))]
+    pub async fn evict_location(
+        &self,
+        req: &EvictLocationRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<EvictionResponse, CacheServiceError> {
+        let mut evicted_keys = 0;
+
+        // Stale copies go too, otherwise the bad reading would come back as a fallback.
+        for prefix in [CURRENT_WEATHER_KEY_PREFIX, STALE_CURRENT_WEATHER_KEY_PREFIX] {
+            let pattern = format!(
+                "{}:{}:{}:{}",
+                prefix,
+                req.country.as_deref().map(|country| escape_glob(&normalize_country(country))).unwrap_or("*".to_string()),
+                req.state.as_deref().map(|state| escape_glob(&normalize_location_component(state))).unwrap_or("*".to_string()),
+                escape_glob(&normalize_location_component(&req.location_name)),
+            );
+            evicted_keys += self.cache_admin_repository
+                .delete_keys_matching(&pattern, redis_pool)
+                .await?;
+        }
+
+        tracing::info!("Evicted {} current weather cache keys for location {}.", evicted_keys, req.location_name);
+        Ok(EvictionResponse { evicted_keys })
+    }
+
+    #[tracing::instrument(name = "Evict Geohash Cache Service", skip(redis_pool))]
+    pub async fn evict_geohash(
+        &self,
+        geohash: &str,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<EvictionResponse, CacheServiceError> {
+        if geohash::decode(geohash).is_err() {
+            return Err(CacheServiceError::RequestValidationError(
+                Some(format!("{} is not a valid geohash", geohash))
+            ));
+        }
+
+        let keys = vec![
+            format!("{}:{}", CURRENT_WEATHER_KEY_PREFIX, geohash),
+            format!("{}:{}", STALE_CURRENT_WEATHER_KEY_PREFIX, geohash),
+        ];
+        let evicted_keys = self.cache_admin_repository
+            .delete_keys(&keys, redis_pool)
+            .await?;
+        self.cache_admin_repository
+            .remove_geo_index_members(CURRENT_WEATHER_GEO_INDEX_KEY, &[geohash.to_string()], redis_pool)
+            .await?;
+
+        tracing::info!("Evicted {} current weather cache keys for geohash {}.", evicted_keys, geohash);
+        Ok(EvictionResponse { evicted_keys })
+    }
+
+    #[tracing::instrument(name = "Flush Current Weather Cache Service", skip(redis_pool))]
+    pub async fn flush_current_weather(
+        &self,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<EvictionResponse, CacheServiceError> {
+        let mut evicted_keys = 0;
+
+        for prefix in [CURRENT_WEATHER_KEY_PREFIX, STALE_CURRENT_WEATHER_KEY_PREFIX] {
+            evicted_keys += self.cache_admin_repository
+                .delete_keys_matching(&format!("{}:*", prefix), redis_pool)
+                .await?;
+            evicted_keys += self.cache_admin_repository
+                .delete_keys_matching(&format!("{}{}:*", prefix, LOCATION_INDEX_KEY_SUFFIX), redis_pool)
+                .await?;
+        }
+        evicted_keys += self.cache_admin_repository
+            .delete_keys(&[CURRENT_WEATHER_GEO_INDEX_KEY.to_string()], redis_pool)
+            .await?;
+
+        tracing::warn!("Flushed {} current weather cache keys.", evicted_keys);
+        Ok(EvictionResponse { evicted_keys })
+    }
+
+    // Renames keys written before location normalization and rebuilds the current weather location index.
+    #[tracing::instrument(name = "Migrate Location Cache Keys Service", skip(redis_pool))]
+    pub async fn migrate_location_keys(
+        &self,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<KeyMigrationResponse, CacheServiceError> {
+        let mut migrated_keys = 0;
+        let mut indexed_keys = 0;
+
+        for (prefix, suffix_segments) in LOCATION_KEYED_FAMILIES {
+            let pattern = format!("{}{}", prefix, ":*".repeat(3 + suffix_segments));
+            let keys = self.cache_admin_repository
+                .rename_keys_matching(
+                    &pattern,
+                    |key| normalized_location_cache_key(key, prefix, suffix_segments),
+                    redis_pool,
+                )
+                .await?;
+            migrated_keys += keys.iter().filter(|key| key.renamed).count() as u64;
+
+            if prefix != CURRENT_WEATHER_KEY_PREFIX && prefix != STALE_CURRENT_WEATHER_KEY_PREFIX {
+                continue;
+            }
+
+            let now = Utc::now().timestamp();
+            let mut index_members: HashMap<String, Vec<(String, i64)>> = HashMap::new();
+            for key in keys {
+                let Some(ttl_in_seconds) = key.ttl_in_seconds else {
+                    continue;
+                };
+                let location_name = key.key
+                    .strip_prefix(&format!("{}:", prefix))
+                    .and_then(|rest| rest.splitn(3, ':').nth(2));
+                let Some(location_name) = location_name else {
+                    continue;
+                };
+                index_members
+                    .entry(location_index_key(prefix, location_name))
+                    .or_default()
+                    .push((key.key.clone(), now + ttl_in_seconds));
+            }
+
+            for (index_key, members) in &index_members {
+                let ttl_in_seconds = members.iter().map(|(_, expires_at)| expires_at - now).max().unwrap_or_default();
+                self.cache_admin_repository
+                    .add_location_index_members(index_key, members, ttl_in_seconds, redis_pool)
+                    .await?;
+                indexed_keys += members.len() as u64;
+            }
+
+            // Indexes under names that were not normalized are superseded by the ones rebuilt above.
+            let index_prefix = format!("{}{}:", prefix, LOCATION_INDEX_KEY_SUFFIX);
+            let outdated_index_keys: Vec<String> = self.cache_admin_repository
+                .find_keys_matching(&format!("{}*", index_prefix), redis_pool)
+                .await?
+                .into_iter()
+                .filter(|index_key| {
+                    let location_name = &index_key[index_prefix.len()..];
+                    normalize_location_component(location_name) != location_name
+                })
+                .collect();
+            self.cache_admin_repository
+                .delete_keys(&outdated_index_keys, redis_pool)
+                .await?;
+        }
+
+        tracing::info!("Migrated {} location cache keys and indexed {} current weather keys.", migrated_keys, indexed_keys);
+        Ok(KeyMigrationResponse { migrated_keys, indexed_keys })
+    }
+}
+
+impl Default for CacheAdminService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,19 +1,39 @@
+use chrono::Utc;
+use crate::org::unibl::etf::configuration::settings::CurrentWeatherCacheSettings;
 
 use crate::org::unibl::etf::model::errors::cache_service_error::{CacheServiceError};
 use crate::org::unibl::etf::model::requests::retrieve_current_weather_cache_request::RetrieveCurrentWeatherCacheRequest;
 use crate::org::unibl::etf::model::requests::store_current_weather_data_request::StoreCurrentWeatherDataRequest;
 use crate::org::unibl::etf::model::responses::current_weather_cache_response::{CurrentWeatherCacheResponse};
+use crate::org::unibl::etf::model::requests::store_forecast_data_request::StoreForecastDataRequest;
+use crate::org::unibl::etf::model::requests::date_range_request::DateRangeRequest;
+use crate::org::unibl::etf::model::requests::store_historical_weather_data_request::StoreHistoricalWeatherDataRequest;
+use crate::org::unibl::etf::model::responses::forecast_cache_response::ForecastCacheResponse;
 use crate::org::unibl::etf::repositories::current_weather_cache_repository::CurrentWeatherRepository;
+use crate::org::unibl::etf::repositories::forecast_cache_repository::ForecastRepository;
+use crate::org::unibl::etf::repositories::historical_weather_cache_repository::HistoricalWeatherRepository;
+use crate::org::unibl::etf::model::requests::store_air_quality_data_request::StoreAirQualityDataRequest;
+use crate::org::unibl::etf::model::responses::air_quality_cache_response::AirQualityCacheResponse;
+use crate::org::unibl::etf::repositories::air_quality_cache_repository::AirQualityRepository;
+use crate::org::unibl::etf::util::geo::haversine_distance_in_meters;
+use crate::org::unibl::etf::model::requests::retrieve_nearby_current_weather_cache_request::RetrieveNearbyCurrentWeatherCacheRequest;
+use crate::org::unibl::etf::model::responses::nearby_current_weather_cache_response::{NearbyCurrentWeatherCacheResponse, NearbyCurrentWeatherObservation};
 
 #[derive(Debug)]
 pub struct CacheService {
-    current_weather_repository: CurrentWeatherRepository
+    current_weather_repository: CurrentWeatherRepository,
+    forecast_repository: ForecastRepository,
+    historical_weather_repository: HistoricalWeatherRepository,
+    air_quality_repository: AirQualityRepository,
 }
 
 impl CacheService {
     fn new() -> Self {
         Self {
-            current_weather_repository: CurrentWeatherRepository::default()
+            current_weather_repository: CurrentWeatherRepository::default(),
+            forecast_repository: ForecastRepository::default(),
+            historical_weather_repository: HistoricalWeatherRepository::default(),
+            air_quality_repository: AirQualityRepository::default(),
         }
     }
 
@@ -21,11 +41,30 @@ impl CacheService {
     pub async fn get_current_weather_cache_data_by_coordinates(
         &self,
         req: &RetrieveCurrentWeatherCacheRequest,
+        settings: &CurrentWeatherCacheSettings,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<CurrentWeatherCacheResponse, CacheServiceError> {
+        match self.find_current_weather_cache_data_by_coordinates(req, false, settings, redis_pool).await {
+            Err(CacheServiceError::CacheMissError(..)) if req.allow_stale => {
+                tracing::info!("Current weather cache entry expired, falling back to the stale copy.");
+                self.find_current_weather_cache_data_by_coordinates(req, true, settings, redis_pool).await
+            },
+            result => result,
+        }
+    }
+
+    async fn find_current_weather_cache_data_by_coordinates(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        stale: bool,
+        settings: &CurrentWeatherCacheSettings,
         redis_pool: &deadpool_redis::Pool,
     ) -> Result<CurrentWeatherCacheResponse, CacheServiceError> {
         let cached_data = match self.current_weather_repository
             .retrieve_current_weather_cache_result_by_coordinates(
                 req,
+                stale,
+                settings.geohash_precision,
                 redis_pool
             )
             .await {
@@ -40,6 +79,9 @@ impl CacheService {
         };
 
         let cached_data = match cached_data {
+            None if settings.neighbour_search_radius_in_meters > 0.0 => {
+                return self.find_nearest_current_weather_cache_data(req, stale, settings, redis_pool).await;
+            }
             None => {
                 return Err(CacheServiceError::CacheMissError(req.lat.clone(), req.lon.clone(), None, None));
             }
@@ -48,10 +90,14 @@ impl CacheService {
             }
         };
 
-        let result: Result<CurrentWeatherCacheResponse, _> = serde_json::from_str(&cached_data);
+        let result: Result<CurrentWeatherCacheResponse, _> = serde_json::from_str(&cached_data.value);
 
         match result {
-            Ok(data) => {
+            Ok(data) if stale => {
+                Ok(data.into_stale(Utc::now().timestamp()))
+            }
+            Ok(mut data) => {
+                data.ttl_remaining_in_seconds = cached_data.ttl_in_seconds;
                 Ok(data)
             }
             Err(e) => {
@@ -60,15 +106,111 @@ impl CacheService {
         }
     }
 
+    // Points close to a cell boundary miss their own cell while a neighbouring one holds a reading a few hundred meters away.
+    async fn find_nearest_current_weather_cache_data(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        stale: bool,
+        settings: &CurrentWeatherCacheSettings,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<CurrentWeatherCacheResponse, CacheServiceError> {
+        let cache_miss = CacheServiceError::CacheMissError(req.lat, req.lon, None, None);
+        let (Some(lat), Some(lon)) = (req.lat, req.lon) else {
+            return Err(cache_miss);
+        };
+
+        let neighbours = self.current_weather_repository
+            .retrieve_current_weather_cache_results_around_coordinates(
+                req,
+                stale,
+                settings.geohash_precision,
+                redis_pool
+            )
+            .await?;
+
+        let nearest = neighbours
+            .into_iter()
+            .filter_map(|cached_data| {
+                let mut data: CurrentWeatherCacheResponse = serde_json::from_str(&cached_data.value)
+                    .map_err(|e| tracing::info!("Error while parsing one of results. Item: {}, Error: {}", cached_data.value, e))
+                    .ok()?;
+                let distance = haversine_distance_in_meters(lat, lon, data.location.lat?, data.location.lon?);
+                data.ttl_remaining_in_seconds = cached_data.ttl_in_seconds;
+                Some((distance, data))
+            })
+            .filter(|(distance, _)| *distance <= settings.neighbour_search_radius_in_meters)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        match nearest {
+            Some((distance, data)) => {
+                tracing::info!("Serving current weather cache data from a neighbouring cell {:.0} m away.", distance);
+                Ok(if stale { data.into_stale(Utc::now().timestamp()) } else { data })
+            },
+            None => Err(cache_miss),
+        }
+    }
+
+    #[tracing::instrument(name = "Get Nearby Current Weather Cached Data Service", skip(redis_pool))]
+    pub async fn get_nearby_current_weather_cache_data(
+        &self,
+        req: &RetrieveNearbyCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<NearbyCurrentWeatherCacheResponse, CacheServiceError> {
+        let nearby = self.current_weather_repository
+            .retrieve_nearby_current_weather_cache_results(req, redis_pool)
+            .await
+            .inspect_err(|e| {
+                tracing::info!("Was not able to get nearby current weather cache data with error: {}", e.get_message());
+            })?;
+
+        let observations = nearby
+            .into_iter()
+            .filter_map(|(distance_km, cached_data)| {
+                match serde_json::from_str::<CurrentWeatherCacheResponse>(&cached_data.value) {
+                    Ok(mut data) => {
+                        data.ttl_remaining_in_seconds = cached_data.ttl_in_seconds;
+                        Some(NearbyCurrentWeatherObservation { distance_km, data })
+                    },
+                    Err(e) => {
+                        tracing::info!("Error while parsing one of results. Item: {}, Error: {}", cached_data.value, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        Ok(NearbyCurrentWeatherCacheResponse { observations })
+    }
+
     #[tracing::instrument(name = "Get Current Weather Cached Data Service", skip(redis_pool))]
     pub async fn get_current_weather_cache_data_by_location(
         &self,
         req: &RetrieveCurrentWeatherCacheRequest,
         redis_pool: &deadpool_redis::Pool,
     ) -> Result<CurrentWeatherCacheResponse, CacheServiceError> {
+        match self.find_current_weather_cache_data_by_location(req, false, redis_pool).await {
+            Err(CacheServiceError::CacheMissError(..)) if req.allow_stale => {
+                tracing::info!("Current weather cache entry expired, falling back to the stale copy.");
+                self.find_current_weather_cache_data_by_location(req, true, redis_pool).await
+            },
+            result => result,
+        }
+    }
+
+    async fn find_current_weather_cache_data_by_location(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        stale: bool,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<CurrentWeatherCacheResponse, CacheServiceError> {
+        let mark_stale = |data: CurrentWeatherCacheResponse| {
+            if stale { data.into_stale(Utc::now().timestamp()) } else { data }
+        };
+
         match self.current_weather_repository
             .retrieve_current_weather_cache_result_by_location(
                 req,
+                stale,
                 redis_pool
             )
             .await {
@@ -76,23 +218,24 @@ impl CacheService {
                 tracing::info!("Successfully retrieved current weather cache data {:?}", cached_data);
                 let cached_data = match cached_data {
                     None => {
-                        return Err(CacheServiceError::ResponseParsingError(Some("Error while parsing repository response".to_string())));
+                        return Err(CacheServiceError::CacheMissError(None, None, req.country.clone(), req.state.clone()));
                     }
                     Some(data) => {
                         data
                     }
                 };
 
-                let result: Result<CurrentWeatherCacheResponse, _> = serde_json::from_str(&cached_data);
+                let result: Result<CurrentWeatherCacheResponse, _> = serde_json::from_str(&cached_data.value);
                 match result {
-                    Ok(data) => {
-                        return Ok(data);
+                    Ok(mut data) => {
+                        data.ttl_remaining_in_seconds = cached_data.ttl_in_seconds;
+                        Ok(mark_stale(data))
                     }
                     Err(e) => {
-                        tracing::info!("Error while parsing one of results. Item: {}, Error: {}", cached_data, e.to_string());
-                        return Err(CacheServiceError::ResponseParsingError(Some("Failed to parse response".to_string())));
+                        tracing::info!("Error while parsing one of results. Item: {}, Error: {}", cached_data.value, e.to_string());
+                        Err(CacheServiceError::ResponseParsingError(Some("Failed to parse response".to_string())))
                     }
-                };
+                }
             },
             Err(e) => {
                 match e {
@@ -103,22 +246,22 @@ impl CacheService {
                             let result: Result<CurrentWeatherCacheResponse, _> = serde_json::from_str(&item);
                             match result {
                                 Ok(data) => {
-                                    candidates.push(data);
+                                    candidates.push(mark_stale(data));
                                 }
                                 Err(e) => {
                                     tracing::info!("Error while parsing one of results. Item: {}, Error: {}", item, e.to_string())
                                 }
                             }
                         }
-                        return Err(CacheServiceError::OnlyPotentialMatchesFoundError(candidates));
+                        Err(CacheServiceError::OnlyPotentialMatchesFoundError(candidates))
                     },
                     _ => {
                         tracing::info!("Was not able to get current weather cache data with error: {}", e.get_message());
-                        return Err(e);
+                        Err(e)
                     }
                 }
             }
-        };
+        }
     }
 
     #[tracing::instrument(name = "Store Current Weather Data Cache Service",
@@ -126,11 +269,201 @@ impl CacheService {
     pub async fn store_current_weather_result_as_cache (
         &self,
         req: &StoreCurrentWeatherDataRequest,
+        settings: &CurrentWeatherCacheSettings,
         redis_pool: &deadpool_redis::Pool,
     ) -> Result<bool, CacheServiceError> {
         self.current_weather_repository
             .store_current_weather_result_as_cache(
                 req,
+                settings,
+                redis_pool
+            )
+            .await
+    }
+
+    #[tracing::instrument(name = "Get Forecast Cached Data Service", skip(redis_pool))]
+    pub async fn get_forecast_cache_data_by_coordinates(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<ForecastCacheResponse, CacheServiceError> {
+        let cached_data = self.forecast_repository
+            .retrieve_forecast_cache_result_by_coordinates(req, redis_pool)
+            .await
+            .inspect_err(|e| {
+                tracing::info!("Was not able to get forecast cache data with error: {}", e.get_message());
+            })?;
+
+        self.parse_forecast_cache_data(cached_data, req)
+    }
+
+    #[tracing::instrument(name = "Get Forecast Cached Data Service", skip(redis_pool))]
+    pub async fn get_forecast_cache_data_by_location(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<ForecastCacheResponse, CacheServiceError> {
+        let cached_data = self.forecast_repository
+            .retrieve_forecast_cache_result_by_location(req, redis_pool)
+            .await
+            .inspect_err(|e| {
+                tracing::info!("Was not able to get forecast cache data with error: {}", e.get_message());
+            })?;
+
+        self.parse_forecast_cache_data(cached_data, req)
+    }
+
+    fn parse_forecast_cache_data(
+        &self,
+        cached_data: Option<String>,
+        req: &RetrieveCurrentWeatherCacheRequest,
+    ) -> Result<ForecastCacheResponse, CacheServiceError> {
+        let cached_data = cached_data.ok_or(CacheServiceError::CacheMissError(
+            req.lat, req.lon, req.country.clone(), req.state.clone()
+        ))?;
+
+        serde_json::from_str(&cached_data)
+            .map_err(|e| CacheServiceError::ResponseParsingError(Some(e.to_string())))
+    }
+
+    #[tracing::instrument(name = "Store Forecast Data Cache Service",
+        skip(redis_pool, req))]
+    pub async fn store_forecast_result_as_cache(
+        &self,
+        req: &StoreForecastDataRequest,
+        ttl_in_seconds: u64,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<bool, CacheServiceError> {
+        self.forecast_repository
+            .store_forecast_result_as_cache(
+                req,
+                ttl_in_seconds,
+                redis_pool
+            )
+            .await
+    }
+
+    #[tracing::instrument(name = "Get Air Quality Cached Data Service", skip(redis_pool))]
+    pub async fn get_air_quality_cache_data_by_coordinates(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<AirQualityCacheResponse, CacheServiceError> {
+        let cached_data = self.air_quality_repository
+            .retrieve_air_quality_cache_result_by_coordinates(req, redis_pool)
+            .await
+            .inspect_err(|e| {
+                tracing::info!("Was not able to get air quality cache data with error: {}", e.get_message());
+            })?;
+
+        self.parse_air_quality_cache_data(cached_data, req)
+    }
+
+    #[tracing::instrument(name = "Get Air Quality Cached Data Service", skip(redis_pool))]
+    pub async fn get_air_quality_cache_data_by_location(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<AirQualityCacheResponse, CacheServiceError> {
+        let cached_data = self.air_quality_repository
+            .retrieve_air_quality_cache_result_by_location(req, redis_pool)
+            .await
+            .inspect_err(|e| {
+                tracing::info!("Was not able to get air quality cache data with error: {}", e.get_message());
+            })?;
+
+        self.parse_air_quality_cache_data(cached_data, req)
+    }
+
+    fn parse_air_quality_cache_data(
+        &self,
+        cached_data: Option<String>,
+        req: &RetrieveCurrentWeatherCacheRequest,
+    ) -> Result<AirQualityCacheResponse, CacheServiceError> {
+        let cached_data = cached_data.ok_or(CacheServiceError::CacheMissError(
+            req.lat, req.lon, req.country.clone(), req.state.clone()
+        ))?;
+
+        serde_json::from_str(&cached_data)
+            .map_err(|e| CacheServiceError::ResponseParsingError(Some(e.to_string())))
+    }
+
+    #[tracing::instrument(name = "Store Air Quality Data Cache Service",
+        skip(redis_pool, req))]
+    pub async fn store_air_quality_result_as_cache(
+        &self,
+        req: &StoreAirQualityDataRequest,
+        ttl_in_seconds: u64,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<bool, CacheServiceError> {
+        self.air_quality_repository
+            .store_air_quality_result_as_cache(
+                req,
+                ttl_in_seconds,
+                redis_pool
+            )
+            .await
+    }
+
+    #[tracing::instrument(name = "Get Historical Weather Cached Data Service", skip(redis_pool))]
+    pub async fn get_historical_weather_cache_data_by_coordinates(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        date_range: &DateRangeRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<CurrentWeatherCacheResponse>, CacheServiceError> {
+        let cached_data = self.historical_weather_repository
+            .retrieve_historical_weather_cache_result_by_coordinates(req, date_range, redis_pool)
+            .await
+            .inspect_err(|e| {
+                tracing::info!("Was not able to get historical weather cache data with error: {}", e.get_message());
+            })?;
+
+        self.parse_historical_weather_cache_data(cached_data, req)
+    }
+
+    #[tracing::instrument(name = "Get Historical Weather Cached Data Service", skip(redis_pool))]
+    pub async fn get_historical_weather_cache_data_by_location(
+        &self,
+        req: &RetrieveCurrentWeatherCacheRequest,
+        date_range: &DateRangeRequest,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<CurrentWeatherCacheResponse>, CacheServiceError> {
+        let cached_data = self.historical_weather_repository
+            .retrieve_historical_weather_cache_result_by_location(req, date_range, redis_pool)
+            .await
+            .inspect_err(|e| {
+                tracing::info!("Was not able to get historical weather cache data with error: {}", e.get_message());
+            })?;
+
+        self.parse_historical_weather_cache_data(cached_data, req)
+    }
+
+    fn parse_historical_weather_cache_data(
+        &self,
+        cached_data: Option<String>,
+        req: &RetrieveCurrentWeatherCacheRequest,
+    ) -> Result<Vec<CurrentWeatherCacheResponse>, CacheServiceError> {
+        let cached_data = cached_data.ok_or(CacheServiceError::CacheMissError(
+            req.lat, req.lon, req.country.clone(), req.state.clone()
+        ))?;
+
+        serde_json::from_str(&cached_data)
+            .map_err(|e| CacheServiceError::ResponseParsingError(Some(e.to_string())))
+    }
+
+    #[tracing::instrument(name = "Store Historical Weather Data Cache Service",
+        skip(redis_pool, req))]
+    pub async fn store_historical_weather_result_as_cache(
+        &self,
+        req: &StoreHistoricalWeatherDataRequest,
+        ttl_in_seconds: u64,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<bool, CacheServiceError> {
+        self.historical_weather_repository
+            .store_historical_weather_result_as_cache(
+                req,
+                ttl_in_seconds,
                 redis_pool
             )
             .await
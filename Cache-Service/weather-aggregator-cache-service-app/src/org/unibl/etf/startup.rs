@@ -5,10 +5,12 @@ use actix_web_validator::QueryConfig;
 use chrono::Utc;
 use rustls::ServerConfig;
 use tracing_actix_web::TracingLogger;
-use crate::org::unibl::etf::configuration::settings::{RedisStoreSettings};
-use crate::org::unibl::etf::controllers::current_weather_controller;
+use crate::org::unibl::etf::configuration::settings::{AdminSettings, CacheSettings, RedisStoreSettings};
+use crate::org::unibl::etf::controllers::{current_weather_controller, forecast_controller, historical_weather_controller, air_quality_controller, cache_admin_controller};
 use crate::org::unibl::etf::handlers::query_error_handler;
 use crate::org::unibl::etf::model::responses::health_check_response::HealthCheckResponse;
+use crate::org::unibl::etf::security::client_identity::capture_client_certificate;
+use crate::org::unibl::etf::services::cache_admin_service::CacheAdminService;
 use crate::org::unibl::etf::services::cache_service::{CacheService};
 
 async fn health_check() -> impl Responder {
@@ -24,6 +26,8 @@ async fn health_check() -> impl Responder {
 pub fn run(
     tcp_listener: TcpListener,
     settings: RedisStoreSettings,
+    cache_settings: CacheSettings,
+    admin_settings: AdminSettings,
     redis_pool: deadpool_redis::Pool,
     server_config: Option<ServerConfig>,
 ) -> std::io::Result<Server> {
@@ -32,12 +36,21 @@ pub fn run(
         web::Data::new(CacheService::default());
     let configuration_settings =
         web::Data::new(settings);
+    let cache_settings =
+        web::Data::new(cache_settings);
+    let cache_admin_service =
+        web::Data::new(CacheAdminService::default());
+    let admin_settings =
+        web::Data::new(admin_settings);
     let redis_pool = web::Data::new(redis_pool);
 
     let mut server = HttpServer::new(move || {
         App::new()
             .app_data(cache_service.clone())
             .app_data(configuration_settings.clone())
+            .app_data(cache_settings.clone())
+            .app_data(cache_admin_service.clone())
+            .app_data(admin_settings.clone())
             .app_data(redis_pool.clone())
             .wrap(TracingLogger::default())
             .app_data(QueryConfig::default()
@@ -46,9 +59,14 @@ pub fn run(
             .service(
                 web::scope("/api/v1")
                     .configure(current_weather_controller::routes)
+                    .configure(forecast_controller::routes)
+                    .configure(historical_weather_controller::routes)
+                    .configure(air_quality_controller::routes)
+                    .service(web::scope("/admin").configure(cache_admin_controller::routes))
             )
             .route("/health_check", web::get().to(health_check))
-    });
+    })
+    .on_connect(capture_client_certificate);
     server = match server_config {
         Some(config) => server.listen_rustls_0_23(tcp_listener, config)?,
         None => server.listen(tcp_listener)?,
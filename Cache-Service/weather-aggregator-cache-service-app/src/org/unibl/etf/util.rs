@@ -1,2 +1,4 @@
 pub mod serializers;
-pub(crate) mod deserializers;
\ No newline at end of file
+pub(crate) mod deserializers;
+pub(crate) mod geo;
+pub(crate) mod location_normalization;
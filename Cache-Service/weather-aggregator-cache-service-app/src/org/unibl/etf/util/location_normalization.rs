@@ -0,0 +1,69 @@
+use celes::Country;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+// Letters NFKD leaves intact because they are not composed from a base letter and a mark.
+fn transliterate(c: char) -> Option<&'static str> {
+    match c {
+        'đ' | 'Đ' => Some("d"),
+        'ø' | 'Ø' => Some("o"),
+        'ł' | 'Ł' => Some("l"),
+        'ß' => Some("ss"),
+        'æ' | 'Æ' => Some("ae"),
+        'œ' | 'Œ' => Some("oe"),
+        'þ' | 'Þ' => Some("th"),
+        'ı' => Some("i"),
+        _ => None,
+    }
+}
+
+// Case folding, whitespace collapse and diacritic stripping, so "Banja  Luka" and "banja luka" share a key.
+// Colons separate key segments, so they are treated as whitespace.
+pub fn normalize_location_component(value: &str) -> String {
+    let folded: String = value
+        .nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(|c| match transliterate(c) {
+            Some(replacement) => replacement.chars().collect::<Vec<char>>(),
+            None => c.to_lowercase().collect(),
+        })
+        .map(|c| if c == ':' { ' ' } else { c })
+        .collect();
+
+    folded.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// Country names, aliases and ISO codes all map to the lowercase alpha-2 code.
+pub fn normalize_country(value: &str) -> String {
+    let trimmed = value.trim();
+    let normalized = normalize_location_component(value);
+
+    Country::from_alpha2(trimmed)
+        .or_else(|_| Country::from_alpha3(trimmed))
+        .or_else(|_| Country::from_name(trimmed))
+        .or_else(|_| Country::from_alias(trimmed))
+        .or_else(|_| Country::from_name(&normalized))
+        .or_else(|_| Country::from_alias(&normalized))
+        .map(|country| country.alpha2.to_lowercase())
+        .unwrap_or(normalized)
+}
+
+// The <country>:<state>:<name> part of every location keyed cache entry.
+pub fn location_key(country: &str, state: &str, location_name: &str) -> String {
+    format!(
+        "{}:{}:{}",
+        normalize_country(country),
+        normalize_location_component(state),
+        normalize_location_component(location_name),
+    )
+}
+
+// Same as location_key, with a wildcard for whatever part is missing.
+pub fn location_key_pattern(country: Option<&str>, state: Option<&str>, location_name: &str) -> String {
+    format!(
+        "{}:{}:{}",
+        country.map(normalize_country).unwrap_or("*".to_string()),
+        state.map(normalize_location_component).unwrap_or("*".to_string()),
+        normalize_location_component(location_name),
+    )
+}
@@ -6,7 +6,7 @@ use rustls::{RootCertStore, ServerConfig};
 use rustls::server::WebPkiClientVerifier;
 use secrecy::{ExposeSecret, SecretBox};
 use serde::Deserialize;
-use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_aux::field_attributes::{deserialize_number_from_string, deserialize_option_number_from_string};
 use serde_aux::prelude::deserialize_bool_from_anything;
 
 #[derive(Deserialize, Debug)]
@@ -15,6 +15,35 @@ pub struct Settings {
     pub geocoding_api: GeocodingAPISettings,
     pub tracing_agent: TracingSettings,
     pub redis_store: RedisStoreSettings,
+    #[serde(default)]
+    pub geocoding_cache: GeocodingCacheSettings,
+}
+
+// Place names rarely move, so resolved lookups are kept for a long time. Unknown names are kept for less,
+// in case the provider learns them.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct GeocodingCacheSettings {
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub enabled: bool,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ttl_in_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub not_found_ttl_in_seconds: u64,
+    // Reverse lookups within the same geohash cell share a cache entry. 6 characters is a cell of about 1.2 km by 0.6 km.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub reverse_geohash_precision: usize,
+}
+
+impl Default for GeocodingCacheSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl_in_seconds: 30 * 86400,
+            not_found_ttl_in_seconds: 86400,
+            reverse_geohash_precision: 6,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -37,11 +66,81 @@ pub struct ApplicationSettings {
 pub struct GeocodingAPISettings {
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub requests_per_30_mins: u64,
-    pub api_key: SecretBox<String>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_minute: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_day: Option<u64>,
+    #[serde(default)]
+    pub api_key: Option<SecretBox<String>>,
+    #[serde(default)]
+    pub api_keys: Vec<ApiKeySettings>,
+    #[serde(default = "default_api_key_cooldown_in_seconds", deserialize_with = "deserialize_number_from_string")]
+    pub api_key_cooldown_in_seconds: u64,
     pub endpoint: String,
+    #[serde(default = "default_reverse_endpoint")]
+    pub reverse_endpoint: String,
     pub provider: String,
 }
 
+fn default_reverse_endpoint() -> String {
+    "https://api.openweathermap.org/geo/1.0/reverse".to_string()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitWindow {
+    pub window_in_seconds: u64,
+    pub max_requests: u64,
+}
+
+fn default_api_key_cooldown_in_seconds() -> u64 {
+    900
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ApiKeySettings {
+    // Reported in usage instead of the key itself.
+    pub id: String,
+    pub key: SecretBox<String>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_day: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PooledApiKey<'a> {
+    pub id: &'a str,
+    pub secret: &'a SecretBox<String>,
+    pub requests_per_day: Option<u64>,
+}
+
+impl GeocodingAPISettings {
+    // A call is only made when every configured window has room for it.
+    pub fn rate_limit_windows(&self) -> Vec<RateLimitWindow> {
+        [
+            (60, self.requests_per_minute),
+            (1800, Some(self.requests_per_30_mins)),
+            (86400, self.requests_per_day),
+        ]
+            .into_iter()
+            .filter_map(|(window_in_seconds, max_requests)| {
+                max_requests.map(|max_requests| RateLimitWindow { window_in_seconds, max_requests })
+            })
+            .collect()
+    }
+
+    // A lone api_key joins the pool as an unmetered key, so older configurations keep working.
+    pub fn api_key_pool(&self) -> Vec<PooledApiKey<'_>> {
+        self.api_key
+            .iter()
+            .map(|secret| PooledApiKey { id: "default", secret, requests_per_day: None })
+            .chain(self.api_keys.iter().map(|api_key| PooledApiKey {
+                id: api_key.id.as_str(),
+                secret: &api_key.key,
+                requests_per_day: api_key.requests_per_day,
+            }))
+            .collect()
+    }
+}
+
 
 #[derive(serde::Deserialize, Debug, Clone)]
 pub struct TracingSettings {
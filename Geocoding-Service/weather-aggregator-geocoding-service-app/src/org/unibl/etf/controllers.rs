@@ -1,2 +1,3 @@
 pub mod geocoding_controller;
+pub mod api_key_controller;
 pub mod errors;
\ No newline at end of file
@@ -4,11 +4,14 @@ use reqwest::Client;
 use crate::org::unibl::etf::configuration::settings::{GeocodingAPISettings};
 use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
 use crate::org::unibl::etf::model::requests::geocoding_request::GeocodingRequest;
+use crate::org::unibl::etf::model::requests::reverse_geocoding_request::ReverseGeocodingRequest;
 use crate::org::unibl::etf::model::responses::geocoding_response::GeocodingResponse;
 use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
 
 pub fn routes(cfg: &mut web::ServiceConfig) {
-    cfg.service(web::resource("/geocode").route(web::get().to(get_coordinates_by_city_name)));
+    cfg
+        .service(web::resource("/geocode").route(web::get().to(get_coordinates_by_city_name)))
+        .service(web::resource("/reverse_geocode").route(web::get().to(get_locations_by_coordinates)));
 }
 
 #[tracing::instrument(name = "Get Coordinates by City name Controller",
@@ -44,4 +47,34 @@ async fn get_coordinates_by_city_name(
             }
         })?
     )
-}
\ No newline at end of file
+}
+
+#[tracing::instrument(name = "Get Locations by Coordinates Controller",
+    skip(http_client, geocoding_service, settings, redis_pool))]
+async fn get_locations_by_coordinates(
+    geocoding_service: web::Data<GeocodingService>,
+    query: Query<ReverseGeocodingRequest>,
+    http_client: web::Data<Client>,
+    settings: web::Data<GeocodingAPISettings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let candidates = geocoding_service
+        .reverse_geocode_location(
+            query.lat,
+            query.lon,
+            query.limit.unwrap(),
+            http_client.get_ref(),
+            settings.get_ref(),
+            redis_pool.get_ref(),
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!("Was not able to reverse geocode coordinates with error: {:?}", e);
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_geocoding_error(e)
+            }
+        })?;
+
+    tracing::info!("Successfully reverse geocoded coordinates with result: {:?}", candidates);
+    Ok(HttpResponse::Ok().json(GeocodingResponse { candidates }))
+}
@@ -39,6 +39,7 @@ async fn main() -> std::io::Result<()> {
     let res = run(
         listener,
         configuration.geocoding_api,
+        configuration.geocoding_cache,
         redis_pool,
         http_server_config
     )?.await;
@@ -1,10 +1,10 @@
-use serde::{Serialize};
+use serde::{Deserialize, Serialize};
 use crate::org::unibl::etf::model::responses::geocoding_api_response::GeocodingAPIResponse;
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LocationCandidate {
     pub location_name: String,
-    #[serde(skip_serializing_if = "String::is_empty")]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub state: String,
     pub country: String,
     pub lat: f64,
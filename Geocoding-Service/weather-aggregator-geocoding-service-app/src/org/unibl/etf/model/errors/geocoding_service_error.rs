@@ -10,7 +10,8 @@ pub enum GeocodingServiceError {
     ExternalGeocodingApiError(u16, Option<String>),
     LocationNotFoundError(Option<String>),
     RequestValidationError(Option<String>),
-    RateLimitExceeded,
+    // Seconds until the provider accepts another call.
+    RateLimitExceeded(Option<u64>),
     RedisError(Option<String>, Option<String>),
 }
 
@@ -19,7 +20,7 @@ impl GeocodingServiceError {
         match self {
             Self::LocationNotFoundError(s) => Self::LocationNotFoundError(s.clone()),
             Self::RequestValidationError(s) => Self::RequestValidationError(s.clone()),
-            Self::RateLimitExceeded => Self::RateLimitExceeded,
+            Self::RateLimitExceeded(retry_after) => Self::RateLimitExceeded(*retry_after),
             _ => Self::ServerError(None),
         }
     }
@@ -27,7 +28,7 @@ impl GeocodingServiceError {
         match self {
             Self::LocationNotFoundError(s) => s.clone().unwrap_or(String::default()),
             Self::RequestValidationError(s) => s.clone().unwrap_or(String::default()),
-            Self::RateLimitExceeded => self.get_message(),
+            Self::RateLimitExceeded(_) => self.get_message(),
             _ => String::default(),
         }
 
@@ -40,7 +41,7 @@ impl GeocodingServiceError {
             Self::ExternalGeocodingApiError(_, _) => "EXTERNAL_GEOCODING_API_ERROR",
             Self::LocationNotFoundError(_) => "LOCATION_NOT_FOUND_ERROR",
             Self::RequestValidationError(_) => "REQUEST_VALIDATION_ERROR",
-            Self::RateLimitExceeded => "REQUEST_RATE_LIMIT EXCEEDED",
+            Self::RateLimitExceeded(_) => "REQUEST_RATE_LIMIT EXCEEDED",
             Self::RedisError(_, _) => "REDIS_ERROR",
         }
     }
@@ -62,7 +63,7 @@ impl GeocodingServiceError {
             GeocodingServiceError::RequestValidationError(msg) => {
                 msg.clone().unwrap_or(String::default())
             },
-            GeocodingServiceError::RateLimitExceeded => {
+            GeocodingServiceError::RateLimitExceeded(_) => {
                 "Rate limit exceeded for the geocoding provider.".to_string()
             },
             GeocodingServiceError::RedisError(_, msg) => msg.clone().unwrap_or(String::default()),
@@ -72,7 +73,7 @@ impl GeocodingServiceError {
         match self {
             GeocodingServiceError::LocationNotFoundError(_) => 1000,
             GeocodingServiceError::RequestValidationError(_) => 1001,
-            GeocodingServiceError::RateLimitExceeded => 1002,
+            GeocodingServiceError::RateLimitExceeded(_) => 1002,
             GeocodingServiceError::RedisError(_, _) => 1003,
             _ => 1004,
         }
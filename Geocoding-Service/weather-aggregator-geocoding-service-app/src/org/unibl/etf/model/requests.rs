@@ -1 +1,2 @@
-pub mod geocoding_request;
\ No newline at end of file
+pub mod geocoding_request;
+pub mod reverse_geocoding_request;
\ No newline at end of file
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use validator::Validate;
+use crate::org::unibl::etf::model::requests::geocoding_request::default_limit;
+
+#[derive(Deserialize, Debug, Validate)]
+pub struct ReverseGeocodingRequest {
+    #[validate(range(min = -90.0, max = 90.0, message = "Latitude must be between -90 and 90."))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0, message = "Longitude must be between -180 and 180."))]
+    pub lon: f64,
+    #[validate(range(min = 0, max = 5, message = "Maximum of 5 results allowed in reverse geocoding (limit query parameter)."))]
+    #[serde(default = "default_limit")]
+    pub limit: Option<u16>,
+}
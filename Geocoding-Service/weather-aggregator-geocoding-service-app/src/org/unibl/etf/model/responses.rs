@@ -1,3 +1,4 @@
 pub mod health_check_response;
 pub mod geocoding_api_response;
-pub mod geocoding_response;
\ No newline at end of file
+pub mod geocoding_response;
+pub mod api_key_usage_response;
\ No newline at end of file
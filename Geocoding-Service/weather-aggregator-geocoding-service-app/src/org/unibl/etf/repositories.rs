@@ -1 +1,2 @@
-pub mod provider_repository;
\ No newline at end of file
+pub mod provider_repository;
+pub mod geocoding_cache_repository;
\ No newline at end of file
@@ -0,0 +1,88 @@
+use deadpool_redis::redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use crate::org::unibl::etf::model::dto::location_candidate::LocationCandidate;
+use crate::org::unibl::etf::model::errors::geocoding_service_error::GeocodingServiceError;
+use crate::org::unibl::etf::util::location_normalization::normalize_location_name;
+
+// Names the provider did not know are cached as well, so repeated lookups for them do not spend quota.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CachedGeocodingResult {
+    Found(Vec<LocationCandidate>),
+    NotFound,
+}
+
+#[derive(Debug)]
+pub struct GeocodingCacheRepository {}
+
+impl GeocodingCacheRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[tracing::instrument(name = "Get cached geocoding result repository", skip(redis_pool))]
+    pub async fn get_geocoding_result(
+        &self,
+        cache_key: &str,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Option<CachedGeocodingResult>, GeocodingServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(GeocodingServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let result: Option<String> = conn.get(cache_key)
+            .await
+            .map_err(|e| {
+                GeocodingServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })?;
+
+        result
+            .map(|json| serde_json::from_str(&json))
+            .transpose()
+            .map_err(|e| GeocodingServiceError::ResponseParsingError(Some(e.to_string())))
+    }
+
+    #[tracing::instrument(name = "Store geocoding result repository", skip(result, redis_pool))]
+    pub async fn store_geocoding_result(
+        &self,
+        cache_key: &str,
+        result: &CachedGeocodingResult,
+        ttl_in_seconds: u64,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<(), GeocodingServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(GeocodingServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        let json = serde_json::to_string(result)
+            .map_err(|e| GeocodingServiceError::ServerError(Some(e.to_string())))?;
+
+        conn.set_ex::<_, _, ()>(cache_key, json, ttl_in_seconds)
+            .await
+            .map_err(|e| {
+                GeocodingServiceError::RedisError(Some(e.code().unwrap_or("").to_string()), Some(e.to_string()))
+            })
+    }
+}
+
+impl Default for GeocodingCacheRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn geocoding_cache_key(provider_name: &str, location_name: &str, limit: u16) -> String {
+    format!("geocoding:{}:{}:{}", provider_name, normalize_location_name(location_name), limit)
+}
+
+pub fn reverse_geocoding_cache_key(provider_name: &str, geohash: &str, limit: u16) -> String {
+    format!("reverse_geocoding:{}:{}:{}", provider_name, geohash, limit)
+}
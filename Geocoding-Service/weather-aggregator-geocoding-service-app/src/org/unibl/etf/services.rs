@@ -1 +1,2 @@
-pub mod geocoding_service;
\ No newline at end of file
+pub mod geocoding_service;
+pub mod api_key_service;
\ No newline at end of file
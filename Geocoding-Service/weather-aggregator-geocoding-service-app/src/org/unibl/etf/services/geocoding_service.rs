@@ -1,94 +1,160 @@
+use geohash::{encode, Coord};
 use reqwest::StatusCode;
 use secrecy::ExposeSecret;
-use crate::org::unibl::etf::configuration::settings::{GeocodingAPISettings};
+use crate::org::unibl::etf::configuration::settings::{GeocodingAPISettings, GeocodingCacheSettings};
 use crate::org::unibl::etf::model::dto::location_candidate::LocationCandidate;
 use crate::org::unibl::etf::model::errors::geocoding_api_error::ExternalGeocodingApiError;
 use crate::org::unibl::etf::model::errors::geocoding_service_error::{GeocodingServiceError};
 use crate::org::unibl::etf::model::responses::geocoding_api_response::GeocodingAPIResponse;
+use crate::org::unibl::etf::repositories::geocoding_cache_repository::{
+    geocoding_cache_key, reverse_geocoding_cache_key, CachedGeocodingResult, GeocodingCacheRepository,
+};
 use crate::org::unibl::etf::repositories::provider_repository::ProviderRepository;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
 
 #[derive(Debug)]
 pub struct GeocodingService {
     provider_repository: ProviderRepository,
+    geocoding_cache_repository: GeocodingCacheRepository,
+    api_key_service: ApiKeyService,
+    cache_settings: GeocodingCacheSettings,
 }
 
 impl GeocodingService {
-    fn new() -> Self {
+    pub fn new(cache_settings: GeocodingCacheSettings) -> Self {
         Self {
-            provider_repository: ProviderRepository::default()
+            provider_repository: ProviderRepository::default(),
+            geocoding_cache_repository: GeocodingCacheRepository::default(),
+            api_key_service: ApiKeyService::default(),
+            cache_settings,
         }
     }
 
-    #[tracing::instrument(name = "Check if ratelimit is exceeded function", skip(redis_pool))]
-    pub async fn check_if_ratelimit_is_exceeded(
+    #[tracing::instrument(name = "Geocode Location Service", skip(client, settings, redis_pool))]
+    pub async fn geocode_location(
+        &self,
+        location: &String,
+        limit: u16,
+        client: &reqwest::Client,
+        settings: &GeocodingAPISettings,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<LocationCandidate>, GeocodingServiceError> {
+        let query = [("q", location.clone()), ("limit", limit.to_string())];
+        let fetch = self.fetch_location_candidates(
+            &settings.endpoint,
+            &query,
+            location,
+            client,
+            settings,
+            redis_pool,
+        );
+
+        self.lookup_with_cache(&geocoding_cache_key(&settings.provider, location, limit), location, redis_pool, fetch).await
+    }
+
+    #[tracing::instrument(name = "Reverse Geocode Location Service", skip(client, settings, redis_pool))]
+    pub async fn reverse_geocode_location(
+        &self,
+        lat: f64,
+        lon: f64,
+        limit: u16,
+        client: &reqwest::Client,
+        settings: &GeocodingAPISettings,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<Vec<LocationCandidate>, GeocodingServiceError> {
+        let coordinates = format!("{},{}", lat, lon);
+        let query = [("lat", lat.to_string()), ("lon", lon.to_string()), ("limit", limit.to_string())];
+        let fetch = self.fetch_location_candidates(
+            &settings.reverse_endpoint,
+            &query,
+            &coordinates,
+            client,
+            settings,
+            redis_pool,
+        );
+
+        let geohash = encode(Coord { x: lon, y: lat }, self.cache_settings.reverse_geohash_precision)
+            .map_err(|e| GeocodingServiceError::RequestValidationError(Some(e.to_string())))?;
+
+        self.lookup_with_cache(&reverse_geocoding_cache_key(&settings.provider, &geohash, limit), &coordinates, redis_pool, fetch).await
+    }
+
+    // Cached answers are served without asking the provider, so they do not count against its rate limits.
+    async fn lookup_with_cache(
         &self,
-        max_number_of_requests_per_30_mins: u64,
-        provider_name: &str,
+        cache_key: &str,
+        location: &str,
         redis_pool: &deadpool_redis::Pool,
-    ) -> Result<bool, GeocodingServiceError> {
-        match self.provider_repository.get_number_of_requests(provider_name, redis_pool).await{
-            Ok(number_of_requests) => {
-                tracing::info!("Successfully retrieved number of requests made for the geocoding provider");
-                Ok(number_of_requests >= max_number_of_requests_per_30_mins as i64)
+        fetch: impl Future<Output = Result<Vec<LocationCandidate>, GeocodingServiceError>>,
+    ) -> Result<Vec<LocationCandidate>, GeocodingServiceError> {
+        if !self.cache_settings.enabled {
+            return fetch.await;
+        }
+
+        // A broken cache only costs provider calls, it does not fail the lookup.
+        match self.geocoding_cache_repository
+            .get_geocoding_result(cache_key, redis_pool)
+            .await
+        {
+            Ok(Some(CachedGeocodingResult::Found(candidates))) => {
+                tracing::info!("Geocoding cache hit for location {}.", location);
+                return Ok(candidates);
             },
+            Ok(Some(CachedGeocodingResult::NotFound)) => {
+                tracing::info!("Geocoding cache hit for unknown location {}.", location);
+                return Err(GeocodingServiceError::LocationNotFoundError(Some(location.to_string())));
+            },
+            Ok(None) => {},
             Err(e) => {
-                tracing::info!("Was not able to get number of requests made for the geocoding provider: {}", e.get_message());
-                Err(e)
+                tracing::warn!("Was not able to read the geocoding cache: {}", e.get_message());
+            },
+        }
+
+        let result = fetch.await;
+        let (cached_result, ttl_in_seconds) = match &result {
+            Ok(candidates) => (CachedGeocodingResult::Found(candidates.clone()), self.cache_settings.ttl_in_seconds),
+            Err(GeocodingServiceError::LocationNotFoundError(_)) => {
+                (CachedGeocodingResult::NotFound, self.cache_settings.not_found_ttl_in_seconds)
             },
+            Err(_) => return result,
+        };
+
+        if let Err(e) = self.geocoding_cache_repository
+            .store_geocoding_result(cache_key, &cached_result, ttl_in_seconds, redis_pool)
+            .await
+        {
+            tracing::warn!("Was not able to store the geocoding result in cache: {}", e.get_message());
         }
+
+        result
     }
 
-    #[tracing::instrument(name = "Geocode Location Service", skip(client, settings))]
-    pub async fn geocode_location(
+    // Location is only used to report what was not found.
+    #[tracing::instrument(name = "Fetch Location Candidates Service", skip(client, settings, redis_pool))]
+    async fn fetch_location_candidates(
         &self,
-        location: &String,
-        limit: u16,
+        endpoint: &str,
+        query: &[(&str, String)],
+        location: &str,
         client: &reqwest::Client,
         settings: &GeocodingAPISettings,
         redis_pool: &deadpool_redis::Pool,
     ) -> Result<Vec<LocationCandidate>, GeocodingServiceError> {
-        match self.check_if_ratelimit_is_exceeded(
-            settings.requests_per_30_mins,
+        let api_key = self.api_key_service.acquire_api_key(settings, redis_pool).await?;
+        self.provider_repository.acquire_rate_limit_slot(
+            &settings.rate_limit_windows(),
             settings.provider.as_str(),
             redis_pool
-        ).await {
-            Ok(exceeded) => {
-                if exceeded {
-                    tracing::info!("Ratelimit exceeded for the geocoding provider.");
-                    return Err(GeocodingServiceError::RateLimitExceeded);
-                }
-                tracing::info!("Ratelimit not exceeded for the geocoding provider.");
-            },
-            Err(e) => {
-                return Err(e);
-            }
-        }
-
+        ).await?;
         let response = client
-            .get(settings.endpoint.clone())
-            .query(&[
-                ("appid", settings.api_key.expose_secret().clone()),
-                ("q", location.clone()),
-                ("limit", limit.to_string()),
-            ])
+            .get(endpoint)
+            .query(&[("appid", api_key.secret.expose_secret().clone())])
+            .query(query)
             .send()
             .await
             .map_err(|e| GeocodingServiceError::ConnectionError(Some(e.to_string())))?;
 
         if response.status().is_success() {
-            match self.provider_repository.increment_number_of_requests(
-                settings.provider.as_str(),
-                redis_pool,
-            ).await {
-                Ok(new_value) => {
-                    tracing::info!("Successfully incremented number of requests made. New value: {}", new_value)
-                },
-                Err(e) => {
-                    tracing::error!("Failed to increment number of requests made with error: {:?}", e.get_message())
-                }
-            }
-
-
             let body_text = response.text().await.map_err(|e| {
                 GeocodingServiceError::ServerError(Some(format!("Failed to get Geocoding API success response body text: {}", e)))
             })?;
@@ -103,7 +169,7 @@ impl GeocodingService {
 
 
             if data.is_empty() {
-                return Err(GeocodingServiceError::LocationNotFoundError(Some(location.clone())));
+                return Err(GeocodingServiceError::LocationNotFoundError(Some(location.to_string())));
             }
 
             let mut candidates: Vec<LocationCandidate> = Vec::new();
@@ -115,6 +181,7 @@ impl GeocodingService {
         }
         else {
             let status = response.status();
+            self.api_key_service.retire_api_key_if_rejected(status, &api_key, settings, redis_pool).await;
 
             match status {
                 StatusCode::NOT_FOUND |
@@ -146,6 +213,6 @@ impl GeocodingService {
 
 impl Default for GeocodingService {
     fn default() -> Self {
-        Self::new()
+        Self::new(GeocodingCacheSettings::default())
     }
 }
\ No newline at end of file
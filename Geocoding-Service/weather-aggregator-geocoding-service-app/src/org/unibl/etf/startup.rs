@@ -5,10 +5,11 @@ use actix_web_validator::QueryConfig;
 use chrono::Utc;
 use rustls::ServerConfig;
 use tracing_actix_web::TracingLogger;
-use crate::org::unibl::etf::configuration::settings::{GeocodingAPISettings};
-use crate::org::unibl::etf::controllers::geocoding_controller;
+use crate::org::unibl::etf::configuration::settings::{GeocodingAPISettings, GeocodingCacheSettings};
+use crate::org::unibl::etf::controllers::{api_key_controller, geocoding_controller};
 use crate::org::unibl::etf::handlers::query_error_handler;
 use crate::org::unibl::etf::model::responses::health_check_response::HealthCheckResponse;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
 use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
 
 async fn health_check() -> impl Responder {
@@ -24,6 +25,7 @@ async fn health_check() -> impl Responder {
 pub fn run(
     tcp_listener: TcpListener,
     settings: GeocodingAPISettings,
+    cache_settings: GeocodingCacheSettings,
     redis_pool: deadpool_redis::Pool,
     server_config: Option<ServerConfig>
 ) -> std::io::Result<Server> {
@@ -31,7 +33,9 @@ pub fn run(
     let http_client =
         web::Data::new(reqwest::Client::new());
     let geocoding_service =
-        web::Data::new(GeocodingService::default());
+        web::Data::new(GeocodingService::new(cache_settings));
+    let api_key_service =
+        web::Data::new(ApiKeyService::default());
     let configuration_settings =
         web::Data::new(settings);
     let redis_pool = web::Data::new(redis_pool);
@@ -41,6 +45,7 @@ pub fn run(
         App::new()
             .app_data(http_client.clone())
             .app_data(geocoding_service.clone())
+            .app_data(api_key_service.clone())
             .app_data(configuration_settings.clone())
             .app_data(redis_pool.clone())
             .wrap(TracingLogger::default())
@@ -50,6 +55,7 @@ pub fn run(
             .service(
                 web::scope("/api/v1")
                     .configure(geocoding_controller::routes)
+                    .configure(api_key_controller::routes)
             )
             .route("/health_check", web::get().to(health_check))
     });
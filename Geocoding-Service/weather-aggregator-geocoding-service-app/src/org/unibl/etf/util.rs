@@ -1 +1,2 @@
-pub mod serializers;
\ No newline at end of file
+pub mod serializers;
+pub mod location_normalization;
\ No newline at end of file
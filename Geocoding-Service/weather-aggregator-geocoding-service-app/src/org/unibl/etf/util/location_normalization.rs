@@ -0,0 +1,12 @@
+// Case folding and whitespace collapse, so "Banja  Luka" and "banja luka" share a cache key.
+// Diacritics are kept, the provider may resolve "Niš" and "Nis" differently.
+// Colons separate key segments, so they are treated as whitespace.
+pub fn normalize_location_name(value: &str) -> String {
+    let folded: String = value
+        .chars()
+        .flat_map(|c| c.to_lowercase())
+        .map(|c| if c == ':' { ' ' } else { c })
+        .collect();
+
+    folded.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
@@ -0,0 +1,28 @@
+use actix_web::{web, HttpResponse, Responder};
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/api_key_usage").route(web::get().to(get_api_key_usage)));
+}
+
+#[tracing::instrument(name = "Get API Key Usage Controller",
+    skip(api_key_service, settings, redis_pool))]
+async fn get_api_key_usage(
+    api_key_service: web::Data<ApiKeyService>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let res = api_key_service
+        .get_api_key_usage(&settings.provider, redis_pool.get_ref())
+        .await
+        .map_err(|e| {
+            tracing::error!("Was not able to get API key usage with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
@@ -0,0 +1,49 @@
+use actix_web::{web, HttpResponse, Responder};
+
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+
+use crate::org::unibl::etf::model::requests::current_weather_request::{CurrentWeatherRequest};
+
+
+use crate::org::unibl::etf::services::forecast_service::ForecastService;
+use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/forecast").route(web::get().to(get_forecast_data)));
+}
+
+#[tracing::instrument(name = "Get Forecast Data Controller",
+    skip(http_client, forecast_service, geocoding_service, settings, redis_pool))]
+async fn get_forecast_data(
+    forecast_service: web::Data<ForecastService>,
+    geocoding_service: web::Data<GeocodingService>,
+    query: Query<CurrentWeatherRequest>,
+    http_client: web::Data<ClientWithMiddleware>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    forecast_service
+        .get_forecast(
+            query.into_inner(),
+            http_client.get_ref(),
+            &settings,
+            redis_pool.get_ref(),
+            geocoding_service.get_ref()
+        )
+        .await
+        .map(|forecast_data| {
+            tracing::info!("Successfully got forecast data from external API: {:?}", forecast_data);
+            HttpResponse::Ok().json(forecast_data)
+        }
+        )
+        .map_err(|e| {
+            tracing::error!("Was not able to get forecast data with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })
+
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use actix_web::{web, HttpResponse, Responder};
+
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+
+use crate::org::unibl::etf::model::requests::current_weather_request::{CurrentWeatherRequest};
+
+
+use crate::org::unibl::etf::services::weather_alerts_service::WeatherAlertsService;
+use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/weather_alerts").route(web::get().to(get_weather_alerts_data)));
+}
+
+#[tracing::instrument(name = "Get Weather Alerts Controller",
+    skip(http_client, weather_alerts_service, geocoding_service, settings, redis_pool))]
+async fn get_weather_alerts_data(
+    weather_alerts_service: web::Data<WeatherAlertsService>,
+    geocoding_service: web::Data<GeocodingService>,
+    query: Query<CurrentWeatherRequest>,
+    http_client: web::Data<ClientWithMiddleware>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    weather_alerts_service
+        .get_weather_alerts(
+            query.into_inner(),
+            http_client.get_ref(),
+            &settings,
+            redis_pool.get_ref(),
+            geocoding_service.get_ref()
+        )
+        .await
+        .map(|alerts_data| {
+            tracing::info!("Successfully got {} weather alerts from external API", alerts_data.alerts.len());
+            HttpResponse::Ok().json(alerts_data)
+        }
+        )
+        .map_err(|e| {
+            tracing::error!("Was not able to get weather alerts data with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })
+
+}
\ No newline at end of file
@@ -15,7 +15,8 @@ pub enum AdapterServiceError {
     GeocodingServiceError(u16, Option<String>),
     GeocodingResponseParsingError(Option<String>),
     AmbiguousLocationNameError(Vec<LocationCandidate>),
-    RateLimitExceeded,
+    // Seconds until the provider accepts another call.
+    RateLimitExceeded(Option<u64>),
     RedisError(Option<String>, Option<String>),
 }
 
@@ -27,7 +28,7 @@ impl AdapterServiceError {
             Self::LocationNotFoundError(s) => Self::LocationNotFoundError(s.clone()),
             Self::AmbiguousLocationNameError(candidates) => Self::AmbiguousLocationNameError(candidates.clone()),
             Self::RequestParametersValidationError(s) => Self::RequestParametersValidationError(s.clone()),
-            Self::RateLimitExceeded => Self::RateLimitExceeded,
+            Self::RateLimitExceeded(retry_after) => Self::RateLimitExceeded(*retry_after),
             _ => Self::ServerError(None),
         }
 
@@ -38,7 +39,7 @@ impl AdapterServiceError {
             Self::LocationNotFoundError(s) => s.clone().unwrap_or(String::default()),
             Self::AmbiguousLocationNameError(_s) => self.get_message(),
             Self::RequestParametersValidationError(s) => s.clone().unwrap_or(String::default()),
-            Self::RateLimitExceeded => self.get_message(),
+            Self::RateLimitExceeded(_) => self.get_message(),
             _ => String::default(),
         }
 
@@ -55,7 +56,7 @@ impl AdapterServiceError {
             AdapterServiceError::RequestParametersValidationError(_) => {
                 String::from("Request parameters are invalid.")
             },
-            AdapterServiceError::RateLimitExceeded => {
+            AdapterServiceError::RateLimitExceeded(_) => {
                 String::from("API Rate Limit exceeded for the provider.")
             }
             _ => { String::from("Unexpected server error.") }
@@ -78,7 +79,7 @@ impl AdapterServiceError {
             AdapterServiceError::LocationNotFoundError(_) => 1008,
             AdapterServiceError::AmbiguousLocationNameError(_) => 1009,
             AdapterServiceError::RequestParametersValidationError(_) => 1010,
-            AdapterServiceError::RateLimitExceeded => 1011,
+            AdapterServiceError::RateLimitExceeded(_) => 1011,
             AdapterServiceError::InvalidProviderResponseError(_) => 1012,
             AdapterServiceError::GeocodingServiceError(_, _) => 1013,
             AdapterServiceError::GeocodingResponseParsingError(_) => 1014,
@@ -98,8 +99,8 @@ impl From<GeocodingServiceError> for AdapterServiceError {
             GeocodingServiceError::ServerError => {
                 AdapterServiceError::ServerError(None)
             },
-            GeocodingServiceError::RateLimitExceeded => {
-                AdapterServiceError::RateLimitExceeded
+            GeocodingServiceError::RateLimitExceeded(retry_after) => {
+                AdapterServiceError::RateLimitExceeded(retry_after)
             }
         }
     }
@@ -19,7 +19,8 @@ pub struct GeocodingGenericErrorDetails {
 #[derive(Debug, Clone)]
 pub enum GeocodingServiceError {
     LocationNotFoundError(Option<String>),
-    RateLimitExceeded,
+    // Seconds until the provider accepts another call.
+    RateLimitExceeded(Option<u64>),
     ServerError,
 }
 
@@ -27,7 +28,7 @@ pub enum GeocodingServiceError {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 enum RawRemoteError {
     LocationNotFoundError(Option<String>),
-    RateLimitExceeded,
+    RateLimitExceeded(Option<u64>),
     ServerError,
     #[serde(other)]
     Unknown,
@@ -48,8 +49,8 @@ where
         RawRemoteError::ServerError => {
             Ok(GeocodingServiceError::ServerError)
         },
-        RawRemoteError::RateLimitExceeded => {
-            Ok(GeocodingServiceError::RateLimitExceeded)
+        RawRemoteError::RateLimitExceeded(retry_after) => {
+            Ok(GeocodingServiceError::RateLimitExceeded(retry_after))
         }
     }
 }
@@ -1,4 +1,13 @@
 pub mod health_check_response;
 pub mod openweather_current_weather_response;
 pub mod uniform_current_weather_response;
-pub mod geocoding_response;
\ No newline at end of file
+pub mod geocoding_response;
+pub mod openweather_forecast_response;
+pub mod uniform_forecast_response;
+pub mod openweather_historical_weather_response;
+
+pub mod openweather_alerts_response;
+pub mod uniform_weather_alerts_response;
+pub mod openweather_air_pollution_response;
+pub mod uniform_air_quality_response;
+pub mod api_key_usage_response;
\ No newline at end of file
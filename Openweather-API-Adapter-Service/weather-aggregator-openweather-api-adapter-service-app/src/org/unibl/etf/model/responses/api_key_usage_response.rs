@@ -0,0 +1,17 @@
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ApiKeyUsageResponse {
+    pub provider: String,
+    pub api_keys: Vec<ApiKeyUsage>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ApiKeyUsage {
+    pub id: String,
+    pub requests_today: u64,
+    pub requests_per_day: Option<u64>,
+    pub remaining_today: Option<u64>,
+    pub cooldown_remaining_in_seconds: Option<u64>,
+    pub retired_after_status: Option<u16>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize};
+
+#[derive(Debug, Deserialize)]
+pub struct OpenWeatherAPIAirPollutionResponse {
+    #[serde(default)]
+    pub list: Vec<AirPollutionItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AirPollutionItem {
+    #[serde(default)]
+    pub dt: Option<i64>, //measurement timestamp in unix epoch format(UTC)
+    #[serde(default)]
+    pub components: Option<Components>,
+}
+
+// All concentrations are in µg/m³.
+#[derive(Debug, Deserialize)]
+pub struct Components {
+    #[serde(default)]
+    pub co: Option<f64>,
+    #[serde(default)]
+    pub no2: Option<f64>,
+    #[serde(default)]
+    pub o3: Option<f64>,
+    #[serde(default)]
+    pub so2: Option<f64>,
+    #[serde(default)]
+    pub pm2_5: Option<f64>,
+    #[serde(default)]
+    pub pm10: Option<f64>,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize};
+
+#[derive(Debug, Deserialize)]
+pub struct OpenWeatherAPIAlertsResponse {
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    #[serde(default)]
+    pub alerts: Vec<Alert>, //omitted by the API when there are no active alerts
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Alert {
+    pub sender_name: Option<String>,
+    pub event: Option<String>,
+    pub start: Option<i64>, //unix epoch format(UTC)
+    pub end: Option<i64>, //unix epoch format(UTC)
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
@@ -39,13 +39,17 @@ pub struct Main {
     #[serde(default)]
     pub feels_like: Option<f64>,
     #[serde(default)]
+    pub temp_min: Option<f64>,
+    #[serde(default)]
+    pub temp_max: Option<f64>,
+    #[serde(default)]
     pub pressure: Option<f64>,  //pressure in mb
     #[serde(default)]
     pub humidity: Option<f64>, //relative humidity
 }
 
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct Coordinates {
     #[serde(default)]
     pub lat: Option<f64>, //latitude
@@ -59,7 +63,7 @@ impl Default for Coordinates {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct Wind {
     #[serde(default)]
     pub speed: Option<f64>, //wind speed in either kph or mph
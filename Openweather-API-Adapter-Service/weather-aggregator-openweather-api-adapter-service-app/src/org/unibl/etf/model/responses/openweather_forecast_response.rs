@@ -0,0 +1,36 @@
+use serde::{Deserialize};
+use crate::org::unibl::etf::model::responses::openweather_current_weather_response::{Coordinates, Main, Weather, Wind};
+
+#[derive(Debug, Deserialize)]
+pub struct OpenWeatherAPIForecastResponse {
+    #[serde(default)]
+    pub list: Vec<ForecastItem>,
+    #[serde(default)]
+    pub city: Option<City>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForecastItem {
+    #[serde(default)]
+    pub dt: Option<i64>, //forecasted time in unix epoch format(UTC)
+    #[serde(default)]
+    pub main: Option<Main>,
+    #[serde(default)]
+    pub weather: Vec<Weather>,
+    #[serde(default)]
+    pub wind: Option<Wind>,
+    #[serde(default)]
+    pub pop: Option<f64>, //probability of precipitation, 0 to 1
+}
+
+#[derive(Debug, Deserialize)]
+pub struct City {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub coord: Option<Coordinates>,
+    #[serde(default)]
+    pub country: Option<String>, //country name code
+    #[serde(default)]
+    pub timezone: Option<i32>, //shift in seconds from UTC
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::openweather_current_weather_response::{Main, Weather, Wind};
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{capitalize, Location, UniformCurrentWeatherResponse, Weather as UniformWeather, Wind as UniformWind};
+use crate::org::unibl::etf::util::convertors::{celsius_to_fahrenheit, degrees_to_cardinal, kph_to_mph, mb_to_inhg};
+
+#[derive(Debug, Deserialize)]
+pub struct OpenWeatherAPIHistoricalWeatherResponse {
+    #[serde(default)]
+    pub list: Vec<HistoricalItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoricalItem {
+    #[serde(default)]
+    pub dt: Option<i64>, //observation time in unix epoch format(UTC)
+    #[serde(default)]
+    pub main: Option<Main>,
+    #[serde(default)]
+    pub weather: Vec<Weather>,
+    #[serde(default)]
+    pub wind: Option<Wind>,
+}
+
+impl OpenWeatherAPIHistoricalWeatherResponse {
+    // The history API carries no location details, the caller provides them from the request.
+    pub fn into_uniform(self, location: Location) -> Result<Vec<UniformCurrentWeatherResponse>, AdapterServiceError> {
+        self.list
+            .into_iter()
+            .map(|item| {
+                let main = item.main.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty list:main field found".to_string())))?;
+                let temp = main.temp.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty list:main:temp field found".to_string())))?;
+                let wind = item.wind.unwrap_or_default();
+
+                Ok(UniformCurrentWeatherResponse {
+                    provider: "openweathermap.org".into(),
+                    location: location.clone(),
+                    weather: UniformWeather {
+                        temp_metric: temp,
+                        temp_imperial: celsius_to_fahrenheit(temp),
+                        temp_feelslike_metric: main.feels_like,
+                        temp_feelslike_imperial: main.feels_like.map(celsius_to_fahrenheit),
+                        humidity: main.humidity.map(|h| h as u8),
+                        pressure_metric: main.pressure,
+                        pressure_imperial: main.pressure.map(mb_to_inhg),
+                        condition: item.weather.first().and_then(|w| w.description.as_deref()).map(capitalize),
+                    },
+                    wind: UniformWind {
+                        speed_metric: wind.speed,
+                        speed_imperial: wind.speed.map(kph_to_mph),
+                        gust_metric: wind.gust,
+                        gust_imperial: wind.gust.map(kph_to_mph),
+                        direction: wind.deg.map(|d| degrees_to_cardinal(d).to_string()),
+                        degrees: wind.deg.map(|d| d as u16),
+                    },
+                    observed_at_timestamp: item.dt,
+                })
+            })
+            .collect()
+    }
+}
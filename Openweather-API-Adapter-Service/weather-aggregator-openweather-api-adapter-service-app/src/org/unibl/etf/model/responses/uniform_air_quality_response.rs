@@ -0,0 +1,71 @@
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_u16;
+
+use serde::{Serialize};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::openweather_air_pollution_response::OpenWeatherAPIAirPollutionResponse;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::Location;
+use crate::org::unibl::etf::util::air_quality_index::{aqi_category, compute_aqi};
+
+#[derive(Debug, Serialize)]
+pub struct UniformAirQualityResponse {
+    pub provider: String,
+    pub location: Location,
+    pub pollutants: Pollutants,
+    #[serde(serialize_with = "serialize_empty_u16")]
+    pub aqi: Option<u16>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub aqi_category: Option<String>,
+    #[serde(serialize_with = "serialize_empty_i64")]
+    pub observed_at_timestamp: Option<i64>,
+}
+
+// Concentrations in µg/m³.
+#[derive(Debug, Serialize)]
+pub struct Pollutants {
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pm2_5: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pm10: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub o3: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub no2: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub so2: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub co: Option<f64>,
+}
+
+impl OpenWeatherAPIAirPollutionResponse {
+    // The air pollution API carries only coordinates, the caller provides the location from the request.
+    pub fn into_uniform(self, location: Location) -> Result<UniformAirQualityResponse, AdapterServiceError> {
+        let item = self.list.into_iter().next().ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty list field found".to_string()))
+        )?;
+        let components = item.components.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty list:components field found".to_string()))
+        )?;
+
+        let pollutants = Pollutants {
+            pm2_5: components.pm2_5,
+            pm10: components.pm10,
+            o3: components.o3,
+            no2: components.no2,
+            so2: components.so2,
+            co: components.co,
+        };
+        let aqi = compute_aqi(&pollutants);
+
+        Ok(UniformAirQualityResponse {
+            provider: "openweathermap.org".into(),
+            location,
+            pollutants,
+            aqi,
+            aqi_category: aqi.map(|aqi| aqi_category(aqi).to_string()),
+            observed_at_timestamp: item.dt,
+        })
+    }
+}
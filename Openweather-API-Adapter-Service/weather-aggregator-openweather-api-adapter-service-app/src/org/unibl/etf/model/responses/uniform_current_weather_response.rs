@@ -8,6 +8,7 @@ use crate::org::unibl::etf::util::serializers::serialize_empty_u16;
 
 use serde::{Serialize};
 use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::geocoding_response::LocationCandidate;
 use crate::org::unibl::etf::model::responses::openweather_current_weather_response::{Coordinates, OpenWeatherAPICurrentWeatherResponse, Sys, Wind as Wind_OpenWeather};
 use crate::org::unibl::etf::util::convertors::{celsius_to_fahrenheit, degrees_to_cardinal, kph_to_mph, mb_to_inhg};
 
@@ -21,7 +22,7 @@ pub struct UniformCurrentWeatherResponse {
     pub observed_at_timestamp: Option<i64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 pub struct Location {
     #[serde(serialize_with = "serialize_empty_string")]
     pub name: Option<String>,
@@ -70,6 +71,30 @@ pub struct Weather {
     pub condition: Option<String>,
 }
 
+impl Location {
+    // Used for provider responses which carry no location details of their own.
+    pub fn from_candidate_or_coordinates(candidate: Option<LocationCandidate>, lat: f64, lon: f64) -> Self {
+        match candidate {
+            Some(cand) => Location {
+                name: Some(cand.location_name),
+                country: Some(Country::from_alpha2(&cand.country)
+                    .map(|c| c.to_string())
+                    .unwrap_or(cand.country)),
+                lat: Some(cand.lat),
+                lon: Some(cand.lon),
+                state_region_province_or_entity: Some(cand.state).filter(|s| !s.is_empty()),
+            },
+            None => Location {
+                name: None,
+                country: None,
+                lat: Some(lat),
+                lon: Some(lon),
+                state_region_province_or_entity: None,
+            },
+        }
+    }
+}
+
 impl UniformCurrentWeatherResponse {
     pub fn set_state_region_province_or_entity(&mut self, region: String) {
         self.location.state_region_province_or_entity = Some(region.clone());
@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+use celes::Country;
+use chrono::{DateTime, NaiveDate};
+use crate::org::unibl::etf::util::serializers::round_serialize;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_u8;
+
+use serde::{Serialize};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::openweather_forecast_response::{ForecastItem, OpenWeatherAPIForecastResponse};
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{capitalize, Location, Weather, Wind};
+use crate::org::unibl::etf::util::convertors::{celsius_to_fahrenheit, degrees_to_cardinal, kph_to_mph, mb_to_inhg};
+
+#[derive(Debug, Serialize)]
+pub struct UniformForecastResponse {
+    pub provider: String,
+    pub location: Location,
+    pub hourly: Vec<UniformHourlyForecast>,
+    pub daily: Vec<UniformDailyForecast>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UniformHourlyForecast {
+    pub timestamp: i64,
+    pub weather: Weather,
+    pub wind: Wind,
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UniformDailyForecast {
+    pub date: String,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_min_metric: f64,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_min_imperial: f64,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_max_metric: f64,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_max_imperial: f64,
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub humidity: Option<u8>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_metric: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_imperial: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub condition: Option<String>,
+}
+
+impl UniformForecastResponse {
+    pub fn set_state_region_province_or_entity(&mut self, region: String) {
+        self.location.state_region_province_or_entity = Some(region.clone());
+    }
+}
+
+impl TryFrom<OpenWeatherAPIForecastResponse> for UniformForecastResponse {
+    type Error = AdapterServiceError;
+
+    fn try_from(src: OpenWeatherAPIForecastResponse) -> Result<Self, Self::Error> {
+        if src.list.is_empty() {
+            return Err(AdapterServiceError::InvalidProviderResponseError(Some("Empty forecast list field found".to_string())));
+        }
+
+        let city = src.city;
+        let timezone_shift = city.as_ref().and_then(|c| c.timezone).unwrap_or(0) as i64;
+        let coordinates = city.as_ref().and_then(|c| c.coord.clone()).unwrap_or_default();
+
+        let country = match city.as_ref().and_then(|c| c.country.clone()) {
+            Some(country) => {
+                Some(Country::from_alpha2(country)
+                    .map(|c| c.to_string())
+                    .map_err(|_e| {
+                        AdapterServiceError::InvalidProviderResponseError(Some("Invalid country code.".to_string()))
+                    })?)
+            },
+            None => None,
+        };
+
+        let hourly = src.list
+            .iter()
+            .map(to_hourly_forecast)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // OpenWeather only offers 3-hour steps, days are built from them in the location's local time.
+        let mut days: BTreeMap<NaiveDate, Vec<&ForecastItem>> = BTreeMap::new();
+        for item in &src.list {
+            if let Some(local_date) = item.dt
+                .and_then(|dt| DateTime::from_timestamp(dt + timezone_shift, 0))
+                .map(|dt| dt.date_naive()) {
+                days.entry(local_date).or_default().push(item);
+            }
+        }
+
+        let daily = days
+            .into_iter()
+            .filter_map(|(date, items)| to_daily_forecast(date, &items))
+            .collect();
+
+        Ok(UniformForecastResponse {
+            provider: "openweathermap.org".into(),
+            location: Location {
+                name: city.and_then(|c| c.name),
+                country,
+                lat: coordinates.lat,
+                lon: coordinates.lon,
+                state_region_province_or_entity: None,
+            },
+            hourly,
+            daily,
+        })
+    }
+}
+
+fn to_hourly_forecast(item: &ForecastItem) -> Result<UniformHourlyForecast, AdapterServiceError> {
+    let timestamp = item.dt.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty list:dt field found".to_string())))?;
+    let main = item.main.as_ref().ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty list:main field found".to_string())))?;
+    let temp = main.temp.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty list:main:temp field found".to_string())))?;
+    let wind = item.wind.clone().unwrap_or_default();
+
+    Ok(UniformHourlyForecast {
+        timestamp,
+        weather: Weather {
+            temp_metric: temp,
+            temp_imperial: celsius_to_fahrenheit(temp),
+            temp_feelslike_metric: main.feels_like,
+            temp_feelslike_imperial: main.feels_like.map(celsius_to_fahrenheit),
+            humidity: main.humidity.map(|h| h as u8),
+            pressure_metric: main.pressure,
+            pressure_imperial: main.pressure.map(mb_to_inhg),
+            condition: item.weather.first().and_then(|w| w.description.as_deref()).map(capitalize),
+        },
+        wind: Wind {
+            speed_metric: wind.speed,
+            speed_imperial: wind.speed.map(kph_to_mph),
+            gust_metric: wind.gust,
+            gust_imperial: wind.gust.map(kph_to_mph),
+            direction: wind.deg.map(|d| degrees_to_cardinal(d).to_string()),
+            degrees: wind.deg.map(|d| d as u16),
+        },
+        precipitation_probability: item.pop.map(|p| (p * 100.0).round() as u8),
+    })
+}
+
+fn to_daily_forecast(date: NaiveDate, items: &[&ForecastItem]) -> Option<UniformDailyForecast> {
+    let mains: Vec<_> = items.iter().filter_map(|i| i.main.as_ref()).collect();
+
+    let temp_min = mains.iter()
+        .filter_map(|m| m.temp_min.or(m.temp))
+        .reduce(f64::min)?;
+    let temp_max = mains.iter()
+        .filter_map(|m| m.temp_max.or(m.temp))
+        .reduce(f64::max)?;
+
+    let humidities: Vec<f64> = mains.iter().filter_map(|m| m.humidity).collect();
+    let humidity = if humidities.is_empty() {
+        None
+    } else {
+        Some((humidities.iter().sum::<f64>() / humidities.len() as f64).round() as u8)
+    };
+
+    let wind_speed_max = items.iter()
+        .filter_map(|i| i.wind.as_ref().and_then(|w| w.speed))
+        .reduce(f64::max);
+
+    let precipitation_probability = items.iter()
+        .filter_map(|i| i.pop)
+        .reduce(f64::max)
+        .map(|p| (p * 100.0).round() as u8);
+
+    // The most frequent condition of the day, earlier slots win ties.
+    let mut conditions: Vec<(String, usize)> = Vec::new();
+    for description in items.iter().filter_map(|i| i.weather.first().and_then(|w| w.description.clone())) {
+        match conditions.iter_mut().find(|(c, _)| *c == description) {
+            Some((_, count)) => *count += 1,
+            None => conditions.push((description, 1)),
+        }
+    }
+    let condition = conditions
+        .into_iter()
+        .fold(None, |best: Option<(String, usize)>, (c, count)| match best {
+            Some((_, best_count)) if best_count >= count => best,
+            _ => Some((c, count)),
+        })
+        .map(|(c, _)| capitalize(&c));
+
+    Some(UniformDailyForecast {
+        date: date.format("%Y-%m-%d").to_string(),
+        temp_min_metric: temp_min,
+        temp_min_imperial: celsius_to_fahrenheit(temp_min),
+        temp_max_metric: temp_max,
+        temp_max_imperial: celsius_to_fahrenheit(temp_max),
+        humidity,
+        wind_speed_max_metric: wind_speed_max,
+        wind_speed_max_imperial: wind_speed_max.map(kph_to_mph),
+        precipitation_probability,
+        condition,
+    })
+}
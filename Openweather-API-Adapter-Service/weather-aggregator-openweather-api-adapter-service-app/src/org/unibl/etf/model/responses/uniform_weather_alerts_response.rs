@@ -0,0 +1,61 @@
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+
+use serde::{Serialize};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::openweather_alerts_response::OpenWeatherAPIAlertsResponse;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::Location;
+
+#[derive(Debug, Serialize)]
+pub struct UniformWeatherAlertsResponse {
+    pub provider: String,
+    pub location: Location,
+    pub alerts: Vec<WeatherAlert>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WeatherAlert {
+    pub event: String,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub severity: Option<String>,
+    pub start_timestamp: i64,
+    #[serde(serialize_with = "serialize_empty_i64")]
+    pub end_timestamp: Option<i64>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub description: Option<String>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub sender: Option<String>,
+    pub source_provider: String,
+}
+
+impl OpenWeatherAPIAlertsResponse {
+    // One Call responses carry only coordinates, the caller provides the location from the request.
+    // The API has no severity level for alerts, so it is left empty.
+    pub fn into_uniform(self, location: Location) -> Result<UniformWeatherAlertsResponse, AdapterServiceError> {
+        let provider = String::from("openweathermap.org");
+
+        let alerts = self.alerts
+            .into_iter()
+            .map(|alert| {
+                let event = alert.event.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty alerts:event field found".to_string())))?;
+                let start = alert.start.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty alerts:start field found".to_string())))?;
+
+                Ok(WeatherAlert {
+                    event,
+                    severity: None,
+                    start_timestamp: start,
+                    end_timestamp: alert.end,
+                    description: alert.description.filter(|d| !d.trim().is_empty()),
+                    sender: alert.sender_name.filter(|s| !s.trim().is_empty()),
+                    source_provider: provider.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, AdapterServiceError>>()?;
+
+        Ok(UniformWeatherAlertsResponse {
+            provider,
+            location,
+            alerts,
+        })
+    }
+}
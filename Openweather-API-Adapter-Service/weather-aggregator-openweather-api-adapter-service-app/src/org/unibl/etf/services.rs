@@ -1,2 +1,7 @@
 pub mod current_weather_service;
-pub mod geocoding_service;
\ No newline at end of file
+pub mod geocoding_service;
+pub mod forecast_service;
+pub mod historical_weather_service;
+pub mod weather_alerts_service;
+pub mod air_quality_service;
+pub mod api_key_service;
\ No newline at end of file
@@ -12,10 +12,12 @@ use crate::org::unibl::etf::model::responses::openweather_current_weather_respon
 use crate::org::unibl::etf::model::responses::uniform_current_weather_response::UniformCurrentWeatherResponse;
 use crate::org::unibl::etf::repositories::provider_repository::ProviderRepository;
 use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
 
 #[derive(Debug)]
 pub struct CurrentWeatherService {
     provider_repository: ProviderRepository,
+    api_key_service: ApiKeyService,
 }
 
 
@@ -23,29 +25,10 @@ impl CurrentWeatherService {
     fn new() -> Self {
         Self {
             provider_repository: ProviderRepository::default(),
+            api_key_service: ApiKeyService::default(),
         }
     }
 
-    #[tracing::instrument(name = "Check if ratelimit is exceeded function", skip(redis_pool))]
-    pub async fn check_if_ratelimit_is_exceeded(
-        &self,
-        max_number_of_requests_per_30_mins: u64,
-        provider_name: &str,
-        redis_pool: &deadpool_redis::Pool,
-    ) -> Result<bool, AdapterServiceError> {
-        match self.provider_repository.get_number_of_requests(provider_name, redis_pool).await{
-            Ok(number_of_requests) => {
-                tracing::info!("Successfully retrieved number of requests made for the geocoding provider");
-                Ok(number_of_requests >= max_number_of_requests_per_30_mins as i64)
-            },
-            Err(e) => {
-                tracing::info!("Was not able to get number of requests made for the geocoding provider: {}", e.get_message());
-                Err(e)
-            },
-        }
-    }
-
-
     #[tracing::instrument(name = "Get Current Weather Data by Coordinates Service", skip(client, settings))]
     pub async fn get_current_weather(
         &self,
@@ -55,24 +38,6 @@ impl CurrentWeatherService {
         redis_pool: &deadpool_redis::Pool,
         geocoding_service: &GeocodingService
     ) -> Result<UniformCurrentWeatherResponse, AdapterServiceError> {
-        match self.check_if_ratelimit_is_exceeded(
-            settings.provider.requests_per_30_mins,
-            settings.provider.name.as_str(),
-            redis_pool
-        ).await {
-            Ok(exceeded) => {
-                if exceeded {
-                    tracing::info!("Ratelimit exceeded for the geocoding provider.");
-                    return Err(AdapterServiceError::RateLimitExceeded);
-                }
-                tracing::info!("Ratelimit not exceeded for the geocoding provider.");
-            },
-            Err(e) => {
-                return Err(e);
-            }
-        }
-
-
         let candidate = if req.location_name.is_some() {
             let candidate = match geocoding_service.geocode_location(
                 req.location_name.clone().unwrap_or("".to_string()).as_str(),
@@ -99,11 +64,17 @@ impl CurrentWeatherService {
             None => (req.lat.unwrap(), req.lon.unwrap()) //validation done earlier
         };
 
+        let api_key = self.api_key_service.acquire_api_key(&settings.provider, redis_pool).await?;
+        self.provider_repository.acquire_rate_limit_slot(
+            &settings.provider.rate_limit_windows(),
+            settings.provider.name.as_str(),
+            redis_pool
+        ).await?;
         let response = client.get(format!("{}/{}", settings.provider.base_api_url, settings.provider.current_weather_endpoint).as_str())
             .query(&[
                 ("lat", &lat.to_string()),
                 ("lon", &lon.to_string()),
-                ("appid", &settings.provider.api_key.expose_secret().to_string()),
+                ("appid", &api_key.secret.expose_secret().to_string()),
                 ("units", &"metric".to_string()),
             ])
             .send()
@@ -111,18 +82,6 @@ impl CurrentWeatherService {
             .map_err(|e| AdapterServiceError::ConnectionError(Some(e.to_string())))?;
 
         if response.status().is_success() {
-            match self.provider_repository.increment_number_of_requests(
-                settings.provider.name.as_str(),
-                redis_pool,
-            ).await {
-                Ok(new_value) => {
-                    tracing::info!("Successfully incremented number of requests made. New value: {}", new_value)
-                },
-                Err(e) => {
-                    tracing::error!("Failed to increment number of requests made with error: {:?}", e.get_message())
-                }
-            }
-
             let body_text = response.text().await.map_err(|e| {
                 AdapterServiceError::ServerError(Some(format!("Failed to get successful external API response body text: {}", e)))
             })?;
@@ -159,6 +118,7 @@ impl CurrentWeatherService {
         }
         else {
             let status = response.status();
+            self.api_key_service.retire_api_key_if_rejected(status, &api_key, &settings.provider, redis_pool).await;
 
             match status {
                 StatusCode::NOT_FOUND |
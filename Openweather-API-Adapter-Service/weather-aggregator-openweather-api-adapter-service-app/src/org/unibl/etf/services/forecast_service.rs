@@ -0,0 +1,148 @@
+
+use reqwest::StatusCode;
+use reqwest_middleware::ClientWithMiddleware;
+use secrecy::ExposeSecret;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+
+use crate::org::unibl::etf::model::errors::openweather_api_error::{OpenWeatherAPIError};
+use crate::org::unibl::etf::model::errors::adapter_service_error::{AdapterServiceError};
+use crate::org::unibl::etf::model::requests::current_weather_request::CurrentWeatherRequest;
+
+use crate::org::unibl::etf::model::responses::openweather_forecast_response::OpenWeatherAPIForecastResponse;
+use crate::org::unibl::etf::model::responses::uniform_forecast_response::UniformForecastResponse;
+use crate::org::unibl::etf::repositories::provider_repository::ProviderRepository;
+use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
+
+#[derive(Debug)]
+pub struct ForecastService {
+    provider_repository: ProviderRepository,
+    api_key_service: ApiKeyService,
+}
+
+
+impl ForecastService {
+    fn new() -> Self {
+        Self {
+            provider_repository: ProviderRepository::default(),
+            api_key_service: ApiKeyService::default(),
+        }
+    }
+
+    #[tracing::instrument(name = "Get Forecast Data Service", skip(client, settings, redis_pool))]
+    pub async fn get_forecast(
+        &self,
+        req: CurrentWeatherRequest,
+        client: &ClientWithMiddleware,
+        settings: &Settings,
+        redis_pool: &deadpool_redis::Pool,
+        geocoding_service: &GeocodingService
+    ) -> Result<UniformForecastResponse, AdapterServiceError> {
+        let candidate = if req.location_name.is_some() {
+            let candidate = match geocoding_service.geocode_location(
+                req.location_name.clone().unwrap_or("".to_string()).as_str(),
+                client,
+                5,
+                &settings.geocoding_service
+            ).await {
+                Ok(candidate) => {
+                    tracing::info!("Successfully geocoded location. Result: {:?}", candidate);
+                    candidate
+                },
+                Err(e) => return {
+                    tracing::error!("Could not geocode location with error: {:?}", e);
+                    Err(e)
+                },
+            };
+            Some(candidate)
+        } else {
+            None
+        };
+
+        let (lat, lon) = match &candidate {
+            Some(cand) => (cand.lat, cand.lon),
+            None => (req.lat.unwrap(), req.lon.unwrap()) //validation done earlier
+        };
+
+        let api_key = self.api_key_service.acquire_api_key(&settings.provider, redis_pool).await?;
+        self.provider_repository.acquire_rate_limit_slot(
+            &settings.provider.rate_limit_windows(),
+            settings.provider.name.as_str(),
+            redis_pool
+        ).await?;
+        let response = client.get(format!("{}/{}", settings.provider.base_api_url, settings.provider.forecast_endpoint).as_str())
+            .query(&[
+                ("lat", &lat.to_string()),
+                ("lon", &lon.to_string()),
+                ("appid", &api_key.secret.expose_secret().to_string()),
+                ("units", &"metric".to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| AdapterServiceError::ConnectionError(Some(e.to_string())))?;
+
+        if response.status().is_success() {
+            let body_text = response.text().await.map_err(|e| {
+                AdapterServiceError::ServerError(Some(format!("Failed to get successful external API response body text: {}", e)))
+            })?;
+
+            let data: OpenWeatherAPIForecastResponse = serde_json::from_str(&body_text)
+                .map_err(|e| {
+                    // This 'e' will now contain the EXACT field and line number
+                    AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                        "Error while parsing successful external API response body text. JSON Error: {} | Raw Body: {}",
+                        e, body_text
+                    )))
+                })?;
+
+
+
+            let response = UniformForecastResponse::try_from(data)
+                .map(|mut forecast_data| {
+                    if let Some(cand) = candidate {
+                        forecast_data.set_state_region_province_or_entity(cand.state.clone());
+                    }
+                    forecast_data
+                })
+                .inspect_err(|e| {
+                    tracing::error!("Was not able to get transform forecast data to uniform format with error: {:?}", e.get_message());
+                })?;
+
+            Ok(response)
+        }
+        else {
+            let status = response.status();
+            self.api_key_service.retire_api_key_if_rejected(status, &api_key, &settings.provider, redis_pool).await;
+
+            match status {
+                StatusCode::NOT_FOUND |
+                StatusCode::UNAUTHORIZED |
+                StatusCode::TOO_MANY_REQUESTS |
+                StatusCode::BAD_REQUEST => {
+                    let error_body_text = response.text().await.map_err(|e| {
+                        AdapterServiceError::ServerError(Some(format!("Failed to get external API error response  body text: {}", e)))
+                    })?;
+
+                    let error_body: OpenWeatherAPIError = serde_json::from_str(&error_body_text)
+                        .map_err(|e| {
+                            AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                                "Error while parsing external API error response body text. JSON Error: {} | Raw Body: {}",
+                                e, error_body_text
+                            )))
+                        })?;
+                    tracing::error!("Error while calling External API: {:?}", error_body);
+                    return Err(AdapterServiceError::OpenWeatherAPIError(error_body.cod, Some(error_body.message)));
+                },
+                _ => {
+                    return Err(AdapterServiceError::OpenWeatherAPIError(status.as_u16(), None));
+                }
+            }
+        }
+    }
+}
+
+impl Default for ForecastService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+
+use reqwest::StatusCode;
+use reqwest_middleware::ClientWithMiddleware;
+use secrecy::ExposeSecret;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+
+use crate::org::unibl::etf::model::errors::openweather_api_error::{OpenWeatherAPIError};
+use crate::org::unibl::etf::model::errors::adapter_service_error::{AdapterServiceError};
+use crate::org::unibl::etf::model::requests::current_weather_request::CurrentWeatherRequest;
+
+use crate::org::unibl::etf::model::requests::date_range_request::DateRangeRequest;
+use crate::org::unibl::etf::model::responses::openweather_historical_weather_response::OpenWeatherAPIHistoricalWeatherResponse;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{Location, UniformCurrentWeatherResponse};
+use crate::org::unibl::etf::repositories::provider_repository::ProviderRepository;
+use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
+
+#[derive(Debug)]
+pub struct HistoricalWeatherService {
+    provider_repository: ProviderRepository,
+    api_key_service: ApiKeyService,
+}
+
+
+impl HistoricalWeatherService {
+    fn new() -> Self {
+        Self {
+            provider_repository: ProviderRepository::default(),
+            api_key_service: ApiKeyService::default(),
+        }
+    }
+
+    #[tracing::instrument(name = "Get Historical Weather Data Service", skip(client, settings, redis_pool))]
+    pub async fn get_historical_weather(
+        &self,
+        req: CurrentWeatherRequest,
+        date_range: DateRangeRequest,
+        client: &ClientWithMiddleware,
+        settings: &Settings,
+        redis_pool: &deadpool_redis::Pool,
+        geocoding_service: &GeocodingService
+    ) -> Result<Vec<UniformCurrentWeatherResponse>, AdapterServiceError> {
+        let candidate = if req.location_name.is_some() {
+            let candidate = match geocoding_service.geocode_location(
+                req.location_name.clone().unwrap_or("".to_string()).as_str(),
+                client,
+                5,
+                &settings.geocoding_service
+            ).await {
+                Ok(candidate) => {
+                    tracing::info!("Successfully geocoded location. Result: {:?}", candidate);
+                    candidate
+                },
+                Err(e) => return {
+                    tracing::error!("Could not geocode location with error: {:?}", e);
+                    Err(e)
+                },
+            };
+            Some(candidate)
+        } else {
+            None
+        };
+
+        let (lat, lon) = match &candidate {
+            Some(cand) => (cand.lat, cand.lon),
+            None => (req.lat.unwrap(), req.lon.unwrap()) //validation done earlier
+        };
+
+        let start = date_range.start_date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc().timestamp();
+        let end = date_range.end_date.and_hms_opt(23, 59, 59).unwrap_or_default().and_utc().timestamp();
+
+        let api_key = self.api_key_service.acquire_api_key(&settings.provider, redis_pool).await?;
+        self.provider_repository.acquire_rate_limit_slot(
+            &settings.provider.rate_limit_windows(),
+            settings.provider.name.as_str(),
+            redis_pool
+        ).await?;
+        let response = client.get(format!("{}/{}", settings.provider.historical_base_api_url, settings.provider.historical_weather_endpoint).as_str())
+            .query(&[
+                ("lat", &lat.to_string()),
+                ("lon", &lon.to_string()),
+                ("type", &"hour".to_string()),
+                ("start", &start.to_string()),
+                ("end", &end.to_string()),
+                ("appid", &api_key.secret.expose_secret().to_string()),
+                ("units", &"metric".to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| AdapterServiceError::ConnectionError(Some(e.to_string())))?;
+
+        if response.status().is_success() {
+            let body_text = response.text().await.map_err(|e| {
+                AdapterServiceError::ServerError(Some(format!("Failed to get successful external API response body text: {}", e)))
+            })?;
+
+            let data: OpenWeatherAPIHistoricalWeatherResponse = serde_json::from_str(&body_text)
+                .map_err(|e| {
+                    AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                        "Error while parsing successful external API response body text. JSON Error: {} | Raw Body: {}",
+                        e, body_text
+                    )))
+                })?;
+
+            let location = Location::from_candidate_or_coordinates(candidate, lat, lon);
+
+            let response = data.into_uniform(location)
+                .inspect_err(|e| {
+                    tracing::error!("Was not able to get transform historical weather data to uniform format with error: {:?}", e.get_message());
+                })?;
+
+            Ok(response)
+        }
+        else {
+            let status = response.status();
+            self.api_key_service.retire_api_key_if_rejected(status, &api_key, &settings.provider, redis_pool).await;
+
+            match status {
+                StatusCode::NOT_FOUND |
+                StatusCode::UNAUTHORIZED |
+                StatusCode::TOO_MANY_REQUESTS |
+                StatusCode::BAD_REQUEST => {
+                    let error_body_text = response.text().await.map_err(|e| {
+                        AdapterServiceError::ServerError(Some(format!("Failed to get external API error response  body text: {}", e)))
+                    })?;
+
+                    let error_body: OpenWeatherAPIError = serde_json::from_str(&error_body_text)
+                        .map_err(|e| {
+                            AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                                "Error while parsing external API error response body text. JSON Error: {} | Raw Body: {}",
+                                e, error_body_text
+                            )))
+                        })?;
+                    tracing::error!("Error while calling External API: {:?}", error_body);
+                    return Err(AdapterServiceError::OpenWeatherAPIError(error_body.cod, Some(error_body.message)));
+                },
+                _ => {
+                    return Err(AdapterServiceError::OpenWeatherAPIError(status.as_u16(), None));
+                }
+            }
+        }
+    }
+}
+
+impl Default for HistoricalWeatherService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
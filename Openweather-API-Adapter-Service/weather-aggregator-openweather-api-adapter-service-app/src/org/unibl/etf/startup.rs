@@ -8,10 +8,15 @@ use reqwest_tracing::TracingMiddleware;
 use rustls::ServerConfig;
 use tracing_actix_web::TracingLogger;
 use crate::org::unibl::etf::configuration::settings::{HttpClientTlsIdentityBundle, Settings};
-use crate::org::unibl::etf::controllers::current_weather_controller;
+use crate::org::unibl::etf::controllers::{current_weather_controller, forecast_controller, historical_weather_controller, weather_alerts_controller, air_quality_controller, api_key_controller};
 use crate::org::unibl::etf::handlers::query_error_handler;
 use crate::org::unibl::etf::model::responses::health_check_response::HealthCheckResponse;
 use crate::org::unibl::etf::services::current_weather_service::CurrentWeatherService;
+use crate::org::unibl::etf::services::forecast_service::ForecastService;
+use crate::org::unibl::etf::services::historical_weather_service::HistoricalWeatherService;
+use crate::org::unibl::etf::services::weather_alerts_service::WeatherAlertsService;
+use crate::org::unibl::etf::services::air_quality_service::AirQualityService;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
 use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
 
 async fn health_check() -> impl Responder {
@@ -46,6 +51,16 @@ pub fn run(
     let redis_pool = web::Data::new(redis_pool);
     let current_weather_service =
         web::Data::new(CurrentWeatherService::default());
+    let forecast_service =
+        web::Data::new(ForecastService::default());
+    let historical_weather_service =
+        web::Data::new(HistoricalWeatherService::default());
+    let weather_alerts_service =
+        web::Data::new(WeatherAlertsService::default());
+    let air_quality_service =
+        web::Data::new(AirQualityService::default());
+    let api_key_service =
+        web::Data::new(ApiKeyService::default());
     let settings =
         web::Data::new(settings);
     let geocoding_service =
@@ -56,6 +71,11 @@ pub fn run(
             .app_data(http_client.clone())
             .app_data(geocoding_service.clone())
             .app_data(current_weather_service.clone())
+            .app_data(forecast_service.clone())
+            .app_data(historical_weather_service.clone())
+            .app_data(weather_alerts_service.clone())
+            .app_data(air_quality_service.clone())
+            .app_data(api_key_service.clone())
             .app_data(settings.clone())
             .app_data(redis_pool.clone())
             .wrap(TracingLogger::default())
@@ -65,6 +85,11 @@ pub fn run(
             .service(
                 web::scope("/api/v1")
                     .configure(current_weather_controller::routes)
+                    .configure(forecast_controller::routes)
+                    .configure(historical_weather_controller::routes)
+                    .configure(weather_alerts_controller::routes)
+                    .configure(air_quality_controller::routes)
+                    .configure(api_key_controller::routes)
             )
             .route("/health_check", web::get().to(health_check))
     });
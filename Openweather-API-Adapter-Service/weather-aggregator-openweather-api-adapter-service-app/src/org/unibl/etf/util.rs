@@ -1,2 +1,3 @@
 pub mod serializers;
-pub mod convertors;
\ No newline at end of file
+pub mod convertors;
+pub mod air_quality_index;
\ No newline at end of file
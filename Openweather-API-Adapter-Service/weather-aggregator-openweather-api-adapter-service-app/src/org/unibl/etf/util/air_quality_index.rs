@@ -0,0 +1,110 @@
+use crate::org::unibl::etf::model::responses::uniform_air_quality_response::Pollutants;
+
+// US EPA breakpoints: (concentration low, concentration high, index low, index high).
+// Particulates are in µg/m³, O3 and CO in ppm, NO2 and SO2 in ppb.
+const PM2_5_BREAKPOINTS: [(f64, f64, f64, f64); 6] = [
+    (0.0, 9.0, 0.0, 50.0),
+    (9.1, 35.4, 51.0, 100.0),
+    (35.5, 55.4, 101.0, 150.0),
+    (55.5, 125.4, 151.0, 200.0),
+    (125.5, 225.4, 201.0, 300.0),
+    (225.5, 325.4, 301.0, 500.0),
+];
+
+const PM10_BREAKPOINTS: [(f64, f64, f64, f64); 6] = [
+    (0.0, 54.0, 0.0, 50.0),
+    (55.0, 154.0, 51.0, 100.0),
+    (155.0, 254.0, 101.0, 150.0),
+    (255.0, 354.0, 151.0, 200.0),
+    (355.0, 424.0, 201.0, 300.0),
+    (425.0, 604.0, 301.0, 500.0),
+];
+
+const O3_BREAKPOINTS: [(f64, f64, f64, f64); 5] = [
+    (0.0, 0.054, 0.0, 50.0),
+    (0.055, 0.070, 51.0, 100.0),
+    (0.071, 0.085, 101.0, 150.0),
+    (0.086, 0.105, 151.0, 200.0),
+    (0.106, 0.200, 201.0, 300.0),
+];
+
+const CO_BREAKPOINTS: [(f64, f64, f64, f64); 6] = [
+    (0.0, 4.4, 0.0, 50.0),
+    (4.5, 9.4, 51.0, 100.0),
+    (9.5, 12.4, 101.0, 150.0),
+    (12.5, 15.4, 151.0, 200.0),
+    (15.5, 30.4, 201.0, 300.0),
+    (30.5, 50.4, 301.0, 500.0),
+];
+
+const SO2_BREAKPOINTS: [(f64, f64, f64, f64); 6] = [
+    (0.0, 35.0, 0.0, 50.0),
+    (36.0, 75.0, 51.0, 100.0),
+    (76.0, 185.0, 101.0, 150.0),
+    (186.0, 304.0, 151.0, 200.0),
+    (305.0, 604.0, 201.0, 300.0),
+    (605.0, 1004.0, 301.0, 500.0),
+];
+
+const NO2_BREAKPOINTS: [(f64, f64, f64, f64); 6] = [
+    (0.0, 53.0, 0.0, 50.0),
+    (54.0, 100.0, 51.0, 100.0),
+    (101.0, 360.0, 101.0, 150.0),
+    (361.0, 649.0, 151.0, 200.0),
+    (650.0, 1249.0, 201.0, 300.0),
+    (1250.0, 2049.0, 301.0, 500.0),
+];
+
+// Molar volume of an ideal gas at 25 °C and 1 atm, in litres.
+const MOLAR_VOLUME: f64 = 24.45;
+
+fn micrograms_to_ppb(concentration: f64, molecular_weight: f64) -> f64 {
+    concentration * MOLAR_VOLUME / molecular_weight
+}
+
+fn truncate(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).floor() / factor
+}
+
+fn sub_index(concentration: f64, breakpoints: &[(f64, f64, f64, f64)]) -> f64 {
+    for &(c_low, c_high, i_low, i_high) in breakpoints {
+        if concentration <= c_high {
+            let c_low = c_low.min(concentration);
+            return (i_high - i_low) / (c_high - c_low) * (concentration - c_low) + i_low;
+        }
+    }
+
+    // Beyond the scale.
+    500.0
+}
+
+/// US EPA air quality index, the highest of the pollutant sub-indices.
+/// Instant concentrations stand in for the averaging periods the EPA prescribes.
+pub fn compute_aqi(pollutants: &Pollutants) -> Option<u16> {
+    let sub_indices = [
+        pollutants.pm2_5.map(|c| sub_index(truncate(c, 1), &PM2_5_BREAKPOINTS)),
+        pollutants.pm10.map(|c| sub_index(truncate(c, 0), &PM10_BREAKPOINTS)),
+        pollutants.o3.map(|c| sub_index(truncate(micrograms_to_ppb(c, 48.00) / 1000.0, 3), &O3_BREAKPOINTS)),
+        pollutants.co.map(|c| sub_index(truncate(micrograms_to_ppb(c, 28.01) / 1000.0, 1), &CO_BREAKPOINTS)),
+        pollutants.so2.map(|c| sub_index(truncate(micrograms_to_ppb(c, 64.07), 0), &SO2_BREAKPOINTS)),
+        pollutants.no2.map(|c| sub_index(truncate(micrograms_to_ppb(c, 46.01), 0), &NO2_BREAKPOINTS)),
+    ];
+
+    sub_indices
+        .into_iter()
+        .flatten()
+        .reduce(f64::max)
+        .map(|aqi| aqi.round().clamp(0.0, 500.0) as u16)
+}
+
+pub fn aqi_category(aqi: u16) -> &'static str {
+    match aqi {
+        0..=50 => "Good",
+        51..=100 => "Moderate",
+        101..=150 => "Unhealthy for Sensitive Groups",
+        151..=200 => "Unhealthy",
+        201..=300 => "Very Unhealthy",
+        _ => "Hazardous",
+    }
+}
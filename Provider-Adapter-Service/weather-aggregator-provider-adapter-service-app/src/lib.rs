@@ -0,0 +1 @@
+pub mod org;
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod unibl;
\ No newline at end of file
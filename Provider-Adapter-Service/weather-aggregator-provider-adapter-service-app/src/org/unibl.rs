@@ -0,0 +1 @@
+pub mod etf;
\ No newline at end of file
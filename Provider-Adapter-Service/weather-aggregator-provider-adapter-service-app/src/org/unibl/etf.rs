@@ -0,0 +1,12 @@
+pub mod startup;
+pub mod model;
+pub mod services;
+pub mod configuration;
+pub mod controllers;
+
+pub mod util;
+pub mod handlers;
+pub mod telemetry;
+pub mod external_dependency_systems;
+pub mod repositories;
+pub mod providers;
@@ -0,0 +1,278 @@
+use opentelemetry_otlp::tonic_types::transport::{Certificate, ClientTlsConfig, Identity};
+use serde::Deserialize;
+use serde_aux::field_attributes::{deserialize_number_from_string, deserialize_option_number_from_string};
+use serde_aux::prelude::deserialize_bool_from_anything;
+use std::{fs, io};
+use std::io::BufReader;
+use std::sync::Arc;
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use secrecy::{ExposeSecret, SecretBox};
+
+#[derive(Deserialize, Debug)]
+pub struct Settings {
+    pub application: ApplicationSettings,
+    pub geocoding_service: GeocodingServiceSettings,
+    pub providers: Vec<ProviderSettings>,
+    pub tracing_agent: TracingSettings,
+    pub redis_store: RedisStoreSettings,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ApplicationSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+    pub host: String,
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub tls_enabled: bool,
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub require_mtls: bool,
+    pub cert_file_path: String,
+    pub private_key_file_path: String,
+    pub pkcs_file_path: String,
+    pub pkcs_export_password: SecretBox<String>,
+    pub ca_cert_file_path: String,
+
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GeocodingServiceSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+    pub host: String,
+    pub scheme: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderKind {
+    OpenWeather,
+    WeatherApi,
+    OpenMeteo,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ProviderSettings {
+    pub name: String,
+    pub kind: ProviderKind,
+    pub base_api_url: String,
+    pub current_weather_endpoint: String,
+    #[serde(default)]
+    pub api_key: Option<SecretBox<String>>,
+    #[serde(default)]
+    pub api_keys: Vec<ApiKeySettings>,
+    #[serde(default = "default_api_key_cooldown_in_seconds", deserialize_with = "deserialize_number_from_string")]
+    pub api_key_cooldown_in_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub requests_per_30_mins: u64,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_minute: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_day: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitWindow {
+    pub window_in_seconds: u64,
+    pub max_requests: u64,
+}
+
+fn default_api_key_cooldown_in_seconds() -> u64 {
+    900
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ApiKeySettings {
+    // Reported in usage instead of the key itself.
+    pub id: String,
+    pub key: SecretBox<String>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_day: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PooledApiKey<'a> {
+    pub id: &'a str,
+    pub secret: &'a SecretBox<String>,
+    pub requests_per_day: Option<u64>,
+}
+
+impl ProviderSettings {
+    // A call is only made when every configured window has room for it.
+    pub fn rate_limit_windows(&self) -> Vec<RateLimitWindow> {
+        [
+            (60, self.requests_per_minute),
+            (1800, Some(self.requests_per_30_mins)),
+            (86400, self.requests_per_day),
+        ]
+            .into_iter()
+            .filter_map(|(window_in_seconds, max_requests)| {
+                max_requests.map(|max_requests| RateLimitWindow { window_in_seconds, max_requests })
+            })
+            .collect()
+    }
+
+    // A lone api_key joins the pool as an unmetered key, so older configurations keep working.
+    pub fn api_key_pool(&self) -> Vec<PooledApiKey<'_>> {
+        self.api_key
+            .iter()
+            .map(|secret| PooledApiKey { id: "default", secret, requests_per_day: None })
+            .chain(self.api_keys.iter().map(|api_key| PooledApiKey {
+                id: api_key.id.as_str(),
+                secret: &api_key.key,
+                requests_per_day: api_key.requests_per_day,
+            }))
+            .collect()
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct RedisStoreSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+    pub host: String,
+    pub scheme: String,
+    pub username: String,
+    pub user_password: SecretBox<String>
+}
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct TracingSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub port: u16,
+    pub host: String,
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub require_tls: bool,
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    pub mtls_enabled: bool,
+    pub client_certificate_pem_file_path: String,
+    pub ca_certificate_pem_file_path: String,
+    pub client_certificate_pem_key_path: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_export_batch_size: u16,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_queue_size: u16,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub scheduled_delay_in_ms: u16,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_concurrent_exports: u16,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub timeout_in_ms: u16,
+    pub domain_name: String,
+    pub scheme: String,
+}
+
+impl TracingSettings {
+    pub fn get_tls_config(&self) -> Result<Option<ClientTlsConfig>, io::Error> {
+        let mut client_tls_config = ClientTlsConfig::new();
+
+        if self.require_tls {
+            let ca_pem_bytes = fs::read_to_string(&self.ca_certificate_pem_file_path)?;
+            let ca_certificate = Certificate::from_pem(ca_pem_bytes);
+
+            client_tls_config = client_tls_config.domain_name(&self.domain_name);
+
+            client_tls_config = client_tls_config
+                .ca_certificate(ca_certificate);
+
+            if self.mtls_enabled {
+                let identity = Identity::from_pem(
+                    fs::read(&self.client_certificate_pem_file_path)?,
+                    fs::read(&self.client_certificate_pem_key_path)?
+                );
+
+                client_tls_config = client_tls_config.identity(
+                    identity
+                );
+
+            }
+            return Ok(Some(client_tls_config));
+        }
+
+        Ok(None)
+    }
+}
+
+impl RedisStoreSettings {
+    pub fn get_redis_config(&self) -> Result<String, io::Error> {
+        let connection_uri = format!("{}://{}:{}@{}:{}",
+                                     self.scheme, &self.username,
+                                     &self.user_password.expose_secret(),
+                                     &self.host, &self.port);
+        Ok(connection_uri)
+    }
+}
+
+impl ApplicationSettings {
+    pub fn get_http_server_tls_config(&self) -> Result<Option<ServerConfig>, io::Error> {
+
+        if !self.tls_enabled {
+            return Ok(None);
+        }
+
+        let server_cert_file =
+            &mut BufReader::new(fs::File::open(self.cert_file_path.clone()).unwrap());
+        let server_key_file =
+            &mut BufReader::new(fs::File::open(self.private_key_file_path.clone()).unwrap());
+
+        let cert_chain = rustls_pemfile::certs(server_cert_file)
+            .collect::<Result<Vec<_>, _>>().unwrap();
+        let keys = rustls_pemfile::pkcs8_private_keys(server_key_file)
+            .next().unwrap().unwrap();
+
+        if !self.require_mtls {
+            return Ok(Some(ServerConfig::builder()
+                .with_no_client_auth()
+                .with_single_cert(cert_chain, keys.into())
+                .unwrap()
+            ));
+        }
+
+        let mut roots = RootCertStore::empty();
+        let cert_file = &mut BufReader::new(fs::File::open(self.ca_cert_file_path.clone())?);
+
+        for cert in rustls_pemfile::certs(cert_file) {
+            roots.add(cert.unwrap()).unwrap();
+        }
+
+        let client_verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+            .build()
+            .unwrap();
+
+        let config = ServerConfig::builder()
+            .with_client_cert_verifier(client_verifier) // Enforce client auth
+            .with_single_cert(cert_chain, keys.into())
+            .unwrap();
+
+        Ok(Some(config))
+    }
+
+    pub fn get_http_client_tls_config(&self) -> Result<HttpClientTlsIdentityBundle, io::Error> {
+
+        let der = fs::read(self.pkcs_file_path.clone())?;
+        let password = &self.pkcs_export_password;
+
+        let identity = reqwest::tls::Identity::from_pkcs12_der(&der, password.expose_secret().clone().as_str());
+        let identity = match identity {
+            Ok(identity) => identity,
+            Err(e) => return Err(io::Error::other(e)),
+        };
+
+        let ca_cert = fs::read(self.ca_cert_file_path.clone())?;
+        let ca_certificate = reqwest::tls::Certificate::from_pem(&ca_cert);
+        let ca_certificate = match ca_certificate {
+            Ok(ca_certificate) => ca_certificate,
+            Err(e) => return Err(io::Error::other(e)),
+        };
+
+        Ok(HttpClientTlsIdentityBundle {
+            identity,
+            ca_certificate,
+        })
+    }
+}
+
+pub struct HttpClientTlsIdentityBundle {
+    pub identity: reqwest::Identity,
+    pub ca_certificate: reqwest::Certificate,
+}
\ No newline at end of file
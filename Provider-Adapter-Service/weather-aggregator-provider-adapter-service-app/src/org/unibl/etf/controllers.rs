@@ -0,0 +1,3 @@
+pub mod current_weather_controller;
+pub mod api_key_controller;
+pub mod errors;
\ No newline at end of file
@@ -0,0 +1,42 @@
+use actix_web::{web, HttpResponse, Responder};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::providers::provider_registry::ProviderRegistry;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/{provider}/api_key_usage").route(web::get().to(get_api_key_usage)));
+}
+
+#[tracing::instrument(name = "Get API Key Usage Controller",
+    skip(api_key_service, provider_registry, redis_pool))]
+async fn get_api_key_usage(
+    path: web::Path<String>,
+    api_key_service: web::Data<ApiKeyService>,
+    provider_registry: web::Data<ProviderRegistry>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let provider_name = path.into_inner();
+
+    let provider = provider_registry.get(&provider_name).ok_or_else(|| {
+        tracing::error!("Provider {} is not configured", provider_name);
+        GenericServiceError {
+            error: GenericServiceErrorDetails::new_adapter_error(
+                &provider_name,
+                AdapterServiceError::UnknownProviderError(provider_name.clone())
+            )
+        }
+    })?;
+
+    let res = api_key_service
+        .get_api_key_usage(provider.settings(), redis_pool.get_ref())
+        .await
+        .map_err(|e| {
+            tracing::error!("Was not able to get API key usage with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(provider.name(), e)
+            }
+        })?;
+
+    Ok(HttpResponse::Ok().json(res))
+}
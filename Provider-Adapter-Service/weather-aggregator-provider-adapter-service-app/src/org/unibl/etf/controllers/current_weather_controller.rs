@@ -0,0 +1,58 @@
+use actix_web::{web, HttpResponse, Responder};
+
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::requests::current_weather_request::{CurrentWeatherRequest};
+use crate::org::unibl::etf::providers::provider_registry::ProviderRegistry;
+use crate::org::unibl::etf::services::current_weather_service::CurrentWeatherService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/{provider}/current_weather").route(web::get().to(get_current_weather_data)));
+}
+
+#[tracing::instrument(name = "Get Current Weather Data Controller",
+    skip(http_client, current_weather_service, provider_registry, settings, redis_pool))]
+async fn get_current_weather_data(
+    path: web::Path<String>,
+    current_weather_service: web::Data<CurrentWeatherService>,
+    provider_registry: web::Data<ProviderRegistry>,
+    query: Query<CurrentWeatherRequest>,
+    http_client: web::Data<ClientWithMiddleware>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let provider_name = path.into_inner();
+
+    let provider = provider_registry.get(&provider_name).ok_or_else(|| {
+        tracing::error!("Provider {} is not configured", provider_name);
+        GenericServiceError {
+            error: GenericServiceErrorDetails::new_adapter_error(
+                &provider_name,
+                AdapterServiceError::UnknownProviderError(provider_name.clone())
+            )
+        }
+    })?;
+
+    current_weather_service
+        .get_current_weather(
+            query.into_inner(),
+            provider,
+            http_client.get_ref(),
+            redis_pool.get_ref(),
+            &settings.geocoding_service
+        )
+        .await
+        .map(|weather_data| {
+            tracing::info!("Successfully got current weather data from external API: {:?}", weather_data);
+            HttpResponse::Ok().json(weather_data)
+        })
+        .map_err(|e| {
+            tracing::error!("Was not able to get current weather data with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(provider.name(), e)
+            }
+        })
+}
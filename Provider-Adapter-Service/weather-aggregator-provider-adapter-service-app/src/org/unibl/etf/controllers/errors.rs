@@ -0,0 +1 @@
+pub mod generic_service_error;
\ No newline at end of file
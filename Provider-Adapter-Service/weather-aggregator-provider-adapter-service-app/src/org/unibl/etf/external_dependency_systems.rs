@@ -0,0 +1 @@
+pub mod redis_store;
\ No newline at end of file
@@ -0,0 +1,12 @@
+use deadpool_redis::{Config, Runtime};
+
+
+pub fn create_redis_pool(connection_uri: String) -> deadpool_redis::Pool {
+
+    let cfg = Config::from_url(connection_uri);
+
+
+    cfg.create_pool(Some(Runtime::Tokio1))
+        .expect("Failed to create Redis pool")
+
+}
@@ -0,0 +1 @@
+pub mod query_error_handler;
\ No newline at end of file
@@ -0,0 +1,23 @@
+use actix_web::{HttpRequest, HttpResponse};
+use actix_web_validator::Error;
+use chrono::Utc;
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+
+pub fn handle_validation_error(err: Error, req: &HttpRequest) -> actix_web::Error {
+    let resp = GenericServiceError {
+        error: GenericServiceErrorDetails {
+            code: AdapterServiceError::RequestParametersValidationError(Some(err.to_string())),
+            code_numeric: 400,
+            message: "Validation of provided query parameters failed.".to_string(),
+            timestamp: Utc::now(),
+            provider: req.match_info().get("provider").unwrap_or_default().to_string(),
+        }
+    };
+
+    actix_web::error::InternalError::from_response(
+        err,
+        HttpResponse::BadRequest().json(resp),
+    )
+        .into()
+}
\ No newline at end of file
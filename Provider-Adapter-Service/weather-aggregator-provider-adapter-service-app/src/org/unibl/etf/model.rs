@@ -0,0 +1,3 @@
+pub mod responses;
+pub mod errors;
+pub mod requests;
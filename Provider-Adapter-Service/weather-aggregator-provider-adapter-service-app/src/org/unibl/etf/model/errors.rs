@@ -0,0 +1,2 @@
+pub mod adapter_service_error;
+pub mod geocoding_error;
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod current_weather_request;
\ No newline at end of file
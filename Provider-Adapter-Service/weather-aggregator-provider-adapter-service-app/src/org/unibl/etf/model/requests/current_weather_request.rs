@@ -0,0 +1,64 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize)]
+pub struct CurrentWeatherRequestRaw {
+    pub location_name: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub ip_address: Option<String>
+
+}
+
+#[derive(Deserialize, Validate, Debug)]
+#[serde(try_from = "CurrentWeatherRequestRaw")]
+pub struct CurrentWeatherRequest {
+    pub location_name: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub ip_address: Option<String>
+
+}
+
+impl TryFrom<CurrentWeatherRequestRaw> for CurrentWeatherRequest {
+    type Error = String;
+
+    fn try_from(raw: CurrentWeatherRequestRaw) -> Result<Self, Self::Error> {
+        if (raw.location_name.is_none() || raw.location_name.clone().unwrap().is_empty())
+            && (raw.lat.is_none() || raw.lon.is_none())
+            && raw.ip_address.is_none() {
+            return Err(String::from("Location name, coordinates (latitude and longitude) or ip address should be provided"));
+        }
+
+        let lat_validated = match raw.lat {
+            Some(lat) => {
+                (-90.0..=90.0).contains(&lat)
+            },
+            None => {
+                true
+            },
+        };
+        let lon_validated = match raw.lon {
+            Some(lon) => {
+                (-180.0..=180.0).contains(&lon)
+            },
+            None => {
+                true
+            },
+        };
+
+        if lon_validated && lat_validated {
+            Ok(CurrentWeatherRequest {
+                lat: raw.lat,
+                lon: raw.lon,
+                location_name: raw.location_name,
+                ip_address: raw.ip_address
+            })
+        } else if !lat_validated {
+            Err(format!("Invalid latitude: {}", raw.lat.ok_or("")?))
+        } else {
+            Err(format!("Invalid longitude: {}", raw.lon.ok_or("")?))
+        }
+
+    }
+}
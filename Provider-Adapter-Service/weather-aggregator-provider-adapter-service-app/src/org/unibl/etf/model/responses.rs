@@ -0,0 +1,4 @@
+pub mod health_check_response;
+pub mod uniform_current_weather_response;
+pub mod geocoding_response;
+pub mod api_key_usage_response;
\ No newline at end of file
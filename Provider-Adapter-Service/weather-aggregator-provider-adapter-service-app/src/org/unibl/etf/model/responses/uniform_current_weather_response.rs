@@ -0,0 +1,107 @@
+use celes::Country;
+use crate::org::unibl::etf::util::serializers::round_serialize;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_u8;
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_u16;
+
+use serde::{Serialize};
+use crate::org::unibl::etf::model::responses::geocoding_response::LocationCandidate;
+
+#[derive(Debug, Serialize)]
+pub struct UniformCurrentWeatherResponse {
+    pub provider: String,
+    pub location: Location,
+    pub weather: Weather,
+    pub wind: Wind,
+    #[serde(serialize_with = "serialize_empty_i64")]
+    pub observed_at_timestamp: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Location {
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub name: Option<String>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub country: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub state_region_province_or_entity: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Wind {
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub speed_metric: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub speed_imperial: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub gust_metric: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub gust_imperial: Option<f64>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub direction: Option<String>,
+    #[serde(serialize_with = "serialize_empty_u16")]
+    pub degrees: Option<u16>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Weather {
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_metric: f64,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_imperial: f64,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub temp_feelslike_metric: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub temp_feelslike_imperial: Option<f64>,
+
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub humidity: Option<u8>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pressure_metric: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pressure_imperial: Option<f64>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub condition: Option<String>,
+}
+
+impl Location {
+    // Used for provider responses which carry no location details of their own.
+    pub fn from_candidate_or_coordinates(candidate: Option<LocationCandidate>, lat: f64, lon: f64) -> Self {
+        match candidate {
+            Some(cand) => Location {
+                name: Some(cand.location_name),
+                country: Some(Country::from_alpha2(&cand.country)
+                    .map(|c| c.to_string())
+                    .unwrap_or(cand.country)),
+                lat: Some(cand.lat),
+                lon: Some(cand.lon),
+                state_region_province_or_entity: Some(cand.state).filter(|s| !s.is_empty()),
+            },
+            None => Location {
+                name: None,
+                country: None,
+                lat: Some(lat),
+                lon: Some(lon),
+                state_region_province_or_entity: None,
+            },
+        }
+    }
+}
+
+impl UniformCurrentWeatherResponse {
+    pub fn set_state_region_province_or_entity(&mut self, region: String) {
+        self.location.state_region_province_or_entity = Some(region.clone());
+    }
+}
+
+pub fn capitalize(s: &str) -> String {
+    let mut c = s.chars();
+    match c.next() {
+        None => String::new(),
+        Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+    }
+}
@@ -0,0 +1,5 @@
+pub mod weather_provider;
+pub mod provider_registry;
+pub mod openweather_provider;
+pub mod weatherapi_provider;
+pub mod openmeteo_provider;
\ No newline at end of file
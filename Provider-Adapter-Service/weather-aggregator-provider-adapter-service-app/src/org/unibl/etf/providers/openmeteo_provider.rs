@@ -0,0 +1,201 @@
+use reqwest::StatusCode;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::Deserialize;
+use crate::org::unibl::etf::configuration::settings::{PooledApiKey, ProviderSettings};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{Location, UniformCurrentWeatherResponse, Weather, Wind};
+use crate::org::unibl::etf::providers::weather_provider::{ProviderCapabilities, ProviderQuery, WeatherProvider};
+use crate::org::unibl::etf::util::convertors::{celsius_to_fahrenheit, degrees_to_cardinal, kph_to_mph, mb_to_inhg};
+
+const CURRENT_WEATHER_VARIABLES: &str = "temperature_2m,apparent_temperature,relative_humidity_2m,pressure_msl,weather_code,cloud_cover,wind_speed_10m,wind_direction_10m,wind_gusts_10m";
+
+#[derive(Debug)]
+pub struct OpenMeteoProvider {
+    settings: ProviderSettings,
+}
+
+impl OpenMeteoProvider {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl WeatherProvider for OpenMeteoProvider {
+    fn settings(&self) -> &ProviderSettings {
+        &self.settings
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            supports_ip_lookup: false,
+            requires_geocoding: true,
+            requires_api_key: false,
+        }
+    }
+
+    fn build_current_weather_request(
+        &self,
+        client: &ClientWithMiddleware,
+        query: &ProviderQuery,
+        _api_key: Option<&PooledApiKey<'_>>,
+    ) -> Result<RequestBuilder, AdapterServiceError> {
+        let (lat, lon) = match query {
+            ProviderQuery::Coordinates { lat, lon } => (*lat, *lon),
+            _ => return Err(AdapterServiceError::RequestParametersValidationError(
+                Some("Open-Meteo API accepts only coordinates".to_string())
+            )),
+        };
+
+        Ok(client.get(format!("{}/{}", self.settings.base_api_url, self.settings.current_weather_endpoint))
+            .query(&[
+                ("latitude", lat.to_string()),
+                ("longitude", lon.to_string()),
+                ("current", CURRENT_WEATHER_VARIABLES.to_string()),
+                ("temperature_unit", "celsius".to_string()),
+                ("wind_speed_unit", "kmh".to_string()),
+                ("timeformat", "unixtime".to_string()),
+            ]))
+    }
+
+    fn map_current_weather_response(
+        &self,
+        body: &str,
+    ) -> Result<UniformCurrentWeatherResponse, AdapterServiceError> {
+        let data: OpenMeteoCurrentWeatherResponse = serde_json::from_str(body)
+            .map_err(|e| {
+                AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                    "Error while parsing successful external API response body text. JSON Error: {} | Raw Body: {}",
+                    e, body
+                )))
+            })?;
+
+        let current = data.current.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing current field".to_string()))
+        )?;
+        let temp = current.temperature_2m.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory temperature field".to_string()))
+        )?;
+
+        Ok(UniformCurrentWeatherResponse {
+            provider: self.settings.name.clone(),
+            //Open-Meteo returns no location details, the service fills them in from the geocoding candidate
+            location: Location {
+                name: None,
+                country: None,
+                lat: data.latitude,
+                lon: data.longitude,
+                state_region_province_or_entity: None,
+            },
+            weather: Weather {
+                temp_metric: temp,
+                temp_imperial: celsius_to_fahrenheit(temp),
+                temp_feelslike_metric: current.apparent_temperature,
+                temp_feelslike_imperial: current.apparent_temperature.map(celsius_to_fahrenheit),
+                humidity: current.relative_humidity_2m.map(|h| h as u8),
+                pressure_metric: current.pressure_msl,
+                pressure_imperial: current.pressure_msl.map(mb_to_inhg),
+                condition: current.weather_code.and_then(wmo_code_to_condition).map(|c| c.to_string()),
+            },
+            wind: Wind {
+                speed_metric: current.wind_speed_10m,
+                speed_imperial: current.wind_speed_10m.map(kph_to_mph),
+                gust_metric: current.wind_gusts_10m,
+                gust_imperial: current.wind_gusts_10m.map(kph_to_mph),
+                direction: current.wind_direction_10m.map(|d| degrees_to_cardinal(d).to_string()),
+                degrees: current.wind_direction_10m.map(|d| d as u16),
+            },
+            observed_at_timestamp: current.time,
+        })
+    }
+
+    fn map_error_response(
+        &self,
+        status: StatusCode,
+        body: &str,
+        _query: &ProviderQuery,
+    ) -> AdapterServiceError {
+        match serde_json::from_str::<OpenMeteoAPIError>(body) {
+            Ok(error_body) => {
+                tracing::error!("Error while calling External API: {:?}", error_body);
+                AdapterServiceError::ProviderAPIError(status.as_u16(), error_body.reason)
+            },
+            Err(_) => AdapterServiceError::ProviderAPIError(status.as_u16(), None),
+        }
+    }
+}
+
+// WMO weather interpretation codes as documented by Open-Meteo
+fn wmo_code_to_condition(code: u16) -> Option<&'static str> {
+    let condition = match code {
+        0 => "Clear sky",
+        1 => "Mainly clear",
+        2 => "Partly cloudy",
+        3 => "Overcast",
+        45 => "Fog",
+        48 => "Depositing rime fog",
+        51 => "Light drizzle",
+        53 => "Moderate drizzle",
+        55 => "Dense drizzle",
+        56 => "Light freezing drizzle",
+        57 => "Dense freezing drizzle",
+        61 => "Slight rain",
+        63 => "Moderate rain",
+        65 => "Heavy rain",
+        66 => "Light freezing rain",
+        67 => "Heavy freezing rain",
+        71 => "Slight snow fall",
+        73 => "Moderate snow fall",
+        75 => "Heavy snow fall",
+        77 => "Snow grains",
+        80 => "Slight rain showers",
+        81 => "Moderate rain showers",
+        82 => "Violent rain showers",
+        85 => "Slight snow showers",
+        86 => "Heavy snow showers",
+        95 => "Thunderstorm",
+        96 => "Thunderstorm with slight hail",
+        99 => "Thunderstorm with heavy hail",
+        _ => return None,
+    };
+    Some(condition)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenMeteoAPIError {
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenMeteoCurrentWeatherResponse {
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
+    #[serde(default)]
+    pub current: Option<OpenMeteoCurrent>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenMeteoCurrent {
+    #[serde(default)]
+    pub time: Option<i64>, //measurement timestamp in unix epoch format(UTC)
+    #[serde(default)]
+    pub temperature_2m: Option<f64>, //temperature in c
+    #[serde(default)]
+    pub apparent_temperature: Option<f64>,
+    #[serde(default)]
+    pub relative_humidity_2m: Option<f64>,
+    #[serde(default)]
+    pub pressure_msl: Option<f64>, //pressure in hPa (mb)
+    #[serde(default)]
+    pub weather_code: Option<u16>, //WMO condition code
+    #[serde(default)]
+    pub cloud_cover: Option<f64>, //cloud cover in percentage
+    #[serde(default)]
+    pub wind_speed_10m: Option<f64>, //wind speed in kph
+    #[serde(default)]
+    pub wind_direction_10m: Option<f64>, //wind direction in degrees
+    #[serde(default)]
+    pub wind_gusts_10m: Option<f64>, //wind gusts speed in kph
+}
@@ -0,0 +1,239 @@
+use celes::Country;
+use reqwest::StatusCode;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::Deserialize;
+use crate::org::unibl::etf::configuration::settings::{PooledApiKey, ProviderSettings};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{capitalize, Location, UniformCurrentWeatherResponse, Weather, Wind};
+use crate::org::unibl::etf::providers::weather_provider::{ProviderCapabilities, ProviderQuery, WeatherProvider};
+use crate::org::unibl::etf::util::convertors::{celsius_to_fahrenheit, degrees_to_cardinal, kph_to_mph, mb_to_inhg};
+
+#[derive(Debug)]
+pub struct OpenWeatherProvider {
+    settings: ProviderSettings,
+}
+
+impl OpenWeatherProvider {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl WeatherProvider for OpenWeatherProvider {
+    fn settings(&self) -> &ProviderSettings {
+        &self.settings
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            supports_ip_lookup: false,
+            requires_geocoding: true,
+            requires_api_key: true,
+        }
+    }
+
+    fn build_current_weather_request(
+        &self,
+        client: &ClientWithMiddleware,
+        query: &ProviderQuery,
+        api_key: Option<&PooledApiKey<'_>>,
+    ) -> Result<RequestBuilder, AdapterServiceError> {
+        let (lat, lon) = match query {
+            ProviderQuery::Coordinates { lat, lon } => (*lat, *lon),
+            _ => return Err(AdapterServiceError::RequestParametersValidationError(
+                Some("Openweather API accepts only coordinates".to_string())
+            )),
+        };
+
+        Ok(client.get(format!("{}/{}", self.settings.base_api_url, self.settings.current_weather_endpoint))
+            .query(&[
+                ("lat", lat.to_string()),
+                ("lon", lon.to_string()),
+                ("appid", self.api_key(api_key)?),
+                ("units", "metric".to_string()),
+            ]))
+    }
+
+    fn map_current_weather_response(
+        &self,
+        body: &str,
+    ) -> Result<UniformCurrentWeatherResponse, AdapterServiceError> {
+        let data: OpenWeatherAPICurrentWeatherResponse = serde_json::from_str(body)
+            .map_err(|e| {
+                AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                    "Error while parsing successful external API response body text. JSON Error: {} | Raw Body: {}",
+                    e, body
+                )))
+            })?;
+
+        let weather = data.weather
+            .first()
+            .ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Empty weather array field found".to_string())))?;
+
+        let main = data.main.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty main field found".to_string())))?;
+        let coordinates = data.coord.unwrap_or_default();
+        let sys = data.sys.unwrap_or_default();
+        let wind = data.wind.unwrap_or_default();
+        let temp = main.temp.ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory value. Empty main:temp field found".to_string())))?;
+
+        let country = match sys.country {
+            Some(country) if !country.is_empty() => {
+                Some(Country::from_alpha2(&country)
+                    .map(|c| c.to_string())
+                    .map_err(|_e| {
+                        AdapterServiceError::InvalidProviderResponseError(Some("Invalid country code.".to_string()))
+                    })?)
+            },
+            _ => None,
+        };
+
+        Ok(UniformCurrentWeatherResponse {
+            provider: self.settings.name.clone(),
+            location: Location {
+                name: data.name,
+                country,
+                lat: coordinates.lat,
+                lon: coordinates.lon,
+                state_region_province_or_entity: None
+            },
+            weather: Weather {
+                temp_metric: temp,
+                temp_imperial: celsius_to_fahrenheit(temp),
+                temp_feelslike_metric: main.feels_like,
+                temp_feelslike_imperial: main.feels_like.map(celsius_to_fahrenheit),
+                humidity: Some(main.humidity.unwrap_or_default() as u8),
+                pressure_metric: main.pressure,
+                pressure_imperial: main.pressure.map(mb_to_inhg),
+                condition: weather.description.as_deref().map(capitalize),
+            },
+            wind: Wind {
+                speed_metric: wind.speed,
+                speed_imperial: wind.speed.map(kph_to_mph),
+                gust_metric: wind.gust,
+                gust_imperial: wind.gust.map(kph_to_mph),
+                direction: wind.deg.map(|d| degrees_to_cardinal(d).to_string()),
+                degrees: wind.deg.map(|d| d as u16),
+            },
+            observed_at_timestamp: data.dt,
+        })
+    }
+
+    fn map_error_response(
+        &self,
+        status: StatusCode,
+        body: &str,
+        _query: &ProviderQuery,
+    ) -> AdapterServiceError {
+        match status {
+            StatusCode::NOT_FOUND |
+            StatusCode::UNAUTHORIZED |
+            StatusCode::TOO_MANY_REQUESTS |
+            StatusCode::BAD_REQUEST => {
+                match serde_json::from_str::<OpenWeatherAPIError>(body) {
+                    Ok(error_body) => {
+                        tracing::error!("Error while calling External API: {:?}", error_body);
+                        AdapterServiceError::ProviderAPIError(error_body.cod, Some(error_body.message))
+                    },
+                    Err(e) => AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                        "Error while parsing external API error response body text. JSON Error: {} | Raw Body: {}",
+                        e, body
+                    ))),
+                }
+            },
+            _ => AdapterServiceError::ProviderAPIError(status.as_u16(), None),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenWeatherAPIError {
+    pub message: String,
+    pub cod: u16,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenWeatherAPICurrentWeatherResponse {
+    #[serde(default)]
+    pub weather: Vec<OpenWeatherCondition>,
+    #[serde(default)]
+    pub main: Option<Main>,
+    #[serde(default)]
+    pub name: Option<String>, // Location name
+    #[serde(default)]
+    pub coord: Option<Coordinates>,
+    #[serde(default)]
+    pub wind: Option<OpenWeatherWind>,
+    #[serde(default)]
+    pub dt: Option<i64>, //measurement timestamp in unix epoch format(UTC)
+    #[serde(default)]
+    pub sys: Option<Sys>,
+    #[serde(default)]
+    pub timezone: Option<i32>, //shift in seconds from UTC
+    #[serde(default)]
+    pub clouds: Option<Clouds>
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenWeatherCondition {
+    #[serde(default)]
+    pub main: Option<String>, //condition, e.g. rain
+    #[serde(default)]
+    pub description: Option<String>, //condition description, e.g. rain
+    #[serde(default)]
+    pub id: Option<u16> //condition code
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Main {
+    #[serde(default)]
+    pub temp: Option<f64>, //temperature in f or c
+    #[serde(default)]
+    pub feels_like: Option<f64>,
+    #[serde(default)]
+    pub temp_min: Option<f64>,
+    #[serde(default)]
+    pub temp_max: Option<f64>,
+    #[serde(default)]
+    pub pressure: Option<f64>,  //pressure in mb
+    #[serde(default)]
+    pub humidity: Option<f64>, //relative humidity
+}
+
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Coordinates {
+    #[serde(default)]
+    pub lat: Option<f64>, //latitude
+    #[serde(default)]
+    pub lon: Option<f64>, //longitude
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct OpenWeatherWind {
+    #[serde(default)]
+    pub speed: Option<f64>, //wind speed in either kph or mph
+    #[serde(default)]
+    pub gust: Option<f64>,  //wind gusts speed in either kph or mph
+    #[serde(default)]
+    pub deg: Option<f64>, //wind direction in degrees
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Sys {
+    #[serde(default)]
+    pub country: Option<String>, //country name code
+}
+
+impl Default for Sys {
+    fn default() -> Self {
+        Sys {
+            country: Some("".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Clouds {
+    #[serde(default)]
+    pub all: Option<f64>, //cloud cover in percentage
+}
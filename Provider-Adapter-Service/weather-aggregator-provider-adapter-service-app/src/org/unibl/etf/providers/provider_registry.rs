@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use crate::org::unibl::etf::configuration::settings::{ProviderKind, ProviderSettings};
+use crate::org::unibl::etf::providers::openmeteo_provider::OpenMeteoProvider;
+use crate::org::unibl::etf::providers::openweather_provider::OpenWeatherProvider;
+use crate::org::unibl::etf::providers::weather_provider::WeatherProvider;
+use crate::org::unibl::etf::providers::weatherapi_provider::WeatherAPIProvider;
+
+#[derive(Debug)]
+pub struct ProviderRegistry {
+    providers: HashMap<String, Box<dyn WeatherProvider>>,
+}
+
+impl ProviderRegistry {
+    pub fn from_settings(providers_settings: Vec<ProviderSettings>) -> Result<Self, String> {
+        let mut providers: HashMap<String, Box<dyn WeatherProvider>> = HashMap::new();
+
+        for provider_settings in providers_settings {
+            let name = provider_settings.name.clone();
+
+            let provider: Box<dyn WeatherProvider> = match provider_settings.kind {
+                ProviderKind::OpenWeather => Box::new(OpenWeatherProvider::new(provider_settings)),
+                ProviderKind::WeatherApi => Box::new(WeatherAPIProvider::new(provider_settings)),
+                ProviderKind::OpenMeteo => Box::new(OpenMeteoProvider::new(provider_settings)),
+            };
+
+            if provider.capabilities().requires_api_key && provider.settings().api_key_pool().is_empty() {
+                return Err(format!("Provider {} requires an API key", name));
+            }
+
+            if providers.insert(name.clone(), provider).is_some() {
+                return Err(format!("Provider {} is configured more than once", name));
+            }
+        }
+
+        Ok(Self { providers })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn WeatherProvider> {
+        self.providers.get(name).map(|provider| provider.as_ref())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.providers.keys().map(|name| name.as_str()).collect()
+    }
+}
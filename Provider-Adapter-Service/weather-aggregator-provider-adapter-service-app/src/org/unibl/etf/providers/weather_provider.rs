@@ -0,0 +1,69 @@
+use std::fmt::Debug;
+use reqwest::StatusCode;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use secrecy::ExposeSecret;
+use crate::org::unibl::etf::configuration::settings::{PooledApiKey, ProviderSettings};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::UniformCurrentWeatherResponse;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProviderCapabilities {
+    pub supports_ip_lookup: bool,
+    //location names are resolved through the Geocoding Service before the provider is called
+    pub requires_geocoding: bool,
+    pub requires_api_key: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ProviderQuery {
+    Coordinates { lat: f64, lon: f64 },
+    LocationName(String),
+    IpAddress(String),
+}
+
+impl ProviderQuery {
+    pub fn location_name(&self) -> Option<String> {
+        match self {
+            ProviderQuery::LocationName(name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+}
+
+// Everything provider specific lives behind this trait. Rate limiting, geocoding and
+// error envelopes are handled once by the generic CurrentWeatherService.
+pub trait WeatherProvider: Send + Sync + Debug {
+    fn settings(&self) -> &ProviderSettings;
+
+    fn capabilities(&self) -> ProviderCapabilities;
+
+    // api_key is the key picked from the pool, None for providers that do not require one.
+    fn build_current_weather_request(
+        &self,
+        client: &ClientWithMiddleware,
+        query: &ProviderQuery,
+        api_key: Option<&PooledApiKey<'_>>,
+    ) -> Result<RequestBuilder, AdapterServiceError>;
+
+    fn map_current_weather_response(
+        &self,
+        body: &str,
+    ) -> Result<UniformCurrentWeatherResponse, AdapterServiceError>;
+
+    fn map_error_response(
+        &self,
+        status: StatusCode,
+        body: &str,
+        query: &ProviderQuery,
+    ) -> AdapterServiceError;
+
+    fn name(&self) -> &str {
+        self.settings().name.as_str()
+    }
+
+    fn api_key(&self, api_key: Option<&PooledApiKey<'_>>) -> Result<String, AdapterServiceError> {
+        api_key
+            .map(|api_key| api_key.secret.expose_secret().to_string())
+            .ok_or(AdapterServiceError::ServerError(Some(format!("Missing API key for the provider {}", self.name()))))
+    }
+}
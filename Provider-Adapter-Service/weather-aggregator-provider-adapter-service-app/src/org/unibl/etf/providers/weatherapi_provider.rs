@@ -0,0 +1,216 @@
+use reqwest::StatusCode;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::Deserialize;
+use crate::org::unibl::etf::configuration::settings::{PooledApiKey, ProviderSettings};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{Location, UniformCurrentWeatherResponse, Weather, Wind};
+use crate::org::unibl::etf::providers::weather_provider::{ProviderCapabilities, ProviderQuery, WeatherProvider};
+
+//weatherapi.com error code for "No location found matching parameter q"
+const LOCATION_NOT_FOUND_ERROR_CODE: u16 = 1006;
+
+#[derive(Debug)]
+pub struct WeatherAPIProvider {
+    settings: ProviderSettings,
+}
+
+impl WeatherAPIProvider {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl WeatherProvider for WeatherAPIProvider {
+    fn settings(&self) -> &ProviderSettings {
+        &self.settings
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            supports_ip_lookup: true,
+            requires_geocoding: false,
+            requires_api_key: true,
+        }
+    }
+
+    fn build_current_weather_request(
+        &self,
+        client: &ClientWithMiddleware,
+        query: &ProviderQuery,
+        api_key: Option<&PooledApiKey<'_>>,
+    ) -> Result<RequestBuilder, AdapterServiceError> {
+        let q_argument = match query {
+            ProviderQuery::Coordinates { lat, lon } => format!("{},{}", lat, lon),
+            ProviderQuery::LocationName(name) => name.clone(),
+            ProviderQuery::IpAddress(ip_address) => ip_address.clone(),
+        };
+
+        Ok(client.get(format!("{}/{}", self.settings.base_api_url, self.settings.current_weather_endpoint))
+            .query(&[
+                ("q", q_argument),
+                ("key", self.api_key(api_key)?),
+            ]))
+    }
+
+    fn map_current_weather_response(
+        &self,
+        body: &str,
+    ) -> Result<UniformCurrentWeatherResponse, AdapterServiceError> {
+        let data: WeatherAPICurrentWeatherResponse = serde_json::from_str(body)
+            .map_err(|e| {
+                AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                    "Error while parsing successful external API response body text. JSON Error: {} | Raw Body: {}",
+                    e, body
+                )))
+            })?;
+
+        let current = data.current.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing current field".to_string()))
+        )?;
+        let location = data.location.unwrap_or_default();
+        let condition = current.condition.unwrap_or_default();
+        let temp_c = current.temp_c.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory temperature field".to_string()))
+        )?;
+        let temp_f = current.temp_f.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory temperature field".to_string()))
+        )?;
+
+        Ok(UniformCurrentWeatherResponse {
+            provider: self.settings.name.clone(),
+            location: Location {
+                name: location.name,
+                country: location.country,
+                lat: location.lat,
+                lon: location.lon,
+                state_region_province_or_entity: location.region
+            },
+            weather: Weather {
+                temp_metric: temp_c,
+                temp_imperial: temp_f,
+                temp_feelslike_metric: current.feelslike_c,
+                temp_feelslike_imperial: current.feelslike_f,
+                humidity: current.humidity.map(|h| h as u8),
+                pressure_metric: current.pressure_mb,
+                pressure_imperial: current.pressure_in,
+                condition: condition.text,
+            },
+            wind: Wind {
+                speed_metric: current.wind_kph,
+                speed_imperial: current.wind_mph,
+                gust_metric: current.gust_kph,
+                gust_imperial: current.gust_mph,
+                direction: current.wind_dir,
+                degrees: current.wind_degree,
+            },
+            observed_at_timestamp: current.last_updated_epoch,
+        })
+    }
+
+    fn map_error_response(
+        &self,
+        status: StatusCode,
+        body: &str,
+        query: &ProviderQuery,
+    ) -> AdapterServiceError {
+        match status {
+            StatusCode::NOT_FOUND |
+            StatusCode::UNAUTHORIZED |
+            StatusCode::TOO_MANY_REQUESTS |
+            StatusCode::BAD_REQUEST => {
+                match serde_json::from_str::<WeatherAPIError>(body) {
+                    Ok(error_body) if error_body.error.code == LOCATION_NOT_FOUND_ERROR_CODE => {
+                        AdapterServiceError::LocationNotFoundError(query.location_name())
+                    },
+                    Ok(error_body) => {
+                        tracing::error!("Error while calling External API: {:?}", error_body);
+                        AdapterServiceError::ProviderAPIError(error_body.error.code, Some(error_body.error.message))
+                    },
+                    Err(e) => AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                        "Error while parsing external API error response body text. JSON Error: {} | Raw Body: {}",
+                        e, body
+                    ))),
+                }
+            },
+            _ => AdapterServiceError::ProviderAPIError(status.as_u16(), None),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherAPIError {
+    pub error: WeatherAPIErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherAPIErrorDetails {
+    pub message: String,
+    pub code: u16,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherAPICurrentWeatherResponse {
+    #[serde(default)]
+    pub location: Option<WeatherAPILocation>,
+    #[serde(default)]
+    pub current: Option<Current>,
+
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct WeatherAPILocation {
+    #[serde(default)]
+    pub lat: Option<f64>, //latitude
+    #[serde(default)]
+    pub lon: Option<f64>, //longitude
+    #[serde(default)]
+    pub name: Option<String>, // Location name
+    #[serde(default)]
+    pub country: Option<String>, //Country name
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Current {
+    #[serde(default)]
+    pub temp_c: Option<f64>, //temperature in c
+    #[serde(default)]
+    pub temp_f: Option<f64>, //temperature in f
+    #[serde(default)]
+    pub feelslike_c: Option<f64>,
+    #[serde(default)]
+    pub feelslike_f: Option<f64>,
+    #[serde(default)]
+    pub condition: Option<Condition>,
+    #[serde(default)]
+    pub pressure_mb: Option<f64>, //pressure in mb
+    #[serde(default)]
+    pub pressure_in: Option<f64>,
+    #[serde(default)]
+    pub humidity: Option<f64>, //relative humidity
+    #[serde(default)]
+    pub wind_mph: Option<f64>, //wind speed in mph
+    #[serde(default)]
+    pub wind_kph: Option<f64>, //wind speed in kph
+    #[serde(default)]
+    pub wind_degree: Option<u16>, //wind direction in degrees
+    #[serde(default)]
+    pub wind_dir: Option<String>,
+    #[serde(default)]
+    pub gust_mph: Option<f64>, //wind gusts speed in mph
+    #[serde(default)]
+    pub gust_kph: Option<f64>, //wind gusts speed in kph
+    #[serde(default)]
+    pub last_updated_epoch: Option<i64>, //last updated
+    #[serde(default)]
+    pub cloud: Option<f64>, //cloud cover in percentage
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct Condition {
+    #[serde(default)]
+    pub text: Option<String>, //condition, e.g. rain
+    #[serde(default)]
+    pub code: Option<u16> //condition code
+}
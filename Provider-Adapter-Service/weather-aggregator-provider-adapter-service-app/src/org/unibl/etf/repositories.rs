@@ -0,0 +1 @@
+pub mod provider_repository;
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod current_weather_service;
+pub mod geocoding_service;
+pub mod api_key_service;
\ No newline at end of file
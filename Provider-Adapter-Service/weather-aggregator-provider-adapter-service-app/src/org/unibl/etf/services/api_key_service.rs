@@ -0,0 +1,105 @@
+use reqwest::StatusCode;
+use crate::org::unibl::etf::configuration::settings::{PooledApiKey, ProviderSettings};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::api_key_usage_response::{ApiKeyUsage, ApiKeyUsageResponse};
+use crate::org::unibl::etf::repositories::provider_repository::{ApiKeySelection, ProviderRepository};
+
+#[derive(Debug)]
+pub struct ApiKeyService {
+    provider_repository: ProviderRepository,
+}
+
+impl ApiKeyService {
+    fn new() -> Self {
+        Self {
+            provider_repository: ProviderRepository::default(),
+        }
+    }
+
+    #[tracing::instrument(name = "Acquire API key Service", skip(settings, redis_pool))]
+    pub async fn acquire_api_key<'a>(
+        &self,
+        settings: &'a ProviderSettings,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<PooledApiKey<'a>, AdapterServiceError> {
+        let api_keys = settings.api_key_pool();
+        if api_keys.is_empty() {
+            return Err(AdapterServiceError::ServerError(Some(format!("Missing API key for the provider {}", settings.name))));
+        }
+
+        match self.provider_repository.acquire_api_key(&settings.name, &api_keys, redis_pool).await? {
+            ApiKeySelection::Selected(index) => {
+                tracing::info!("Using API key {} for the provider {}.", api_keys[index].id, settings.name);
+                Ok(api_keys[index])
+            },
+            ApiKeySelection::Exhausted(retry_after) => {
+                tracing::warn!("No API key of the provider {} is available, next one in {:?}.", settings.name, retry_after);
+                Err(AdapterServiceError::RateLimitExceeded(Some(retry_after.as_millis().div_ceil(1000) as u64)))
+            },
+        }
+    }
+
+    // 401 and 429 are answers about the key itself, so it sits out the cool-down and the pool moves on.
+    #[tracing::instrument(name = "Retire rejected API key Service", skip(api_key, settings, redis_pool))]
+    pub async fn retire_api_key_if_rejected(
+        &self,
+        status: StatusCode,
+        api_key: &PooledApiKey<'_>,
+        settings: &ProviderSettings,
+        redis_pool: &deadpool_redis::Pool,
+    ) {
+        if status != StatusCode::UNAUTHORIZED && status != StatusCode::TOO_MANY_REQUESTS {
+            return;
+        }
+
+        tracing::warn!(
+            "API key {} of the provider {} was rejected with status {}, retiring it for {} seconds.",
+            api_key.id, settings.name, status, settings.api_key_cooldown_in_seconds
+        );
+        if let Err(e) = self.provider_repository.retire_api_key(
+            &settings.name,
+            api_key.id,
+            status.as_u16(),
+            settings.api_key_cooldown_in_seconds,
+            redis_pool,
+        ).await {
+            tracing::error!("Failed to retire API key {} with error: {:?}", api_key.id, e.get_message());
+        }
+    }
+
+    #[tracing::instrument(name = "Get API key usage Service", skip(settings, redis_pool))]
+    pub async fn get_api_key_usage(
+        &self,
+        settings: &ProviderSettings,
+        redis_pool: &deadpool_redis::Pool,
+    ) -> Result<ApiKeyUsageResponse, AdapterServiceError> {
+        let api_keys = settings.api_key_pool();
+        let records = self.provider_repository
+            .get_api_key_usage(&settings.name, &api_keys, redis_pool)
+            .await?;
+
+        Ok(ApiKeyUsageResponse {
+            provider: settings.name.clone(),
+            api_keys: api_keys
+                .iter()
+                .zip(records)
+                .map(|(api_key, record)| ApiKeyUsage {
+                    id: api_key.id.to_string(),
+                    requests_today: record.requests_today,
+                    requests_per_day: api_key.requests_per_day,
+                    remaining_today: api_key.requests_per_day
+                        .map(|quota| quota.saturating_sub(record.requests_today)),
+                    cooldown_remaining_in_seconds: record.cooldown_remaining
+                        .map(|cooldown| cooldown.as_millis().div_ceil(1000) as u64),
+                    retired_after_status: record.retired_after_status,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Default for ApiKeyService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
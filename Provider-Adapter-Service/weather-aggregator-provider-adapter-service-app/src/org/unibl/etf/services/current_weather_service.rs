@@ -0,0 +1,127 @@
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{GeocodingServiceSettings};
+use crate::org::unibl::etf::model::errors::adapter_service_error::{AdapterServiceError};
+use crate::org::unibl::etf::model::requests::current_weather_request::CurrentWeatherRequest;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{Location, UniformCurrentWeatherResponse};
+use crate::org::unibl::etf::providers::weather_provider::{ProviderQuery, WeatherProvider};
+use crate::org::unibl::etf::repositories::provider_repository::ProviderRepository;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
+use crate::org::unibl::etf::services::geocoding_service::GeocodingService;
+
+#[derive(Debug)]
+pub struct CurrentWeatherService {
+    provider_repository: ProviderRepository,
+    api_key_service: ApiKeyService,
+    geocoding_service: GeocodingService,
+}
+
+
+impl CurrentWeatherService {
+    fn new() -> Self {
+        Self {
+            provider_repository: ProviderRepository::default(),
+            api_key_service: ApiKeyService::default(),
+            geocoding_service: GeocodingService::default(),
+        }
+    }
+
+    #[tracing::instrument(name = "Get Current Weather Data Service", skip(client, redis_pool, geocoding_settings))]
+    pub async fn get_current_weather(
+        &self,
+        req: CurrentWeatherRequest,
+        provider: &dyn WeatherProvider,
+        client: &ClientWithMiddleware,
+        redis_pool: &deadpool_redis::Pool,
+        geocoding_settings: &GeocodingServiceSettings,
+    ) -> Result<UniformCurrentWeatherResponse, AdapterServiceError> {
+        let capabilities = provider.capabilities();
+
+        let location_name = req.location_name.clone().filter(|name| !name.is_empty());
+
+        let (query, candidate) = match (req.lat, req.lon, location_name, req.ip_address.clone()) {
+            (Some(lat), Some(lon), _, _) => (ProviderQuery::Coordinates { lat, lon }, None),
+            (_, _, Some(location_name), _) if capabilities.requires_geocoding => {
+                let candidate = match self.geocoding_service.geocode_location(
+                    location_name.as_str(),
+                    client,
+                    5,
+                    geocoding_settings
+                ).await {
+                    Ok(candidate) => {
+                        tracing::info!("Successfully geocoded location. Result: {:?}", candidate);
+                        candidate
+                    },
+                    Err(e) => return {
+                        tracing::error!("Could not geocode location with error: {:?}", e);
+                        Err(e)
+                    },
+                };
+                (ProviderQuery::Coordinates { lat: candidate.lat, lon: candidate.lon }, Some(candidate))
+            },
+            (_, _, Some(location_name), _) => (ProviderQuery::LocationName(location_name), None),
+            (_, _, None, Some(ip_address)) if capabilities.supports_ip_lookup => (ProviderQuery::IpAddress(ip_address), None),
+            (_, _, None, Some(_)) => {
+                return Err(AdapterServiceError::RequestParametersValidationError(
+                    Some(format!("Provider {} does not support lookup by ip address", provider.name()))
+                ));
+            },
+            _ => return Err(AdapterServiceError::RequestParametersValidationError(None)), //validation done earlier
+        };
+
+        let api_key = if capabilities.requires_api_key {
+            Some(self.api_key_service.acquire_api_key(provider.settings(), redis_pool).await?)
+        } else {
+            None
+        };
+
+        self.provider_repository.acquire_rate_limit_slot(
+            &provider.settings().rate_limit_windows(),
+            provider.name(),
+            redis_pool
+        ).await?;
+
+        let response = provider.build_current_weather_request(client, &query, api_key.as_ref())?
+            .send()
+            .await
+            .map_err(|e| AdapterServiceError::ConnectionError(Some(e.to_string())))?;
+
+        let status = response.status();
+
+        let body_text = response.text().await.map_err(|e| {
+            AdapterServiceError::ServerError(Some(format!("Failed to get external API response body text: {}", e)))
+        })?;
+
+        if !status.is_success() {
+            if let Some(api_key) = &api_key {
+                self.api_key_service.retire_api_key_if_rejected(status, api_key, provider.settings(), redis_pool).await;
+            }
+            let e = provider.map_error_response(status, &body_text, &query);
+            tracing::error!("Error while calling External API: {:?}", e);
+            return Err(e);
+        }
+
+        let mut weather_data = provider.map_current_weather_response(&body_text)
+            .inspect_err(|e| {
+                tracing::error!("Was not able to get transform weather data to uniform format with error: {:?}", e.get_message());
+            })?;
+
+        match candidate {
+            Some(cand) if weather_data.location.name.is_none() => {
+                let (lat, lon) = (cand.lat, cand.lon);
+                weather_data.location = Location::from_candidate_or_coordinates(Some(cand), lat, lon);
+            },
+            Some(cand) if !cand.state.is_empty() => {
+                weather_data.set_state_region_province_or_entity(cand.state);
+            },
+            _ => {}
+        }
+
+        Ok(weather_data)
+    }
+}
+
+impl Default for CurrentWeatherService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,2 @@
+pub mod serializers;
+pub mod convertors;
\ No newline at end of file
@@ -0,0 +1,47 @@
+-- Picks the key with the most quota left today, skipping keys that are cooling down after an upstream rejection.
+-- Unmetered keys tie on remaining quota and are spread by how often they were used today.
+--
+-- KEYS: usage counter and cool-down key, once per pooled key.
+-- ARGV[1]: milliseconds until the daily quotas reset, ARGV[2]: usage counter TTL in seconds,
+-- ARGV[3..]: daily quota per pooled key, -1 when unmetered.
+-- Returns {1-based index of the picked key, 0}, or {0, retry after in milliseconds} when none is usable.
+
+local reset_in = tonumber(ARGV[1])
+local usage_ttl = tonumber(ARGV[2])
+local api_keys = #ARGV - 2
+local best, best_remaining, best_used = 0, -1, 0
+local retry_after = -1
+
+for i = 1, api_keys do
+    local quota = tonumber(ARGV[i + 2])
+    local used = tonumber(redis.call('GET', KEYS[i * 2 - 1]) or '0')
+    local cooldown = redis.call('PTTL', KEYS[i * 2])
+    local wait = 0
+    if cooldown > 0 then
+        wait = cooldown
+    elseif quota >= 0 and used >= quota then
+        wait = reset_in
+    end
+
+    if wait > 0 then
+        if retry_after < 0 or wait < retry_after then
+            retry_after = wait
+        end
+    else
+        local remaining = math.huge
+        if quota >= 0 then
+            remaining = quota - used
+        end
+        if remaining > best_remaining or (remaining == best_remaining and used < best_used) then
+            best, best_remaining, best_used = i, remaining, used
+        end
+    end
+end
+
+if best == 0 then
+    return {0, retry_after}
+end
+
+redis.call('INCR', KEYS[best * 2 - 1])
+redis.call('EXPIRE', KEYS[best * 2 - 1], usage_ttl)
+return {best, 0}
@@ -0,0 +1,38 @@
+-- Sliding window log with one sorted set per window, scored by call time in milliseconds.
+-- A call is recorded in every window or in none, so concurrent callers can not overshoot a limit.
+-- Redis time is used so instances with drifting clocks still agree on the windows.
+--
+-- KEYS[1..n]: one sorted set per window, KEYS[n + 1]: sequence counter keeping members unique.
+-- ARGV: window length in milliseconds and request limit, once per window.
+-- Returns {1, 0} when the call was recorded, {0, retry after in milliseconds} otherwise.
+
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local windows = #ARGV / 2
+local retry_after = 0
+
+for i = 1, windows do
+    local window = tonumber(ARGV[i * 2 - 1])
+    local limit = tonumber(ARGV[i * 2])
+    redis.call('ZREMRANGEBYSCORE', KEYS[i], '-inf', now - window)
+    if redis.call('ZCARD', KEYS[i]) >= limit then
+        local wait = window
+        local oldest = redis.call('ZRANGE', KEYS[i], 0, 0, 'WITHSCORES')
+        if oldest[2] then
+            wait = tonumber(oldest[2]) + window - now
+        end
+        retry_after = math.max(retry_after, wait)
+    end
+end
+
+if retry_after > 0 then
+    return {0, retry_after}
+end
+
+local member = now .. ':' .. redis.call('INCR', KEYS[windows + 1])
+for i = 1, windows do
+    redis.call('ZADD', KEYS[i], now, member)
+    redis.call('PEXPIRE', KEYS[i], tonumber(ARGV[i * 2 - 1]))
+end
+
+return {1, 0}
@@ -1,6 +1,6 @@
 use opentelemetry_otlp::tonic_types::transport::{Certificate, ClientTlsConfig, Identity};
 use serde::Deserialize;
-use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_aux::field_attributes::{deserialize_number_from_string, deserialize_option_number_from_string};
 use serde_aux::prelude::deserialize_bool_from_anything;
 use std::{fs, io};
 use std::io::BufReader;
@@ -39,9 +39,77 @@ pub struct ProviderSettings {
     pub name: String,
     pub base_api_url: String,
     pub current_weather_endpoint: String,
-    pub api_key: SecretBox<String>,
+    pub forecast_endpoint: String,
+    pub historical_weather_endpoint: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub forecast_days: u8,
+    #[serde(default)]
+    pub api_key: Option<SecretBox<String>>,
+    #[serde(default)]
+    pub api_keys: Vec<ApiKeySettings>,
+    #[serde(default = "default_api_key_cooldown_in_seconds", deserialize_with = "deserialize_number_from_string")]
+    pub api_key_cooldown_in_seconds: u64,
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub requests_per_30_mins: u64,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_minute: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_day: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitWindow {
+    pub window_in_seconds: u64,
+    pub max_requests: u64,
+}
+
+fn default_api_key_cooldown_in_seconds() -> u64 {
+    900
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ApiKeySettings {
+    // Reported in usage instead of the key itself.
+    pub id: String,
+    pub key: SecretBox<String>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub requests_per_day: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PooledApiKey<'a> {
+    pub id: &'a str,
+    pub secret: &'a SecretBox<String>,
+    pub requests_per_day: Option<u64>,
+}
+
+impl ProviderSettings {
+    // A call is only made when every configured window has room for it.
+    pub fn rate_limit_windows(&self) -> Vec<RateLimitWindow> {
+        [
+            (60, self.requests_per_minute),
+            (1800, Some(self.requests_per_30_mins)),
+            (86400, self.requests_per_day),
+        ]
+            .into_iter()
+            .filter_map(|(window_in_seconds, max_requests)| {
+                max_requests.map(|max_requests| RateLimitWindow { window_in_seconds, max_requests })
+            })
+            .collect()
+    }
+
+    // A lone api_key joins the pool as an unmetered key, so older configurations keep working.
+    pub fn api_key_pool(&self) -> Vec<PooledApiKey<'_>> {
+        self.api_key
+            .iter()
+            .map(|secret| PooledApiKey { id: "default", secret, requests_per_day: None })
+            .chain(self.api_keys.iter().map(|api_key| PooledApiKey {
+                id: api_key.id.as_str(),
+                secret: &api_key.key,
+                requests_per_day: api_key.requests_per_day,
+            }))
+            .collect()
+    }
 }
 
 
@@ -1,2 +1,7 @@
 pub mod current_weather_controller;
+pub mod forecast_controller;
+pub mod historical_weather_controller;
+pub mod weather_alerts_controller;
+pub mod air_quality_controller;
+pub mod api_key_controller;
 pub mod errors;
\ No newline at end of file
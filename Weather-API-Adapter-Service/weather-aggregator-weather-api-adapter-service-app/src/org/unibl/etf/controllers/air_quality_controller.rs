@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+
+use crate::org::unibl::etf::model::requests::current_weather_request::{CurrentWeatherRequest};
+use crate::org::unibl::etf::model::responses::uniform_air_quality_response::UniformAirQualityResponse;
+use crate::org::unibl::etf::services::air_quality_service::AirQualityService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/air_quality").route(web::get().to(get_air_quality_data)));
+}
+
+#[tracing::instrument(name = "Get Air Quality Data Controller",
+    skip(http_client, air_quality_service, settings, redis_pool))]
+async fn get_air_quality_data(
+    air_quality_service: web::Data<AirQualityService>,
+    query: Query<CurrentWeatherRequest>,
+    http_client: web::Data<ClientWithMiddleware>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let res = air_quality_service
+        .get_air_quality_data(
+            query.as_ref(),
+            http_client.get_ref(),
+            &settings.provider,
+            redis_pool.get_ref(),
+        )
+        .await
+        .map(|res| {
+            tracing::info!("Successfully got air quality data from external API: {:?}", res);
+            res
+        })
+        .map_err(|e| {
+            tracing::error!("Was not able to get air quality data with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })?;
+
+    UniformAirQualityResponse::try_from(res)
+        .map(|air_quality_data| HttpResponse::Ok().json(air_quality_data))
+        .map_err(|e| {
+            tracing::error!("Was not able to get transform air quality data to uniform format with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })
+
+}
+
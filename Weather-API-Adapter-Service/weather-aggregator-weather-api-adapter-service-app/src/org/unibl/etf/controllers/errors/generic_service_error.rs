@@ -1,6 +1,6 @@
 use std::fmt;
 use actix_web::{error, HttpResponse};
-use actix_web::http::StatusCode;
+use actix_web::http::{header, StatusCode};
 use chrono::{DateTime, Utc};
 use serde::{Serialize};
 use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
@@ -49,6 +49,9 @@ impl error::ResponseError for GenericServiceError {
             AdapterServiceError::RequestParametersValidationError(_) => {
                 StatusCode::BAD_REQUEST
             },
+            AdapterServiceError::RateLimitExceeded(_) => {
+                StatusCode::TOO_MANY_REQUESTS
+            },
             _ => StatusCode::INTERNAL_SERVER_ERROR
         };
         status_code
@@ -61,7 +64,12 @@ impl error::ResponseError for GenericServiceError {
         sanitized_details.code = sanitized_details.code.get_sanitized_error();
 
 
-        HttpResponse::build(self.status_code())
+        let mut response = HttpResponse::build(self.status_code());
+        if let AdapterServiceError::RateLimitExceeded(Some(retry_after)) = self.error.code {
+            response.insert_header((header::RETRY_AFTER, retry_after.to_string()));
+        }
+
+        response
             .json(GenericServiceError {
                 error: sanitized_details,
             })
@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+
+use crate::org::unibl::etf::model::requests::current_weather_request::{CurrentWeatherRequest};
+use crate::org::unibl::etf::model::responses::uniform_forecast_response::UniformForecastResponse;
+use crate::org::unibl::etf::services::forecast_service::ForecastService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/forecast").route(web::get().to(get_forecast_data)));
+}
+
+#[tracing::instrument(name = "Get Forecast Data Controller",
+    skip(http_client, forecast_service, settings, redis_pool))]
+async fn get_forecast_data(
+    forecast_service: web::Data<ForecastService>,
+    query: Query<CurrentWeatherRequest>,
+    http_client: web::Data<ClientWithMiddleware>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let res = forecast_service
+        .get_forecast_data(
+            query.as_ref(),
+            http_client.get_ref(),
+            &settings.provider,
+            redis_pool.get_ref(),
+        )
+        .await
+        .map(|res| {
+            tracing::info!("Successfully got forecast data from external API: {:?}", res);
+            res
+        })
+        .map_err(|e| {
+            tracing::error!("Was not able to get forecast data with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })?;
+
+    UniformForecastResponse::try_from(res)
+        .map(|forecast_data| HttpResponse::Ok().json(forecast_data))
+        .map_err(|e| {
+            tracing::error!("Was not able to get transform forecast data to uniform format with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })
+
+}
+
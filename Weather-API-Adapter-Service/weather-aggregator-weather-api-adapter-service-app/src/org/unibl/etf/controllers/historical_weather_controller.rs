@@ -0,0 +1,56 @@
+use actix_web::{web, HttpResponse, Responder};
+
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+
+use crate::org::unibl::etf::model::requests::current_weather_request::{CurrentWeatherRequest};
+use crate::org::unibl::etf::model::requests::date_range_request::DateRangeRequest;
+use crate::org::unibl::etf::model::responses::weatherapi_historical_weather_response::to_uniform_observations;
+use crate::org::unibl::etf::services::historical_weather_service::HistoricalWeatherService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/historical_weather").route(web::get().to(get_historical_weather_data)));
+}
+
+#[tracing::instrument(name = "Get Historical Weather Data Controller",
+    skip(http_client, historical_weather_service, settings, redis_pool))]
+async fn get_historical_weather_data(
+    historical_weather_service: web::Data<HistoricalWeatherService>,
+    query: Query<CurrentWeatherRequest>,
+    date_range: Query<DateRangeRequest>,
+    http_client: web::Data<ClientWithMiddleware>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let res = historical_weather_service
+        .get_historical_weather_data(
+            query.as_ref(),
+            date_range.as_ref(),
+            http_client.get_ref(),
+            &settings.provider,
+            redis_pool.get_ref(),
+        )
+        .await
+        .inspect(|_| {
+            tracing::info!("Successfully got historical weather data from external API");
+        })
+        .map_err(|e| {
+            tracing::error!("Was not able to get historical weather data with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })?;
+
+    to_uniform_observations(res)
+        .map(|observations| HttpResponse::Ok().json(observations))
+        .map_err(|e| {
+            tracing::error!("Was not able to get transform historical weather data to uniform format with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })
+
+}
+
@@ -0,0 +1,54 @@
+use actix_web::{web, HttpResponse, Responder};
+
+use actix_web_validator::Query;
+use reqwest_middleware::ClientWithMiddleware;
+use crate::org::unibl::etf::configuration::settings::{Settings};
+use crate::org::unibl::etf::controllers::errors::generic_service_error::{GenericServiceError, GenericServiceErrorDetails};
+
+use crate::org::unibl::etf::model::requests::current_weather_request::{CurrentWeatherRequest};
+use crate::org::unibl::etf::model::responses::uniform_weather_alerts_response::UniformWeatherAlertsResponse;
+use crate::org::unibl::etf::services::weather_alerts_service::WeatherAlertsService;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/weather_alerts").route(web::get().to(get_weather_alerts_data)));
+}
+
+#[tracing::instrument(name = "Get Weather Alerts Controller",
+    skip(http_client, weather_alerts_service, settings, redis_pool))]
+async fn get_weather_alerts_data(
+    weather_alerts_service: web::Data<WeatherAlertsService>,
+    query: Query<CurrentWeatherRequest>,
+    http_client: web::Data<ClientWithMiddleware>,
+    settings: web::Data<Settings>,
+    redis_pool: web::Data<deadpool_redis::Pool>
+) -> Result<impl Responder, GenericServiceError> {
+    let res = weather_alerts_service
+        .get_weather_alerts_data(
+            query.as_ref(),
+            http_client.get_ref(),
+            &settings.provider,
+            redis_pool.get_ref(),
+        )
+        .await
+        .map(|res| {
+            tracing::info!("Successfully got weather alerts data from external API: {:?}", res);
+            res
+        })
+        .map_err(|e| {
+            tracing::error!("Was not able to get weather alerts data with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })?;
+
+    UniformWeatherAlertsResponse::try_from(res)
+        .map(|alerts_data| HttpResponse::Ok().json(alerts_data))
+        .map_err(|e| {
+            tracing::error!("Was not able to get transform weather alerts data to uniform format with error: {:?}", e.get_message());
+            GenericServiceError {
+                error: GenericServiceErrorDetails::new_adapter_error(&settings.provider.name, e)
+            }
+        })
+
+}
+
@@ -10,7 +10,8 @@ pub enum AdapterServiceError {
     LocationNotFoundError(String),
     RequestParametersValidationError(Option<String>),
     InvalidProviderResponseError(Option<String>),
-    RateLimitExceeded,
+    // Seconds until the provider accepts another call.
+    RateLimitExceeded(Option<u64>),
     RedisError(Option<String>, Option<String>),
 }
 
@@ -22,7 +23,7 @@ impl AdapterServiceError {
         match self {
             Self::LocationNotFoundError(s) => Self::LocationNotFoundError(s.clone()),
             Self::RequestParametersValidationError(s) => Self::RequestParametersValidationError(s.clone()),
-            Self::RateLimitExceeded => Self::RateLimitExceeded,
+            Self::RateLimitExceeded(retry_after) => Self::RateLimitExceeded(*retry_after),
             _ => Self::ServerError(None),
         }
 
@@ -32,7 +33,7 @@ impl AdapterServiceError {
         match self {
             Self::LocationNotFoundError(_s) => self.get_message(),
             Self::RequestParametersValidationError(s) => s.clone().unwrap_or(String::default()),
-            Self::RateLimitExceeded => self.get_message(),
+            Self::RateLimitExceeded(_) => self.get_message(),
             _ => String::default(),
         }
 
@@ -54,7 +55,7 @@ impl AdapterServiceError {
             AdapterServiceError::LocationNotFoundError(location) => {
                 format!("Location with name {} not found", location)
             },
-            AdapterServiceError::RateLimitExceeded => {
+            AdapterServiceError::RateLimitExceeded(_) => {
                 String::from("API Rate Limit exceeded for the provider.")
             },
 
@@ -87,7 +88,7 @@ impl AdapterServiceError {
             AdapterServiceError::RequestParametersValidationError(_) => 1017,
             AdapterServiceError::InvalidProviderResponseError(_) => 1018,
             AdapterServiceError::RedisError(_, _) => 1019,
-            AdapterServiceError::RateLimitExceeded => 1020,
+            AdapterServiceError::RateLimitExceeded(_) => 1020,
         }
     }
 }
@@ -1 +1,2 @@
-pub mod current_weather_request;
\ No newline at end of file
+pub mod current_weather_request;
+pub mod date_range_request;
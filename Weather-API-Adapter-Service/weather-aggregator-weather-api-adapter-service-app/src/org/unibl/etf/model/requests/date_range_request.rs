@@ -0,0 +1,39 @@
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use validator::Validate;
+
+pub const MAX_DATE_RANGE_IN_DAYS: i64 = 30;
+
+#[derive(Deserialize)]
+pub struct DateRangeRequestRaw {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Deserialize, Validate, Debug, Clone)]
+#[serde(try_from = "DateRangeRequestRaw")]
+pub struct DateRangeRequest {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl TryFrom<DateRangeRequestRaw> for DateRangeRequest {
+    type Error = String;
+
+    fn try_from(raw: DateRangeRequestRaw) -> Result<Self, Self::Error> {
+        if raw.start_date > raw.end_date {
+            return Err(String::from("Start date must not be after end date"));
+        }
+        if raw.end_date >= Utc::now().date_naive() {
+            return Err(String::from("Historical weather is only available for past dates"));
+        }
+        if (raw.end_date - raw.start_date).num_days() >= MAX_DATE_RANGE_IN_DAYS {
+            return Err(format!("Date range must not exceed {} days", MAX_DATE_RANGE_IN_DAYS));
+        }
+
+        Ok(DateRangeRequest {
+            start_date: raw.start_date,
+            end_date: raw.end_date,
+        })
+    }
+}
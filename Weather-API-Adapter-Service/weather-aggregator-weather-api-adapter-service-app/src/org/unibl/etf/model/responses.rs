@@ -1,3 +1,12 @@
 pub mod health_check_response;
 pub mod weatherapi_current_weather_response;
 pub mod uniform_current_weather_response;
+pub mod weatherapi_forecast_response;
+pub mod uniform_forecast_response;
+pub mod weatherapi_historical_weather_response;
+
+pub mod weatherapi_alerts_response;
+pub mod uniform_weather_alerts_response;
+pub mod weatherapi_air_quality_response;
+pub mod uniform_air_quality_response;
+pub mod api_key_usage_response;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_u16;
+
+use serde::{Serialize};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::Location;
+use crate::org::unibl::etf::model::responses::weatherapi_air_quality_response::WeatherAPIAirQualityResponse;
+use crate::org::unibl::etf::util::air_quality_index::{aqi_category, compute_aqi};
+
+#[derive(Debug, Serialize)]
+pub struct UniformAirQualityResponse {
+    pub provider: String,
+    pub location: Location,
+    pub pollutants: Pollutants,
+    #[serde(serialize_with = "serialize_empty_u16")]
+    pub aqi: Option<u16>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub aqi_category: Option<String>,
+    #[serde(serialize_with = "serialize_empty_i64")]
+    pub observed_at_timestamp: Option<i64>,
+}
+
+// Concentrations in µg/m³.
+#[derive(Debug, Serialize)]
+pub struct Pollutants {
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pm2_5: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub pm10: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub o3: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub no2: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub so2: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub co: Option<f64>,
+}
+
+impl TryFrom<WeatherAPIAirQualityResponse> for UniformAirQualityResponse {
+    type Error = AdapterServiceError;
+
+    fn try_from(src: WeatherAPIAirQualityResponse) -> Result<Self, Self::Error> {
+        let current = src.current.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing current field".to_string()))
+        )?;
+        let air_quality = current.air_quality.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing current:air_quality field".to_string()))
+        )?;
+        let location = src.location.unwrap_or_default();
+
+        let pollutants = Pollutants {
+            pm2_5: air_quality.pm2_5,
+            pm10: air_quality.pm10,
+            o3: air_quality.o3,
+            no2: air_quality.no2,
+            so2: air_quality.so2,
+            co: air_quality.co,
+        };
+        let aqi = compute_aqi(&pollutants);
+
+        Ok(UniformAirQualityResponse {
+            provider: "weatherapi.com".to_string(),
+            location: Location {
+                name: location.name,
+                country: location.country,
+                lat: location.lat,
+                lon: location.lon,
+                state_region_province_or_entity: location.region
+            },
+            pollutants,
+            aqi,
+            aqi_category: aqi.map(|aqi| aqi_category(aqi).to_string()),
+            observed_at_timestamp: current.last_updated_epoch,
+        })
+    }
+}
@@ -21,7 +21,7 @@ pub struct UniformCurrentWeatherResponse {
     pub observed_at_timestamp: Option<i64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 pub struct Location {
     #[serde(serialize_with = "serialize_empty_string")]
     pub name: Option<String>,
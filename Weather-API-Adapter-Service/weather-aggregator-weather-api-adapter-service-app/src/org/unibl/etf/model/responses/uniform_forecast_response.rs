@@ -0,0 +1,147 @@
+use crate::org::unibl::etf::util::serializers::round_serialize;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_f64;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_and_round_empty_u8;
+
+use serde::{Serialize};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{Location, Weather, Wind};
+use crate::org::unibl::etf::model::responses::weatherapi_current_weather_response::Condition;
+use crate::org::unibl::etf::model::responses::weatherapi_forecast_response::{ForecastDay, Hour, WeatherAPIForecastResponse};
+
+#[derive(Debug, Serialize)]
+pub struct UniformForecastResponse {
+    pub provider: String,
+    pub location: Location,
+    pub hourly: Vec<UniformHourlyForecast>,
+    pub daily: Vec<UniformDailyForecast>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UniformHourlyForecast {
+    pub timestamp: i64,
+    pub weather: Weather,
+    pub wind: Wind,
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UniformDailyForecast {
+    pub date: String,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_min_metric: f64,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_min_imperial: f64,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_max_metric: f64,
+    #[serde(serialize_with = "round_serialize")]
+    pub temp_max_imperial: f64,
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub humidity: Option<u8>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_metric: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_f64")]
+    pub wind_speed_max_imperial: Option<f64>,
+    #[serde(serialize_with = "serialize_and_round_empty_u8")]
+    pub precipitation_probability: Option<u8>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub condition: Option<String>,
+}
+
+impl TryFrom<WeatherAPIForecastResponse> for UniformForecastResponse {
+    type Error = AdapterServiceError;
+
+    fn try_from(src: WeatherAPIForecastResponse) -> Result<Self, Self::Error> {
+        let forecast = src.forecast.ok_or(
+            AdapterServiceError::InvalidProviderResponseError(Some("Missing forecast field".to_string()))
+        )?;
+        if forecast.forecastday.is_empty() {
+            return Err(AdapterServiceError::InvalidProviderResponseError(Some("Empty forecastday field found".to_string())));
+        }
+        let location = src.location.unwrap_or_default();
+
+        let hourly = forecast.forecastday
+            .iter()
+            .flat_map(|day| day.hour.iter())
+            .map(to_hourly_forecast)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let daily = forecast.forecastday
+            .into_iter()
+            .map(to_daily_forecast)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(UniformForecastResponse {
+            provider: "weatherapi.com".to_string(),
+            location: Location {
+                name: location.name,
+                country: location.country,
+                lat: location.lat,
+                lon: location.lon,
+                state_region_province_or_entity: location.region
+            },
+            hourly,
+            daily,
+        })
+    }
+}
+
+fn to_hourly_forecast(hour: &Hour) -> Result<UniformHourlyForecast, AdapterServiceError> {
+    let timestamp = hour.time_epoch.ok_or(
+        AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory hour time field".to_string()))
+    )?;
+    let temp_c = hour.temp_c.ok_or(
+        AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory hour temperature field".to_string()))
+    )?;
+    let temp_f = hour.temp_f.ok_or(
+        AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory hour temperature field".to_string()))
+    )?;
+
+    Ok(UniformHourlyForecast {
+        timestamp,
+        weather: Weather {
+            temp_metric: temp_c,
+            temp_imperial: temp_f,
+            temp_feelslike_metric: hour.feelslike_c,
+            temp_feelslike_imperial: hour.feelslike_f,
+            humidity: hour.humidity.map(|h| h as u8),
+            pressure_metric: hour.pressure_mb,
+            pressure_imperial: hour.pressure_in,
+            condition: hour.condition.as_ref().and_then(|c| c.text.clone()),
+        },
+        wind: Wind {
+            speed_metric: hour.wind_kph,
+            speed_imperial: hour.wind_mph,
+            gust_metric: hour.gust_kph,
+            gust_imperial: hour.gust_mph,
+            direction: hour.wind_dir.clone(),
+            degrees: hour.wind_degree,
+        },
+        precipitation_probability: hour.chance_of_rain.map(|p| p as u8),
+    })
+}
+
+fn to_daily_forecast(forecast_day: ForecastDay) -> Result<UniformDailyForecast, AdapterServiceError> {
+    let date = forecast_day.date.ok_or(
+        AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory forecast date field".to_string()))
+    )?;
+    let day = forecast_day.day.ok_or(
+        AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory forecast day field".to_string()))
+    )?;
+    let missing_temperature = || AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory day temperature field".to_string()));
+    let condition = day.condition.unwrap_or(Condition::default());
+
+    Ok(UniformDailyForecast {
+        date,
+        temp_min_metric: day.mintemp_c.ok_or_else(missing_temperature)?,
+        temp_min_imperial: day.mintemp_f.ok_or_else(missing_temperature)?,
+        temp_max_metric: day.maxtemp_c.ok_or_else(missing_temperature)?,
+        temp_max_imperial: day.maxtemp_f.ok_or_else(missing_temperature)?,
+        humidity: day.avghumidity.map(|h| h.round() as u8),
+        wind_speed_max_metric: day.maxwind_kph,
+        wind_speed_max_imperial: day.maxwind_mph,
+        precipitation_probability: day.daily_chance_of_rain.map(|p| p as u8),
+        condition: condition.text,
+    })
+}
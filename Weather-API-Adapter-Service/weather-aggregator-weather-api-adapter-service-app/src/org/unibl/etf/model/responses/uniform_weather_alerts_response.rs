@@ -0,0 +1,85 @@
+use chrono::DateTime;
+use crate::org::unibl::etf::util::serializers::serialize_empty_string;
+use crate::org::unibl::etf::util::serializers::serialize_empty_i64;
+
+use serde::{Serialize};
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::Location;
+use crate::org::unibl::etf::model::responses::weatherapi_alerts_response::{Alert, WeatherAPIAlertsResponse};
+
+#[derive(Debug, Serialize)]
+pub struct UniformWeatherAlertsResponse {
+    pub provider: String,
+    pub location: Location,
+    pub alerts: Vec<WeatherAlert>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WeatherAlert {
+    pub event: String,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub severity: Option<String>,
+    pub start_timestamp: i64,
+    #[serde(serialize_with = "serialize_empty_i64")]
+    pub end_timestamp: Option<i64>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub description: Option<String>,
+    #[serde(serialize_with = "serialize_empty_string")]
+    pub sender: Option<String>,
+    pub source_provider: String,
+}
+
+impl TryFrom<WeatherAPIAlertsResponse> for UniformWeatherAlertsResponse {
+    type Error = AdapterServiceError;
+
+    fn try_from(src: WeatherAPIAlertsResponse) -> Result<Self, Self::Error> {
+        let provider = "weatherapi.com".to_string();
+        let location = src.location.unwrap_or_default();
+
+        let alerts = src.alerts
+            .map(|alerts| alerts.alert)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|alert| to_weather_alert(alert, &provider))
+            .collect::<Result<Vec<_>, AdapterServiceError>>()?;
+
+        Ok(UniformWeatherAlertsResponse {
+            provider,
+            location: Location {
+                name: location.name,
+                country: location.country,
+                lat: location.lat,
+                lon: location.lon,
+                state_region_province_or_entity: location.region
+            },
+            alerts,
+        })
+    }
+}
+
+fn to_weather_alert(alert: Alert, provider: &str) -> Result<WeatherAlert, AdapterServiceError> {
+    // Some alerts are published with a headline only.
+    let event = alert.event
+        .filter(|e| !e.trim().is_empty())
+        .or(alert.headline)
+        .ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory alert event field".to_string())))?;
+
+    let start = alert.effective
+        .as_deref()
+        .and_then(|effective| DateTime::parse_from_rfc3339(effective).ok())
+        .ok_or(AdapterServiceError::InvalidProviderResponseError(Some("Missing or invalid mandatory alert effective field".to_string())))?;
+
+    let end = alert.expires
+        .as_deref()
+        .and_then(|expires| DateTime::parse_from_rfc3339(expires).ok());
+
+    Ok(WeatherAlert {
+        event,
+        severity: alert.severity.filter(|s| !s.trim().is_empty() && !s.eq_ignore_ascii_case("unknown")),
+        start_timestamp: start.timestamp(),
+        end_timestamp: end.map(|e| e.timestamp()),
+        description: alert.desc.filter(|d| !d.trim().is_empty()),
+        sender: None,
+        source_provider: provider.to_string(),
+    })
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize};
+use crate::org::unibl::etf::model::responses::weatherapi_current_weather_response::Location;
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherAPIAirQualityResponse {
+    #[serde(default)]
+    pub location: Option<Location>,
+    #[serde(default)]
+    pub current: Option<CurrentAirQuality>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CurrentAirQuality {
+    #[serde(default)]
+    pub last_updated_epoch: Option<i64>, //last updated
+    #[serde(default)]
+    pub air_quality: Option<AirQuality>,
+}
+
+// All concentrations are in µg/m³.
+#[derive(Debug, Deserialize)]
+pub struct AirQuality {
+    #[serde(default)]
+    pub co: Option<f64>,
+    #[serde(default)]
+    pub no2: Option<f64>,
+    #[serde(default)]
+    pub o3: Option<f64>,
+    #[serde(default)]
+    pub so2: Option<f64>,
+    #[serde(default)]
+    pub pm2_5: Option<f64>,
+    #[serde(default)]
+    pub pm10: Option<f64>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize};
+use crate::org::unibl::etf::model::responses::weatherapi_current_weather_response::Location;
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherAPIAlertsResponse {
+    #[serde(default)]
+    pub location: Option<Location>,
+    #[serde(default)]
+    pub alerts: Option<Alerts>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Alerts {
+    #[serde(default)]
+    pub alert: Vec<Alert>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Alert {
+    #[serde(default)]
+    pub headline: Option<String>,
+    #[serde(default)]
+    pub severity: Option<String>, //CAP severity: Minor, Moderate, Severe, Extreme or Unknown
+    #[serde(default)]
+    pub event: Option<String>,
+    #[serde(default)]
+    pub effective: Option<String>, //ISO 8601 with offset, e.g. 2021-01-05T21:47:00-05:00
+    #[serde(default)]
+    pub expires: Option<String>,
+    #[serde(default)]
+    pub desc: Option<String>,
+}
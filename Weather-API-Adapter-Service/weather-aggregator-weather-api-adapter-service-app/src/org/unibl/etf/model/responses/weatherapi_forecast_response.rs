@@ -0,0 +1,84 @@
+use serde::{Deserialize};
+use crate::org::unibl::etf::model::responses::weatherapi_current_weather_response::{Condition, Location};
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherAPIForecastResponse {
+    #[serde(default)]
+    pub location: Option<Location>,
+    #[serde(default)]
+    pub forecast: Option<Forecast>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Forecast {
+    #[serde(default)]
+    pub forecastday: Vec<ForecastDay>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForecastDay {
+    #[serde(default)]
+    pub date: Option<String>, //forecast date in yyyy-MM-dd format, local to the location
+    #[serde(default)]
+    pub day: Option<Day>,
+    #[serde(default)]
+    pub hour: Vec<Hour>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Day {
+    #[serde(default)]
+    pub maxtemp_c: Option<f64>,
+    #[serde(default)]
+    pub maxtemp_f: Option<f64>,
+    #[serde(default)]
+    pub mintemp_c: Option<f64>,
+    #[serde(default)]
+    pub mintemp_f: Option<f64>,
+    #[serde(default)]
+    pub avghumidity: Option<f64>,
+    #[serde(default)]
+    pub maxwind_kph: Option<f64>,
+    #[serde(default)]
+    pub maxwind_mph: Option<f64>,
+    #[serde(default)]
+    pub daily_chance_of_rain: Option<f64>, //in percentage
+    #[serde(default)]
+    pub condition: Option<Condition>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Hour {
+    #[serde(default)]
+    pub time_epoch: Option<i64>,
+    #[serde(default)]
+    pub temp_c: Option<f64>,
+    #[serde(default)]
+    pub temp_f: Option<f64>,
+    #[serde(default)]
+    pub feelslike_c: Option<f64>,
+    #[serde(default)]
+    pub feelslike_f: Option<f64>,
+    #[serde(default)]
+    pub condition: Option<Condition>,
+    #[serde(default)]
+    pub pressure_mb: Option<f64>,
+    #[serde(default)]
+    pub pressure_in: Option<f64>,
+    #[serde(default)]
+    pub humidity: Option<f64>,
+    #[serde(default)]
+    pub wind_kph: Option<f64>,
+    #[serde(default)]
+    pub wind_mph: Option<f64>,
+    #[serde(default)]
+    pub wind_degree: Option<u16>,
+    #[serde(default)]
+    pub wind_dir: Option<String>,
+    #[serde(default)]
+    pub gust_kph: Option<f64>,
+    #[serde(default)]
+    pub gust_mph: Option<f64>,
+    #[serde(default)]
+    pub chance_of_rain: Option<f64>, //in percentage
+}
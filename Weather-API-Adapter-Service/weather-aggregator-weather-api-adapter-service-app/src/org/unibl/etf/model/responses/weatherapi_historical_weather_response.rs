@@ -0,0 +1,57 @@
+use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
+use crate::org::unibl::etf::model::responses::uniform_current_weather_response::{Location, UniformCurrentWeatherResponse, Weather, Wind};
+use crate::org::unibl::etf::model::responses::weatherapi_forecast_response::WeatherAPIForecastResponse;
+
+// history.json answers in the same format as forecast.json.
+pub type WeatherAPIHistoricalWeatherResponse = WeatherAPIForecastResponse;
+
+pub fn to_uniform_observations(src: WeatherAPIHistoricalWeatherResponse) -> Result<Vec<UniformCurrentWeatherResponse>, AdapterServiceError> {
+    let forecast = src.forecast.ok_or(
+        AdapterServiceError::InvalidProviderResponseError(Some("Missing forecast field".to_string()))
+    )?;
+    let location = src.location.unwrap_or_default();
+    let location = Location {
+        name: location.name,
+        country: location.country,
+        lat: location.lat,
+        lon: location.lon,
+        state_region_province_or_entity: location.region
+    };
+
+    forecast.forecastday
+        .into_iter()
+        .flat_map(|day| day.hour.into_iter())
+        .map(|hour| {
+            let temp_c = hour.temp_c.ok_or(
+                AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory hour temperature field".to_string()))
+            )?;
+            let temp_f = hour.temp_f.ok_or(
+                AdapterServiceError::InvalidProviderResponseError(Some("Missing mandatory hour temperature field".to_string()))
+            )?;
+
+            Ok(UniformCurrentWeatherResponse {
+                provider: "weatherapi.com".to_string(),
+                location: location.clone(),
+                weather: Weather {
+                    temp_metric: temp_c,
+                    temp_imperial: temp_f,
+                    temp_feelslike_metric: hour.feelslike_c,
+                    temp_feelslike_imperial: hour.feelslike_f,
+                    humidity: hour.humidity.map(|h| h as u8),
+                    pressure_metric: hour.pressure_mb,
+                    pressure_imperial: hour.pressure_in,
+                    condition: hour.condition.and_then(|c| c.text),
+                },
+                wind: Wind {
+                    speed_metric: hour.wind_kph,
+                    speed_imperial: hour.wind_mph,
+                    gust_metric: hour.gust_kph,
+                    gust_imperial: hour.gust_mph,
+                    direction: hour.wind_dir,
+                    degrees: hour.wind_degree,
+                },
+                observed_at_timestamp: hour.time_epoch,
+            })
+        })
+        .collect()
+}
@@ -1,7 +1,45 @@
 
-use deadpool_redis::redis::{pipe, AsyncCommands, RedisResult};
+use std::sync::LazyLock;
+use std::time::Duration;
+use chrono::{NaiveTime, Utc};
+use deadpool_redis::redis::{AsyncCommands, Script};
+use crate::org::unibl::etf::configuration::settings::{PooledApiKey, RateLimitWindow};
 use crate::org::unibl::etf::model::errors::adapter_service_error::AdapterServiceError;
 
+// Shared by every provider facing service so they all count calls the same way.
+static ACQUIRE_REQUEST_SLOT_SCRIPT: LazyLock<Script> = LazyLock::new(|| Script::new(include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"), "/../../Redis-Store/scripts/acquire_provider_request_slot.lua"
+))));
+
+// Usage counters outlive their day a little so late reports still find them.
+const API_KEY_USAGE_TTL_IN_SECONDS: u64 = 2 * 86400;
+
+static ACQUIRE_API_KEY_SCRIPT: LazyLock<Script> = LazyLock::new(|| Script::new(include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"), "/../../Redis-Store/scripts/acquire_provider_api_key.lua"
+))));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    Allowed,
+    // Time until every window has room for another call.
+    Limited(Duration),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeySelection {
+    // Index into the pool the key was picked from.
+    Selected(usize),
+    // Time until a key is back from its cool-down or its quota resets.
+    Exhausted(Duration),
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKeyUsageRecord {
+    pub requests_today: u64,
+    pub cooldown_remaining: Option<Duration>,
+    pub retired_after_status: Option<u16>,
+}
+
 #[derive(Debug)]
 pub struct ProviderRepository{
 
@@ -13,12 +51,17 @@ impl ProviderRepository {
         Self {}
     }
 
-    #[tracing::instrument(name = "Get Number of requests for a provider repository", skip(redis_pool))]
-    pub async fn get_number_of_requests(
+    #[tracing::instrument(name = "Acquire request slot for a provider repository", skip(redis_pool))]
+    pub async fn acquire_request_slot(
         &self,
         provider_name: &str,
+        windows: &[RateLimitWindow],
         redis_pool: &deadpool_redis::Pool
-    ) -> Result<i64, AdapterServiceError> {
+    ) -> Result<RateLimitDecision, AdapterServiceError> {
+        if windows.is_empty() {
+            return Ok(RateLimitDecision::Allowed);
+        }
+
         let mut conn = match redis_pool.get().await {
             Ok(c) => c,
             Err(e) => {
@@ -27,9 +70,17 @@ impl ProviderRepository {
             }
         };
 
-        let key = format!("provider:{}:ratelimit", provider_name);
+        let mut invocation = ACQUIRE_REQUEST_SLOT_SCRIPT.prepare_invoke();
+        for window in windows {
+            invocation
+                .key(format!("provider:{}:ratelimit:{}", provider_name, window.window_in_seconds))
+                .arg(window.window_in_seconds * 1000)
+                .arg(window.max_requests);
+        }
+        invocation.key(format!("provider:{}:ratelimit:sequence", provider_name));
 
-        let number_of_requests: Option<i64> = conn.get(&key)
+        let (allowed, retry_after_in_ms): (i64, u64) = invocation
+            .invoke_async(&mut conn)
             .await
             .map_err(|e| {
                 AdapterServiceError::RedisError(
@@ -37,17 +88,44 @@ impl ProviderRepository {
                 )
             })?;
 
-        match number_of_requests {
-            Some(number_of_requests) => Ok(number_of_requests),
-            None => { Ok(0)}
+        if allowed == 1 {
+            Ok(RateLimitDecision::Allowed)
+        } else {
+            Ok(RateLimitDecision::Limited(Duration::from_millis(retry_after_in_ms)))
         }
     }
-    #[tracing::instrument(name = "Increment number of requests repository", skip(redis_pool))]
-    pub async fn increment_number_of_requests(
+
+    // Turns a refused slot into the error the caller hands back, with the wait rounded up to whole seconds.
+    #[tracing::instrument(name = "Acquire rate limit slot for a provider repository", skip(windows, redis_pool))]
+    pub async fn acquire_rate_limit_slot(
         &self,
+        windows: &[RateLimitWindow],
         provider_name: &str,
         redis_pool: &deadpool_redis::Pool,
-    )-> Result<i64, AdapterServiceError> {
+    ) -> Result<(), AdapterServiceError> {
+        match self.acquire_request_slot(provider_name, windows, redis_pool).await {
+            Ok(RateLimitDecision::Allowed) => {
+                tracing::info!("Ratelimit not exceeded for the provider {}.", provider_name);
+                Ok(())
+            },
+            Ok(RateLimitDecision::Limited(retry_after)) => {
+                tracing::info!("Ratelimit exceeded for the provider {}, next call allowed in {:?}.", provider_name, retry_after);
+                Err(AdapterServiceError::RateLimitExceeded(Some(retry_after.as_millis().div_ceil(1000) as u64)))
+            },
+            Err(e) => {
+                tracing::info!("Was not able to acquire a rate limit slot for the provider: {}", e.get_message());
+                Err(e)
+            },
+        }
+    }
+
+    #[tracing::instrument(name = "Acquire api key for a provider repository", skip(api_keys, redis_pool))]
+    pub async fn acquire_api_key(
+        &self,
+        provider_name: &str,
+        api_keys: &[PooledApiKey<'_>],
+        redis_pool: &deadpool_redis::Pool
+    ) -> Result<ApiKeySelection, AdapterServiceError> {
         let mut conn = match redis_pool.get().await {
             Ok(c) => c,
             Err(e) => {
@@ -56,30 +134,110 @@ impl ProviderRepository {
             }
         };
 
-        let key = format!("provider:{}:ratelimit", provider_name);
+        let now = Utc::now();
+        let day = now.format("%Y-%m-%d").to_string();
+        let next_day = (now.date_naive() + chrono::Days::new(1)).and_time(NaiveTime::MIN).and_utc();
 
-        let result: RedisResult<(i64, i64)> = pipe()
-            //.atomic()
-            .incr(&key, 1)
-            .expire(&key, 1800)
-            .query_async(&mut conn)
-            .await;
+        let mut invocation = ACQUIRE_API_KEY_SCRIPT.prepare_invoke();
+        invocation
+            .arg((next_day - now).num_milliseconds())
+            .arg(API_KEY_USAGE_TTL_IN_SECONDS);
+        for api_key in api_keys {
+            invocation
+                .key(api_key_usage_key(provider_name, api_key.id, &day))
+                .key(api_key_cooldown_key(provider_name, api_key.id))
+                .arg(api_key.requests_per_day.map(|quota| quota as i64).unwrap_or(-1));
+        }
 
-        let current_count = match result {
-            Ok((current_count, _expire_successful)) => {
-                tracing::info!("Successfully incremented number of requests in redis store.");
-                current_count
-            },
+        let (selected, retry_after_in_ms): (usize, u64) = invocation
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| {
+                AdapterServiceError::RedisError(
+                    Some(e.code().unwrap_or("").to_string()), Some(e.to_string())
+                )
+            })?;
+
+        if selected == 0 {
+            Ok(ApiKeySelection::Exhausted(Duration::from_millis(retry_after_in_ms)))
+        } else {
+            Ok(ApiKeySelection::Selected(selected - 1))
+        }
+    }
+
+    #[tracing::instrument(name = "Retire api key for a provider repository", skip(redis_pool))]
+    pub async fn retire_api_key(
+        &self,
+        provider_name: &str,
+        key_id: &str,
+        status: u16,
+        cooldown_in_seconds: u64,
+        redis_pool: &deadpool_redis::Pool
+    ) -> Result<(), AdapterServiceError> {
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
             Err(e) => {
-                tracing::error!("Failed to increment number of requests in redis store.");
-                return Err(AdapterServiceError::RedisError(
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(AdapterServiceError::ServerError(Some(error_message)));
+            }
+        };
+
+        conn.set_ex::<_, _, ()>(api_key_cooldown_key(provider_name, key_id), status, cooldown_in_seconds)
+            .await
+            .map_err(|e| {
+                AdapterServiceError::RedisError(
                     Some(e.code().unwrap_or("").to_string()), Some(e.to_string())
-                ));
+                )
+            })
+    }
+
+    #[tracing::instrument(name = "Get api key usage for a provider repository", skip(api_keys, redis_pool))]
+    pub async fn get_api_key_usage(
+        &self,
+        provider_name: &str,
+        api_keys: &[PooledApiKey<'_>],
+        redis_pool: &deadpool_redis::Pool
+    ) -> Result<Vec<ApiKeyUsageRecord>, AdapterServiceError> {
+        if api_keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = match redis_pool.get().await {
+            Ok(c) => c,
+            Err(e) => {
+                let error_message = format!("Failed to get connection from pool: {}", e);
+                return Err(AdapterServiceError::ServerError(Some(error_message)));
             }
         };
 
-        Ok(current_count)
+        let day = Utc::now().format("%Y-%m-%d").to_string();
+        let mut pipe = deadpool_redis::redis::pipe();
+        for api_key in api_keys {
+            let cooldown_key = api_key_cooldown_key(provider_name, api_key.id);
+            pipe.get(api_key_usage_key(provider_name, api_key.id, &day))
+                .get(&cooldown_key)
+                .pttl(&cooldown_key);
+        }
 
+        let values: Vec<(Option<u64>, Option<u16>, i64)> = pipe
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| {
+                AdapterServiceError::RedisError(
+                    Some(e.code().unwrap_or("").to_string()), Some(e.to_string())
+                )
+            })?;
+
+        Ok(values
+            .into_iter()
+            .map(|(requests_today, retired_after_status, cooldown_in_ms)| ApiKeyUsageRecord {
+                requests_today: requests_today.unwrap_or_default(),
+                cooldown_remaining: u64::try_from(cooldown_in_ms)
+                    .ok()
+                    .map(Duration::from_millis),
+                retired_after_status,
+            })
+            .collect())
     }
 }
 
@@ -87,4 +245,12 @@ impl Default for ProviderRepository {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+fn api_key_usage_key(provider_name: &str, key_id: &str, day: &str) -> String {
+    format!("provider:{}:api_key:{}:usage:{}", provider_name, key_id, day)
+}
+
+fn api_key_cooldown_key(provider_name: &str, key_id: &str) -> String {
+    format!("provider:{}:api_key:{}:cooldown", provider_name, key_id)
+}
@@ -7,10 +7,12 @@ use crate::org::unibl::etf::model::errors::adapter_service_error::{AdapterServic
 use crate::org::unibl::etf::model::requests::current_weather_request::CurrentWeatherRequest;
 use crate::org::unibl::etf::model::responses::weatherapi_current_weather_response::{WeatherAPICurrentWeatherResponse};
 use crate::org::unibl::etf::repositories::provider_repository::ProviderRepository;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
 
 #[derive(Debug)]
 pub struct CurrentWeatherService {
     provider_repository: ProviderRepository,
+    api_key_service: ApiKeyService,
 }
 
 
@@ -18,25 +20,7 @@ impl CurrentWeatherService {
     fn new() -> Self {
         Self {
             provider_repository: ProviderRepository::default(),
-        }
-    }
-
-    #[tracing::instrument(name = "Check if ratelimit is exceeded function", skip(redis_pool))]
-    pub async fn check_if_ratelimit_is_exceeded(
-        &self,
-        max_number_of_requests_per_30_mins: u64,
-        provider_name: &str,
-        redis_pool: &deadpool_redis::Pool,
-    ) -> Result<bool, AdapterServiceError> {
-        match self.provider_repository.get_number_of_requests(provider_name, redis_pool).await{
-            Ok(number_of_requests) => {
-                tracing::info!("Successfully retrieved number of requests made for the geocoding provider");
-                Ok(number_of_requests >= max_number_of_requests_per_30_mins as i64)
-            },
-            Err(e) => {
-                tracing::info!("Was not able to get number of requests made for the geocoding provider: {}", e.get_message());
-                Err(e)
-            },
+            api_key_service: ApiKeyService::default(),
         }
     }
 
@@ -48,23 +32,6 @@ impl CurrentWeatherService {
         provider_settings: &ProviderSettings,
         redis_pool: &deadpool_redis::Pool
     ) -> Result<WeatherAPICurrentWeatherResponse, AdapterServiceError> {
-        match self.check_if_ratelimit_is_exceeded(
-            provider_settings.requests_per_30_mins,
-            provider_settings.name.as_str(),
-            redis_pool
-        ).await {
-            Ok(exceeded) => {
-                if exceeded {
-                    tracing::info!("Ratelimit exceeded for the geocoding provider.");
-                    return Err(AdapterServiceError::RateLimitExceeded);
-                }
-                tracing::info!("Ratelimit not exceeded for the geocoding provider.");
-            },
-            Err(e) => {
-                return Err(e);
-            }
-        }
-
         let q_argument = if request.lat.is_none() || request.lon.is_none() {
             if request.location_name.is_some() {
                 request.location_name.clone().unwrap_or("".to_string())
@@ -83,11 +50,17 @@ impl CurrentWeatherService {
             format!("{},{}", &request.lat.clone().unwrap().to_string(), &request.lon.clone().unwrap().to_string())
         };
 
+        let api_key = self.api_key_service.acquire_api_key(provider_settings, redis_pool).await?;
+        self.provider_repository.acquire_rate_limit_slot(
+            &provider_settings.rate_limit_windows(),
+            provider_settings.name.as_str(),
+            redis_pool
+        ).await?;
         let response = client
             .get(format!("{}/{}", provider_settings.base_api_url, provider_settings.current_weather_endpoint))
             .query(&[
                 ("q", q_argument.as_str()),
-                ("key", provider_settings.api_key.expose_secret().as_str()),
+                ("key", api_key.secret.expose_secret().as_str()),
             ])
             .send()
             .await
@@ -96,18 +69,6 @@ impl CurrentWeatherService {
             })?;
 
         if response.status().is_success() {
-            match self.provider_repository.increment_number_of_requests(
-                provider_settings.name.as_str(),
-                redis_pool,
-            ).await {
-                Ok(new_value) => {
-                    tracing::info!("Successfully incremented number of requests made. New value: {}", new_value)
-                },
-                Err(e) => {
-                    tracing::error!("Failed to increment number of requests made with error: {:?}", e.get_message())
-                }
-            }
-
             let body_text = response.text().await.map_err(|e| {
                 AdapterServiceError::ServerError(Some(format!("Failed to get successful external API response body text: {}", e)))
             })?;
@@ -124,6 +85,7 @@ impl CurrentWeatherService {
         }
         else {
             let status = response.status();
+            self.api_key_service.retire_api_key_if_rejected(status, &api_key, provider_settings, redis_pool).await;
 
             match status {
                 StatusCode::NOT_FOUND |
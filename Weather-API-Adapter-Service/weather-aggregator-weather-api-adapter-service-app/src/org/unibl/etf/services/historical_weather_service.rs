@@ -0,0 +1,130 @@
+use reqwest::{StatusCode};
+use reqwest_middleware::ClientWithMiddleware;
+use secrecy::ExposeSecret;
+use crate::org::unibl::etf::configuration::settings::{ProviderSettings};
+use crate::org::unibl::etf::model::errors::weather_api_error::{WeatherAPIError};
+use crate::org::unibl::etf::model::errors::adapter_service_error::{AdapterServiceError};
+use crate::org::unibl::etf::model::requests::current_weather_request::CurrentWeatherRequest;
+use crate::org::unibl::etf::model::requests::date_range_request::DateRangeRequest;
+use crate::org::unibl::etf::model::responses::weatherapi_historical_weather_response::{WeatherAPIHistoricalWeatherResponse};
+use crate::org::unibl::etf::repositories::provider_repository::ProviderRepository;
+use crate::org::unibl::etf::services::api_key_service::ApiKeyService;
+
+#[derive(Debug)]
+pub struct HistoricalWeatherService {
+    provider_repository: ProviderRepository,
+    api_key_service: ApiKeyService,
+}
+
+
+impl HistoricalWeatherService {
+    fn new() -> Self {
+        Self {
+            provider_repository: ProviderRepository::default(),
+            api_key_service: ApiKeyService::default(),
+        }
+    }
+
+    #[tracing::instrument(name = "Get Historical Weather Data by Coordinates or Location name Service", skip(client, provider_settings, redis_pool))]
+    pub async fn get_historical_weather_data(
+        &self,
+        request: &CurrentWeatherRequest,
+        date_range: &DateRangeRequest,
+        client: &ClientWithMiddleware,
+        provider_settings: &ProviderSettings,
+        redis_pool: &deadpool_redis::Pool
+    ) -> Result<WeatherAPIHistoricalWeatherResponse, AdapterServiceError> {
+        let q_argument = if let (Some(lat), Some(lon)) = (request.lat, request.lon) {
+            format!("{},{}", lat, lon)
+        }
+        else {
+            if request.location_name.is_some() {
+                request.location_name.clone().unwrap_or("".to_string())
+            }
+            else {
+                if request.ip_address.is_some() {
+                    request.ip_address.clone().unwrap()
+                }
+                else {
+                    return Err(AdapterServiceError::ServerError(None));
+                }
+
+            }
+        };
+
+        let api_key = self.api_key_service.acquire_api_key(provider_settings, redis_pool).await?;
+        self.provider_repository.acquire_rate_limit_slot(
+            &provider_settings.rate_limit_windows(),
+            provider_settings.name.as_str(),
+            redis_pool
+        ).await?;
+        let response = client
+            .get(format!("{}/{}", provider_settings.base_api_url, provider_settings.historical_weather_endpoint))
+            .query(&[
+                ("q", q_argument.as_str()),
+                ("dt", date_range.start_date.format("%Y-%m-%d").to_string().as_str()),
+                ("end_dt", date_range.end_date.format("%Y-%m-%d").to_string().as_str()),
+                ("key", api_key.secret.expose_secret().as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| {
+                AdapterServiceError::ConnectionError(Some(e.to_string()))
+            })?;
+
+        if response.status().is_success() {
+            let body_text = response.text().await.map_err(|e| {
+                AdapterServiceError::ServerError(Some(format!("Failed to get successful external API response body text: {}", e)))
+            })?;
+
+            let data: WeatherAPIHistoricalWeatherResponse = serde_json::from_str(&body_text)
+                .map_err(|e| {
+                    AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                        "Error while parsing successful external API response body text. JSON Error: {} | Raw Body: {}",
+                        e, body_text
+                    )))
+                })?;
+
+            Ok(data)
+        }
+        else {
+            let status = response.status();
+            self.api_key_service.retire_api_key_if_rejected(status, &api_key, provider_settings, redis_pool).await;
+
+            match status {
+                StatusCode::NOT_FOUND |
+                StatusCode::UNAUTHORIZED |
+                StatusCode::TOO_MANY_REQUESTS |
+                StatusCode::BAD_REQUEST => {
+                    let error_body_text = response.text().await.map_err(|e| {
+                        AdapterServiceError::ServerError(Some(format!("Failed to get external API error response body text: {}", e)))
+                    })?;
+
+                    let error_body: WeatherAPIError = serde_json::from_str(&error_body_text)
+                        .map_err(|e| {
+                            AdapterServiceError::ExternalAPIResponseParsingError(Some(format!(
+                                "Error while parsing external API error response body text. JSON Error: {} | Raw Body: {}",
+                                e, error_body_text
+                            )))
+                        })?;
+                    if error_body.error.code == 1006 {
+                        return Err(AdapterServiceError::LocationNotFoundError(
+                            request.location_name.clone().unwrap())
+                        );
+                    }
+                    tracing::error!("Error while calling External API: {:?}", error_body);
+                    return Err(AdapterServiceError::WeatherAPIError(error_body.error.code, Some(error_body.error.message)));
+                },
+                _ => {
+                    return Err(AdapterServiceError::WeatherAPIError(status.as_u16(), None));
+                }
+            }
+        }
+    }
+}
+
+impl Default for HistoricalWeatherService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -1 +1,2 @@
-pub mod serializers;
\ No newline at end of file
+pub mod serializers;
+pub mod air_quality_index;
\ No newline at end of file
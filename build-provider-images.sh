@@ -0,0 +1,22 @@
+#!/bin/sh
+# Builds the production images of the provider facing services.
+# They include the Redis scripts from Redis-Store/scripts at compile time, which sit outside their
+# own build context, so the scripts are passed in as the additional redis-scripts context.
+# Extra arguments are handed to every docker build, e.g. ./build-provider-images.sh --no-cache
+set -e
+
+cd "$(dirname "$0")"
+
+for service in \
+    Geocoding-Service/weather-aggregator-geocoding-service-app \
+    Openweather-API-Adapter-Service/weather-aggregator-openweather-api-adapter-service-app \
+    Provider-Adapter-Service/weather-aggregator-provider-adapter-service-app \
+    Weather-API-Adapter-Service/weather-aggregator-weather-api-adapter-service-app
+do
+    docker build \
+        --file "$service/Dockerfile.prod" \
+        --build-context redis-scripts=Redis-Store/scripts \
+        --tag "$(basename "$service")" \
+        "$@" \
+        "$service"
+done